@@ -37,6 +37,7 @@ fn place_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			false,
+			None,
 		));
 
 		// Assert
@@ -61,6 +62,7 @@ fn partial_fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
 		));
 
 		// Act
@@ -92,6 +94,7 @@ fn fill_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
 		));
 
 		// Act
@@ -122,6 +125,7 @@ fn cancel_order_should_work() {
 			20 * UNITS,
 			100 * UNITS,
 			true,
+			None,
 		));
 
 		// Act
@@ -0,0 +1,61 @@
+use crate::bonds::*;
+use crate::test_utils::assert_approx_eq;
+use crate::types::Balance;
+use proptest::prelude::*;
+
+const ONE: Balance = 1_000_000_000_000;
+const TIME_SCALE: u64 = 10 * 365 * 86_400_000;
+
+fn reserve() -> impl Strategy<Value = Balance> {
+	1_000 * ONE..1_000_000 * ONE
+}
+
+fn trade_amount() -> impl Strategy<Value = Balance> {
+	ONE..100 * ONE
+}
+
+fn time_to_maturity() -> impl Strategy<Value = u64> {
+	0..TIME_SCALE / 2
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn out_given_in_should_not_decrease_invariant(
+		in_reserve in reserve(),
+		out_reserve in reserve(),
+		amount in trade_amount(),
+		time_to_maturity in time_to_maturity()) {
+		//Act
+		let amount_out = calculate_out_given_in(in_reserve, out_reserve, amount, time_to_maturity, TIME_SCALE).unwrap();
+
+		//Assert
+		let a = 1f64 - time_to_maturity as f64 / TIME_SCALE as f64;
+		let (x, y) = (in_reserve as f64, out_reserve as f64);
+		let (new_x, new_y) = ((in_reserve + amount) as f64, (out_reserve - amount_out) as f64);
+
+		// normalized by the out reserve to keep the precision of floating point arithmetic
+		let before = (x / y).powf(a) + 1f64;
+		let after = (new_x / y).powf(a) + (new_y / y).powf(a);
+
+		assert!(after + 1e-10 >= before, "The invariant decreased");
+		assert_approx_eq!(after, before, 1e-9, "The invariant does not hold");
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(1000))]
+	#[test]
+	fn in_given_out_should_be_inverse_of_out_given_in(
+		in_reserve in reserve(),
+		out_reserve in reserve(),
+		amount in trade_amount(),
+		time_to_maturity in time_to_maturity()) {
+		//Act
+		let amount_out = calculate_out_given_in(in_reserve, out_reserve, amount, time_to_maturity, TIME_SCALE).unwrap();
+		let amount_in = calculate_in_given_out(in_reserve, out_reserve, amount_out, time_to_maturity, TIME_SCALE).unwrap();
+
+		//Assert
+		assert_approx_eq!(amount_in, amount, amount / 1_000_000, "Buy and sell are not symmetric");
+	}
+}
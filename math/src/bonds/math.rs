@@ -0,0 +1,200 @@
+//! YieldSpace invariant for bond pools.
+//!
+//! Bonds converge to the value of their underlying asset at maturity, so a constant-product curve misprices them.
+//! The pool uses the invariant `x^(1-t) + y^(1-t) = k`, where `x` and `y` are the reserves of the pool
+//! and `t` is the time to maturity divided by the time scale of the pool.
+//! The curve becomes constant-product-like far from maturity and constant-sum at maturity,
+//! so the price of the bonds approaches the price of the underlying asset as the maturity gets closer.
+
+use crate::types::Balance;
+use crate::{
+	ensure,
+	MathError::{self, InsufficientOutReserve, Overflow, ZeroDuration, ZeroReserve},
+};
+
+use fixed::types::U32F96;
+use num_traits::{CheckedMul, CheckedSub, One, Zero};
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Rounding};
+
+/// Calculating amount of asset out given amount of asset in.
+/// Formula : OUT_RESERVE - (OUT_RESERVE^(1-t) + IN_RESERVE^(1-t) - (IN_RESERVE + AMOUNT)^(1-t))^(1/(1-t))
+///
+/// - `in_reserve` - reserve amount of selling asset
+/// - `out_reserve` - reserve amount of buying asset
+/// - `amount` - amount of selling asset
+/// - `time_to_maturity` - time until maturity of the bonds
+/// - `time_scale` - time scale of the pool, needs to be greater than `time_to_maturity`
+///
+/// Returns MathError in case of error
+pub fn calculate_out_given_in(
+	in_reserve: Balance,
+	out_reserve: Balance,
+	amount: Balance,
+	time_to_maturity: u64,
+	time_scale: u64,
+) -> Result<Balance, MathError> {
+	ensure!(!in_reserve.is_zero() && !out_reserve.is_zero(), ZeroReserve);
+
+	if amount.is_zero() {
+		return Ok(0);
+	}
+
+	let exponent = calculate_exponent(time_to_maturity, time_scale)?;
+
+	// (IN_RESERVE / OUT_RESERVE)^(1-t), rounded down to keep the new out reserve higher
+	let reserve_ratio = div_to_fixed(in_reserve, out_reserve, Rounding::Down).ok_or(Overflow)?;
+	let reserve_ratio: U32F96 = crate::transcendental::pow(reserve_ratio, exponent).map_err(|_| Overflow)?;
+
+	// ((IN_RESERVE + AMOUNT) / IN_RESERVE)^(1-t), rounded down to keep the new out reserve higher
+	let new_in_reserve = in_reserve.checked_add(amount).ok_or(Overflow)?;
+	let in_ratio = div_to_fixed(new_in_reserve, in_reserve, Rounding::Down).ok_or(Overflow)?;
+	let in_ratio: U32F96 = crate::transcendental::pow(in_ratio, exponent).map_err(|_| Overflow)?;
+
+	// (NEW_OUT_RESERVE / OUT_RESERVE)^(1-t)
+	let decrease = reserve_ratio
+		.checked_mul(in_ratio.checked_sub(U32F96::one()).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+	let out_ratio = U32F96::one().checked_sub(decrease).ok_or(InsufficientOutReserve)?;
+	ensure!(!out_ratio.is_zero(), InsufficientOutReserve);
+
+	let inverse_exponent = U32F96::one().checked_div(exponent).ok_or(Overflow)?;
+	let out_ratio: U32F96 = crate::transcendental::pow(out_ratio, inverse_exponent).map_err(|_| Overflow)?;
+
+	let new_out_reserve = mul_to_balance(out_reserve, out_ratio, Rounding::Up).ok_or(Overflow)?;
+
+	out_reserve.checked_sub(new_out_reserve).ok_or(InsufficientOutReserve)
+}
+
+/// Calculating amount of asset in given amount of asset out.
+/// Formula : (IN_RESERVE^(1-t) + OUT_RESERVE^(1-t) - (OUT_RESERVE - AMOUNT)^(1-t))^(1/(1-t)) - IN_RESERVE
+///
+/// - `in_reserve` - reserve amount of selling asset
+/// - `out_reserve` - reserve amount of buying asset
+/// - `amount` - amount of buying asset
+/// - `time_to_maturity` - time until maturity of the bonds
+/// - `time_scale` - time scale of the pool, needs to be greater than `time_to_maturity`
+///
+/// Returns MathError in case of error
+pub fn calculate_in_given_out(
+	in_reserve: Balance,
+	out_reserve: Balance,
+	amount: Balance,
+	time_to_maturity: u64,
+	time_scale: u64,
+) -> Result<Balance, MathError> {
+	ensure!(!in_reserve.is_zero() && !out_reserve.is_zero(), ZeroReserve);
+	ensure!(amount < out_reserve, InsufficientOutReserve);
+
+	if amount.is_zero() {
+		return Ok(0);
+	}
+
+	let exponent = calculate_exponent(time_to_maturity, time_scale)?;
+
+	// (OUT_RESERVE / IN_RESERVE)^(1-t), rounded up to keep the new in reserve higher
+	let reserve_ratio = div_to_fixed(out_reserve, in_reserve, Rounding::Up).ok_or(Overflow)?;
+	let reserve_ratio: U32F96 = crate::transcendental::pow(reserve_ratio, exponent).map_err(|_| Overflow)?;
+
+	// ((OUT_RESERVE - AMOUNT) / OUT_RESERVE)^(1-t), rounded down to keep the new in reserve higher
+	let new_out_reserve = out_reserve.checked_sub(amount).ok_or(InsufficientOutReserve)?;
+	let out_ratio = div_to_fixed(new_out_reserve, out_reserve, Rounding::Down).ok_or(Overflow)?;
+	let out_ratio: U32F96 = crate::transcendental::pow(out_ratio, exponent).map_err(|_| Overflow)?;
+
+	// (NEW_IN_RESERVE / IN_RESERVE)^(1-t)
+	let increase = reserve_ratio
+		.checked_mul(U32F96::one().checked_sub(out_ratio).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+	let in_ratio = U32F96::one().checked_add(increase).ok_or(Overflow)?;
+
+	let inverse_exponent = U32F96::one().checked_div(exponent).ok_or(Overflow)?;
+	let in_ratio: U32F96 = crate::transcendental::pow(in_ratio, inverse_exponent).map_err(|_| Overflow)?;
+
+	let new_in_reserve = mul_to_balance(in_reserve, in_ratio, Rounding::Up).ok_or(Overflow)?;
+
+	// Rounding up to cover the precision loss of the power function.
+	Ok(new_in_reserve.checked_sub(in_reserve).ok_or(Overflow)?.saturating_add(1))
+}
+
+/// Calculating spot price given reserve of selling asset and reserve of buying asset.
+/// Formula : (OUT_RESERVE / IN_RESERVE)^t
+///
+/// Returns price of asset_in denominated in asset_out (asset_out/asset_in)
+///
+/// - `in_reserve` - reserve amount of selling asset
+/// - `out_reserve` - reserve amount of buying asset
+/// - `time_to_maturity` - time until maturity of the bonds
+/// - `time_scale` - time scale of the pool, needs to be greater than `time_to_maturity`
+///
+/// Returns MathError in case of error
+pub fn calculate_spot_price(
+	in_reserve: Balance,
+	out_reserve: Balance,
+	time_to_maturity: u64,
+	time_scale: u64,
+) -> Result<FixedU128, MathError> {
+	ensure!(!in_reserve.is_zero() && !out_reserve.is_zero(), ZeroReserve);
+
+	let exponent = calculate_exponent(time_to_maturity, time_scale)?;
+	let time_factor = U32F96::one().checked_sub(exponent).ok_or(Overflow)?;
+
+	let reserve_ratio = div_to_fixed(out_reserve, in_reserve, Rounding::Down).ok_or(Overflow)?;
+	let price: U32F96 = crate::transcendental::pow(reserve_ratio, time_factor).map_err(|_| Overflow)?;
+
+	let price = mul_to_balance(FixedU128::DIV, price, Rounding::Down).ok_or(Overflow)?;
+
+	Ok(FixedU128::from_inner(price))
+}
+
+/// Calculating spot price including fee.
+///
+/// Returns price of asset_in denominated in asset_out (asset_out/asset_in)
+///
+/// - `in_reserve` - reserve amount of selling asset
+/// - `out_reserve` - reserve amount of buying asset
+/// - `time_to_maturity` - time until maturity of the bonds
+/// - `time_scale` - time scale of the pool, needs to be greater than `time_to_maturity`
+/// - `fee_rate` - fee rate of the pool
+///
+/// Returns MathError in case of error
+pub fn calculate_spot_price_with_fee(
+	in_reserve: Balance,
+	out_reserve: Balance,
+	time_to_maturity: u64,
+	time_scale: u64,
+	fee_rate: Option<(u32, u32)>,
+) -> Result<FixedU128, MathError> {
+	let spot_price_without_fee = calculate_spot_price(in_reserve, out_reserve, time_to_maturity, time_scale)?;
+
+	if let Some((n, d)) = fee_rate {
+		// The amount out is reduced by fee, so the spot price is decreased
+		let fee = FixedU128::checked_from_rational(n, d).ok_or(Overflow)?;
+		let fee_multiplier = FixedU128::from_rational(1, 1).checked_sub(&fee).ok_or(Overflow)?;
+
+		return spot_price_without_fee.checked_mul(&fee_multiplier).ok_or(Overflow);
+	}
+
+	Ok(spot_price_without_fee)
+}
+
+/// Calculating the exponent `1 - t` of the invariant, where `t` is `time_to_maturity / time_scale`.
+fn calculate_exponent(time_to_maturity: u64, time_scale: u64) -> Result<U32F96, MathError> {
+	ensure!(!time_scale.is_zero(), ZeroDuration);
+	ensure!(time_to_maturity < time_scale, Overflow);
+
+	// Rounding up the time factor to keep the curve further from the constant-sum one.
+	let time_factor = div_to_fixed(time_to_maturity.into(), time_scale.into(), Rounding::Up).ok_or(Overflow)?;
+
+	U32F96::one().checked_sub(time_factor).ok_or(Overflow)
+}
+
+/// Divide `num` by `denom` and return a fixed number. Rounds the division by `r`.
+fn div_to_fixed(num: Balance, denom: Balance, r: Rounding) -> Option<U32F96> {
+	let bits = multiply_by_rational_with_rounding(num, U32F96::one().to_bits(), denom, r)?;
+	Some(U32F96::from_bits(bits))
+}
+
+/// Multiply a `balance` with a `fixed` number and return a balance. Rounds the implicit division by `r`.
+fn mul_to_balance(balance: Balance, fixed: U32F96, r: Rounding) -> Option<Balance> {
+	multiply_by_rational_with_rounding(balance, fixed.to_bits(), U32F96::one().to_bits(), r)
+}
@@ -0,0 +1,135 @@
+use crate::bonds::*;
+use crate::types::Balance;
+use crate::MathError::{InsufficientOutReserve, Overflow, ZeroDuration, ZeroReserve};
+use crate::test_utils::assert_approx_eq;
+use num_traits::CheckedMul;
+use sp_arithmetic::{FixedPointNumber, FixedU128};
+
+const ONE: Balance = 1_000_000_000_000;
+const TIME_SCALE: u64 = 10 * 365 * 86_400_000;
+
+/// Reference implementation of the invariant in floating point arithmetic.
+fn reference_out_given_in(in_reserve: Balance, out_reserve: Balance, amount: Balance, t: f64) -> f64 {
+	let a = 1f64 - t;
+	let (x, y, dx) = (in_reserve as f64, out_reserve as f64, amount as f64);
+	let out_ratio = (1f64 + (x / y).powf(a) * (1f64 - ((x + dx) / x).powf(a))).powf(1f64 / a);
+	y * (1f64 - out_ratio)
+}
+
+#[test]
+fn out_given_in_should_be_one_to_one_when_bonds_are_mature() {
+	let result = calculate_out_given_in(1_000 * ONE, 1_000 * ONE, 10 * ONE, 0, TIME_SCALE).unwrap();
+
+	assert!(result <= 10 * ONE);
+	assert_approx_eq!(result, 10 * ONE, 2);
+}
+
+#[test]
+fn out_given_in_should_match_reference_implementation() {
+	let cases = vec![
+		(1_000 * ONE, 1_200 * ONE, 10 * ONE, TIME_SCALE / 2),
+		(1_200 * ONE, 1_000 * ONE, 10 * ONE, TIME_SCALE / 2),
+		(1_000 * ONE, 1_200 * ONE, 100 * ONE, TIME_SCALE / 10),
+		(500 * ONE, 2_000 * ONE, ONE, TIME_SCALE * 9 / 10),
+	];
+
+	for (in_reserve, out_reserve, amount, time_to_maturity) in cases {
+		let result = calculate_out_given_in(in_reserve, out_reserve, amount, time_to_maturity, TIME_SCALE).unwrap();
+		let expected = reference_out_given_in(
+			in_reserve,
+			out_reserve,
+			amount,
+			time_to_maturity as f64 / TIME_SCALE as f64,
+		);
+
+		assert_approx_eq!(result as f64, expected, expected / 1_000_000f64);
+	}
+}
+
+#[test]
+fn out_given_in_should_price_bonds_below_par_when_bond_reserve_is_higher() {
+	// bonds are sold for the underlying asset
+	let result = calculate_out_given_in(1_200 * ONE, 1_000 * ONE, 10 * ONE, TIME_SCALE / 2, TIME_SCALE).unwrap();
+	assert!(result < 10 * ONE);
+
+	// the discount decreases as maturity gets closer
+	let closer_to_maturity =
+		calculate_out_given_in(1_200 * ONE, 1_000 * ONE, 10 * ONE, TIME_SCALE / 10, TIME_SCALE).unwrap();
+	assert!(result < closer_to_maturity);
+	assert!(closer_to_maturity < 10 * ONE);
+}
+
+#[test]
+fn out_given_in_should_fail_when_parameters_are_invalid() {
+	assert_eq!(calculate_out_given_in(0, ONE, ONE, 0, TIME_SCALE), Err(ZeroReserve));
+	assert_eq!(calculate_out_given_in(ONE, 0, ONE, 0, TIME_SCALE), Err(ZeroReserve));
+	assert_eq!(calculate_out_given_in(ONE, ONE, ONE, TIME_SCALE, TIME_SCALE), Err(Overflow));
+	assert_eq!(calculate_out_given_in(ONE, ONE, ONE, 0, 0), Err(ZeroDuration));
+	assert_eq!(calculate_out_given_in(ONE, ONE, 0, 0, TIME_SCALE), Ok(0));
+}
+
+#[test]
+fn in_given_out_should_be_one_to_one_when_bonds_are_mature() {
+	let result = calculate_in_given_out(1_000 * ONE, 1_000 * ONE, 10 * ONE, 0, TIME_SCALE).unwrap();
+
+	assert!(result >= 10 * ONE);
+	assert_approx_eq!(result, 10 * ONE, 2);
+}
+
+#[test]
+fn in_given_out_should_be_inverse_of_out_given_in() {
+	let cases = vec![
+		(1_000 * ONE, 1_200 * ONE, 10 * ONE, TIME_SCALE / 2),
+		(1_200 * ONE, 1_000 * ONE, 10 * ONE, TIME_SCALE / 2),
+		(1_000 * ONE, 1_200 * ONE, 100 * ONE, TIME_SCALE / 10),
+	];
+
+	for (in_reserve, out_reserve, amount, time_to_maturity) in cases {
+		let amount_out = calculate_out_given_in(in_reserve, out_reserve, amount, time_to_maturity, TIME_SCALE).unwrap();
+		let amount_in =
+			calculate_in_given_out(in_reserve, out_reserve, amount_out, time_to_maturity, TIME_SCALE).unwrap();
+
+		assert_approx_eq!(amount_in, amount, amount / 1_000_000);
+	}
+}
+
+#[test]
+fn in_given_out_should_fail_when_parameters_are_invalid() {
+	assert_eq!(calculate_in_given_out(0, ONE, ONE, 0, TIME_SCALE), Err(ZeroReserve));
+	assert_eq!(calculate_in_given_out(ONE, ONE, ONE, 0, TIME_SCALE), Err(InsufficientOutReserve));
+	assert_eq!(calculate_in_given_out(ONE, ONE, ONE / 2, TIME_SCALE, TIME_SCALE), Err(Overflow));
+	assert_eq!(calculate_in_given_out(ONE, ONE, ONE / 2, 0, 0), Err(ZeroDuration));
+	assert_eq!(calculate_in_given_out(ONE, ONE, 0, 0, TIME_SCALE), Ok(0));
+}
+
+#[test]
+fn spot_price_should_converge_to_par_at_maturity() {
+	assert_eq!(
+		calculate_spot_price(1_200 * ONE, 1_000 * ONE, 0, TIME_SCALE),
+		Ok(FixedU128::from(1))
+	);
+
+	let price = calculate_spot_price(1_200 * ONE, 1_000 * ONE, TIME_SCALE / 2, TIME_SCALE).unwrap();
+	let expected = (1_000f64 / 1_200f64).powf(0.5);
+	assert_approx_eq!(price.to_float(), expected, 0.000_001);
+
+	let closer_to_maturity = calculate_spot_price(1_200 * ONE, 1_000 * ONE, TIME_SCALE / 10, TIME_SCALE).unwrap();
+	assert!(price < closer_to_maturity);
+	assert!(closer_to_maturity < FixedU128::from(1));
+}
+
+#[test]
+fn spot_price_with_fee_should_be_lower_than_spot_price() {
+	let price = calculate_spot_price(1_200 * ONE, 1_000 * ONE, TIME_SCALE / 2, TIME_SCALE).unwrap();
+	let price_with_fee =
+		calculate_spot_price_with_fee(1_200 * ONE, 1_000 * ONE, TIME_SCALE / 2, TIME_SCALE, Some((3, 1_000))).unwrap();
+
+	assert_eq!(
+		price_with_fee,
+		price.checked_mul(&FixedU128::from_rational(997, 1_000)).unwrap()
+	);
+	assert_eq!(
+		calculate_spot_price_with_fee(1_200 * ONE, 1_000 * ONE, TIME_SCALE / 2, TIME_SCALE, None),
+		Ok(price)
+	);
+}
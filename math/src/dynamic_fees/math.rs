@@ -1,8 +1,10 @@
 use super::types::*;
 use crate::dynamic_fees::types::NetVolumeDirection::{InOut, OutIn};
+use crate::types::Ratio;
 use num_traits::Zero;
+use primitive_types::U512;
 use sp_arithmetic::traits::Saturating;
-use sp_arithmetic::{FixedPointOperand, FixedU128, PerThing};
+use sp_arithmetic::{FixedPointNumber, FixedPointOperand, FixedU128, PerThing};
 
 /// Recalculate Omnipool's asset fee given previously calculated fee and oracle data.
 ///
@@ -40,6 +42,41 @@ where
 	recalculate_fee(volume, previous_fee, last_block_diff, params, InOut)
 }
 
+/// Calculate the volatility component of an asset fee given short and long term oracle prices of the asset.
+///
+/// The fee is proportional to the relative divergence of the prices: `f = a * |Ps - Pl| / Pl`,
+/// where `a` is the amplification, and is capped at `params.max_fee`.
+///
+/// `short_price` is the price of the asset over the short oracle period.
+/// `long_price` is the price of the asset over the long oracle period.
+/// `params` is the volatility fee parameters, such as maximum fee and amplification.
+pub fn calculate_volatility_fee<Fee: PerThing>(
+	short_price: Ratio,
+	long_price: Ratio,
+	params: VolatilityFeeParams<Fee>,
+) -> Fee
+where
+	<Fee as PerThing>::Inner: FixedPointOperand,
+{
+	if long_price.is_zero() || short_price.d.is_zero() || long_price.d.is_zero() {
+		return Fee::zero();
+	}
+
+	// Compare the prices with common denominator to avoid precision loss.
+	let short = U512::from(short_price.n).saturating_mul(U512::from(long_price.d));
+	let long = U512::from(long_price.n).saturating_mul(U512::from(short_price.d));
+	let diff = short.max(long).saturating_sub(short.min(long));
+
+	let divergence = diff.saturating_mul(U512::from(FixedU128::DIV)) / long;
+	let divergence = FixedU128::from_inner(u128::try_from(divergence).unwrap_or(u128::MAX));
+
+	params
+		.amplification
+		.saturating_mul(divergence)
+		.into_clamped_perthing::<Fee>()
+		.min(params.max_fee)
+}
+
 fn recalculate_fee<Fee: PerThing>(
 	volume: OracleEntry,
 	previous_fee: Fee,
@@ -1,5 +1,6 @@
 use super::types::*;
-use crate::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
+use crate::dynamic_fees::{calculate_volatility_fee, recalculate_asset_fee, recalculate_protocol_fee};
+use crate::types::Ratio;
 use num_traits::Zero;
 use sp_arithmetic::{FixedU128, Permill};
 
@@ -232,3 +233,52 @@ fn protocol_fee_should_clamp_to_max_fee() {
 	let calculated_fee = recalculate_protocol_fee(volume, previous_fee, last_block_diff, params);
 	assert_eq!(calculated_fee, expected_fee);
 }
+
+#[test]
+fn volatility_fee_should_be_zero_when_prices_are_equal() {
+	let params = VolatilityFeeParams {
+		max_fee: Permill::from_percent(5),
+		amplification: FixedU128::from(2),
+	};
+
+	let calculated_fee = calculate_volatility_fee(Ratio::new(2, 4), Ratio::new(1, 2), params);
+	assert_eq!(calculated_fee, Permill::zero());
+}
+
+#[test]
+fn volatility_fee_should_be_proportional_to_price_divergence() {
+	let params = VolatilityFeeParams {
+		max_fee: Permill::from_percent(5),
+		amplification: FixedU128::from_rational(1, 10),
+	};
+
+	// short price 10% above long price
+	let calculated_fee = calculate_volatility_fee(Ratio::new(110, 100), Ratio::new(1, 1), params.clone());
+	assert_eq!(calculated_fee, Permill::from_percent(1));
+
+	// short price 10% below long price
+	let calculated_fee = calculate_volatility_fee(Ratio::new(90, 100), Ratio::new(1, 1), params);
+	assert_eq!(calculated_fee, Permill::from_percent(1));
+}
+
+#[test]
+fn volatility_fee_should_clamp_to_max_fee() {
+	let params = VolatilityFeeParams {
+		max_fee: Permill::from_percent(5),
+		amplification: FixedU128::from(2),
+	};
+
+	let calculated_fee = calculate_volatility_fee(Ratio::new(u128::MAX, 1), Ratio::new(1, u128::MAX), params);
+	assert_eq!(calculated_fee, Permill::from_percent(5));
+}
+
+#[test]
+fn volatility_fee_should_be_zero_when_long_price_is_zero() {
+	let params = VolatilityFeeParams {
+		max_fee: Permill::from_percent(5),
+		amplification: FixedU128::from(2),
+	};
+
+	let calculated_fee = calculate_volatility_fee(Ratio::new(1, 1), Ratio::zero(), params);
+	assert_eq!(calculated_fee, Permill::zero());
+}
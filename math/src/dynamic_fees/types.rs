@@ -10,6 +10,13 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+/// Volatility fee parameters - maximum fee and amplification of the price divergence.
+#[derive(Debug, Clone)]
+pub struct VolatilityFeeParams<Fee> {
+	pub max_fee: Fee,
+	pub amplification: FixedU128,
+}
+
 /// Oracle entry data for an asset, providing amount in and out and total liquidity of an asset.
 #[derive(Debug, Clone)]
 pub struct OracleEntry {
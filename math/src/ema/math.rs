@@ -111,8 +111,8 @@ pub fn exp_smoothing(smoothing: Fraction, iterations: u32) -> Fraction {
 /// + `alpha = 1 - 0.5^(2 / period)` to have the same median as a `period`-length SMA.
 /// See https://en.wikipedia.org/wiki/Moving_average#Relationship_between_SMA_and_EMA
 ///
-/// Note: Not used in the pallet except to check configured values. Not meant to be used by code
-/// interacting with the pallet. Use the configured values.
+/// Note: The oracle pallet uses hardcoded values for its predefined periods and only derives the
+/// smoothing factor of custom periods with this function.
 pub fn smoothing_from_period(period: u64) -> Fraction {
 	fraction::frac(2, u128::from(period.max(1)).saturating_add(1))
 }
@@ -3,6 +3,7 @@ use crate::lbp::lbp;
 use primitive_types::U256;
 use proptest::prelude::*;
 use rand::Rng;
+use sp_arithmetic::Permill;
 
 const MIN_START_BLOCK: u32 = 0;
 const MAX_START_BLOCK: u32 = 10_000_000;
@@ -25,6 +26,19 @@ fn final_weight() -> impl Strategy<Value = u32> {
 	10_000_001u32..100_000_000u32
 }
 
+fn decay_rate() -> impl Strategy<Value = u32> {
+	1u32..=10u32
+}
+
+fn steps() -> impl Strategy<Value = u32> {
+	1u32..=100u32
+}
+
+fn breakpoint() -> impl Strategy<Value = Permill> {
+	// at least 0.1% so that the breakpoint is not at the start of the shortest sale
+	(1_000u32..1_000_000u32).prop_map(Permill::from_parts)
+}
+
 //Spec: https://www.notion.so/Property-Tests-7b506add39ea48fc8f68ecd18391e30a#9bbed73541c84e45a9855360aeee1f9b
 proptest! {
 	#![proptest_config(ProptestConfig::with_cases(10000))]
@@ -52,3 +66,109 @@ proptest! {
 		assert_approx_eq!(a1*a2, b1*b2, max_delta, "The invariant does not hold")
 	}
 }
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10000))]
+	#[test]
+	fn exponential_weights_should_front_load_weight_change(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		low_weight in initial_weight(),
+		high_weight in final_weight(),
+		rate in decay_rate()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		//Act
+		let decreasing = lbp::calculate_exponential_weights(start_x_block, end_x_block, high_weight, low_weight, rate, at_block).unwrap();
+		let increasing = lbp::calculate_exponential_weights(start_x_block, end_x_block, low_weight, high_weight, rate, at_block).unwrap();
+
+		//Assert
+		assert!(decreasing >= low_weight && decreasing <= high_weight);
+		assert!(increasing >= low_weight && increasing <= high_weight);
+
+		// curve is always ahead of the linear interpolation
+		let linear_decreasing = lbp::calculate_linear_weights(start_x_block, end_x_block, high_weight, low_weight, at_block).unwrap();
+		let linear_increasing = lbp::calculate_linear_weights(start_x_block, end_x_block, low_weight, high_weight, at_block).unwrap();
+		assert!(decreasing <= linear_decreasing + 1);
+		assert!(increasing + 1 >= linear_increasing);
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10000))]
+	#[test]
+	fn exponential_weights_should_be_monotonic(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		low_weight in initial_weight(),
+		high_weight in final_weight(),
+		rate in decay_rate()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		//Act
+		let weight = lbp::calculate_exponential_weights(start_x_block, end_x_block, high_weight, low_weight, rate, at_block).unwrap();
+		let next_weight = lbp::calculate_exponential_weights(start_x_block, end_x_block, high_weight, low_weight, rate, at_block + 1).unwrap();
+
+		//Assert
+		assert!(next_weight <= weight);
+		assert_eq!(lbp::calculate_exponential_weights(start_x_block, end_x_block, high_weight, low_weight, rate, start_x_block), Ok(high_weight));
+		assert_eq!(lbp::calculate_exponential_weights(start_x_block, end_x_block, high_weight, low_weight, rate, end_x_block), Ok(low_weight));
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10000))]
+	#[test]
+	fn step_weights_should_lag_behind_linear_weights(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		low_weight in initial_weight(),
+		high_weight in final_weight(),
+		steps in steps()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		//Act
+		let weight = lbp::calculate_step_weights(start_x_block, end_x_block, high_weight, low_weight, steps, at_block).unwrap();
+
+		//Assert
+		let linear_weight = lbp::calculate_linear_weights(start_x_block, end_x_block, high_weight, low_weight, at_block).unwrap();
+		assert!(weight <= high_weight);
+		assert!(weight + 1 >= linear_weight);
+		assert_eq!(lbp::calculate_step_weights(start_x_block, end_x_block, high_weight, low_weight, steps, end_x_block), Ok(low_weight));
+	}
+}
+
+proptest! {
+	#![proptest_config(ProptestConfig::with_cases(10000))]
+	#[test]
+	fn piecewise_linear_weights_should_stay_within_segment_bounds(
+		start_x_block in start_blocks(),
+		lbp_length in lbp_length(),
+		start_y_weight in initial_weight(),
+		end_y_weight in final_weight(),
+		breakpoint_y_weight in initial_weight(),
+		breakpoint in breakpoint()) {
+		//Arrange
+		let end_x_block = start_x_block.checked_add(lbp_length).unwrap();
+		let at_block = rand::thread_rng().gen_range(start_x_block..end_x_block);
+
+		//Act
+		let weight = lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, breakpoint, breakpoint_y_weight, at_block).unwrap();
+
+		//Assert
+		let lowest = start_y_weight.min(breakpoint_y_weight).min(end_y_weight);
+		let highest = start_y_weight.max(breakpoint_y_weight).max(end_y_weight);
+		assert!(weight >= lowest && weight <= highest);
+
+		let breakpoint_x_block = start_x_block + breakpoint.mul_floor(lbp_length);
+		assert_eq!(lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, breakpoint, breakpoint_y_weight, breakpoint_x_block), Ok(breakpoint_y_weight));
+		assert_eq!(lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, breakpoint, breakpoint_y_weight, start_x_block), Ok(start_y_weight));
+		assert_eq!(lbp::calculate_piecewise_linear_weights(start_x_block, end_x_block, start_y_weight, end_y_weight, breakpoint, breakpoint_y_weight, end_x_block), Ok(end_y_weight));
+	}
+}
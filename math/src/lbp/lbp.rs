@@ -12,7 +12,7 @@ use fixed::types::U32F96;
 use num_traits::{CheckedMul, CheckedSub, Zero};
 use sp_arithmetic;
 use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
-use sp_arithmetic::{FixedPointNumber, FixedU128, Rounding};
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill, Rounding};
 
 /// Calculating spot price given reserve of selling asset and reserve of buying asset.
 /// Formula : BUY_RESERVE * AMOUNT / SELL_RESERVE
@@ -211,6 +211,151 @@ pub fn calculate_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u3
 	to_lbp_weight!(result)
 }
 
+/// Calculating weight at any given block in an interval using exponential decay.
+///
+/// The weight moves from `start_y` towards `end_y` following `e^(-rate * p)`, where `p` is the elapsed
+/// fraction of the interval. The curve is scaled so that it starts exactly at `start_y` and ends exactly at `end_y`.
+/// Higher `rate` moves the weight closer to `end_y` earlier in the interval.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `rate` - steepness of the decay
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_exponential_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	rate: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (elapsed, duration) = elapsed_and_duration(start_x, end_x, at)?;
+
+	// rate 0 would result in 0/0 when normalizing the curve
+	ensure!(rate != 0, DivisionByZero);
+
+	let x = div_to_fixed(
+		(rate as u128).checked_mul(elapsed.into()).ok_or(Overflow)?,
+		duration.into(),
+		Rounding::Down,
+	)
+	.ok_or(Overflow)?;
+
+	let full_decay: U32F96 = crate::transcendental::exp(U32F96::from_num(rate), true).map_err(|_| Overflow)?;
+	let decay: U32F96 = crate::transcendental::exp(x, true).map_err(|_| Overflow)?;
+
+	// share of the weight difference which is still left to move at `at`
+	let remaining = decay
+		.checked_sub(full_decay)
+		.ok_or(Overflow)?
+		.checked_div(U32F96::one().checked_sub(full_decay).ok_or(Overflow)?)
+		.ok_or(Overflow)?;
+
+	let offset = mul_to_balance(start_y.abs_diff(end_y).into(), remaining, Rounding::Down).ok_or(Overflow)?;
+
+	let result = if start_y >= end_y {
+		u128::from(end_y).checked_add(offset)
+	} else {
+		u128::from(end_y).checked_sub(offset)
+	}
+	.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Calculating weight at any given block in an interval which changes in `steps` equal jumps.
+///
+/// The weight is constant within a step and matches the linear interpolation at the step boundaries.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `steps` - number of weight changes within the interval
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_step_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	steps: u32,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (elapsed, duration) = elapsed_and_duration(start_x, end_x, at)?;
+
+	ensure!(steps != 0, DivisionByZero);
+
+	let (start_y, end_y, elapsed, duration, steps) = to_u256!(start_y, end_y, elapsed, duration, steps);
+
+	let completed_steps = elapsed
+		.checked_mul(steps)
+		.ok_or(Overflow)?
+		.checked_div(duration)
+		.ok_or(Overflow)?;
+	let remaining_steps = steps.checked_sub(completed_steps).ok_or(Overflow)?;
+
+	let left_part = start_y.checked_mul(remaining_steps).ok_or(Overflow)?;
+	let right_part = end_y.checked_mul(completed_steps).ok_or(Overflow)?;
+	let result = (left_part.checked_add(right_part).ok_or(Overflow)?)
+		.checked_div(steps)
+		.ok_or(Overflow)?;
+
+	to_lbp_weight!(result)
+}
+
+/// Calculating weight at any given block in an interval using two linear segments.
+///
+/// The weight moves linearly from `start_y` to `breakpoint_y` until the `breakpoint` fraction of the interval
+/// has elapsed, and then linearly from `breakpoint_y` to `end_y`.
+///
+/// - `start_x` - beginning of an interval
+/// - `end_x` - end of an interval
+/// - `start_y` - initial weight
+/// - `end_y` - final weight
+/// - `breakpoint` - fraction of the interval at which the segments meet
+/// - `breakpoint_y` - weight at the breakpoint
+/// - `at` - block number at which to calculate the weight
+pub fn calculate_piecewise_linear_weights<BlockNumber: num_traits::CheckedSub + TryInto<u32>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	start_y: LBPWeight,
+	end_y: LBPWeight,
+	breakpoint: Permill,
+	breakpoint_y: LBPWeight,
+	at: BlockNumber,
+) -> Result<LBPWeight, MathError> {
+	let (elapsed, duration) = elapsed_and_duration(start_x, end_x, at)?;
+
+	let breakpoint_x = breakpoint.mul_floor(duration);
+
+	if elapsed <= breakpoint_x && breakpoint_x != 0 {
+		calculate_linear_weights(0u32, breakpoint_x, start_y, breakpoint_y, elapsed)
+	} else {
+		calculate_linear_weights(breakpoint_x, duration, breakpoint_y, end_y, elapsed)
+	}
+}
+
+/// Returns number of blocks elapsed since `start_x` and the length of the interval.
+fn elapsed_and_duration<BlockNumber: num_traits::CheckedSub + TryInto<u32>>(
+	start_x: BlockNumber,
+	end_x: BlockNumber,
+	at: BlockNumber,
+) -> Result<(u32, u32), MathError> {
+	end_x.checked_sub(&at).ok_or(Overflow)?;
+	let elapsed = at.checked_sub(&start_x).ok_or(Overflow)?;
+	let duration = end_x.checked_sub(&start_x).ok_or(Overflow)?;
+
+	let duration: u32 = duration.try_into().map_err(|_| Overflow)?;
+	// if duration fits into u32, elapsed fits too
+	let elapsed: u32 = elapsed.try_into().map_err(|_| Overflow)?;
+
+	ensure!(duration != 0, ZeroDuration);
+
+	Ok((elapsed, duration))
+}
+
 /// Create a fixed point number based on two `u128` values. Divides the values and rounds according to `r`.
 pub(crate) fn div_to_fixed(num: u128, denom: u128, r: Rounding) -> Option<U32F96> {
 	let bits = multiply_by_rational_with_rounding(num, U32F96::one().to_bits(), denom, r)?;
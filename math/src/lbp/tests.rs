@@ -2,7 +2,8 @@
 use crate::lbp::lbp;
 
 use crate::types::{Balance, LBPWeight, HYDRA_ONE};
-use crate::MathError::{Overflow, ZeroDuration, ZeroReserve};
+use crate::MathError::{DivisionByZero, Overflow, ZeroDuration, ZeroReserve};
+use sp_arithmetic::Permill;
 
 use std::vec;
 
@@ -219,3 +220,309 @@ fn linear_weights_should_work() {
 		);
 	}
 }
+
+#[test]
+fn exponential_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1u32,
+			150u32,
+			Ok(1_377),
+			"Easy decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			1u32,
+			150u32,
+			Ok(1_623),
+			"Easy increasing case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			5u32,
+			150u32,
+			Ok(1_075),
+			"Steep decreasing case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			2_000u32,
+			5u32,
+			150u32,
+			Ok(2_000),
+			"Constant case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			5u32,
+			100u32,
+			Ok(2_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			5u32,
+			200u32,
+			Ok(1_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			0u32,
+			150u32,
+			Err(DivisionByZero),
+			"Zero rate",
+		),
+		(
+			200u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			1u32,
+			150u32,
+			Err(Overflow),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			1u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1u32,
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_exponential_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn step_weights_should_work() {
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			124u32,
+			Ok(2_000),
+			"Within first step",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			125u32,
+			Ok(1_750),
+			"Second step",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			170u32,
+			Ok(1_500),
+			"Third step",
+		),
+		(
+			100u32,
+			200u32,
+			1_000u32,
+			2_000u32,
+			4u32,
+			170u32,
+			Ok(1_500),
+			"Increasing case",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			100u32,
+			Ok(2_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			200u32,
+			Ok(1_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			0u32,
+			150u32,
+			Err(DivisionByZero),
+			"Zero steps",
+		),
+		(
+			100u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			4u32,
+			10u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_step_weights(case.0, case.1, case.2, case.3, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
+
+#[test]
+fn piecewise_linear_weights_should_work() {
+	let breakpoint = Permill::from_percent(50);
+	let cases = vec![
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			125u32,
+			Ok(1_600),
+			"First segment",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			150u32,
+			Ok(1_200),
+			"Breakpoint",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			175u32,
+			Ok(1_100),
+			"Second segment",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			100u32,
+			Ok(2_000),
+			"Initial weight",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			200u32,
+			Ok(1_000),
+			"Final weight",
+		),
+		(
+			100u32,
+			100u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			100u32,
+			Err(ZeroDuration),
+			"Invalid interval",
+		),
+		(
+			100u32,
+			200u32,
+			2_000u32,
+			1_000u32,
+			1_200u32,
+			210u32,
+			Err(Overflow),
+			"Out of bound",
+		),
+	];
+
+	for case in cases {
+		assert_eq!(
+			lbp::calculate_piecewise_linear_weights(case.0, case.1, case.2, case.3, breakpoint, case.4, case.5),
+			case.6,
+			"{}",
+			case.7
+		);
+	}
+}
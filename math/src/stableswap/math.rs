@@ -1,3 +1,4 @@
+use crate::dynamic_fees::types::OracleEntry;
 use crate::stableswap::types::AssetReserve;
 
 use crate::support::rational::round_to_rational;
@@ -198,11 +199,6 @@ pub fn calculate_shares_for_amount<const D: u8>(
 	if asset_idx >= n_coins {
 		return None;
 	}
-	let fixed_fee = FixedU128::from(fee);
-	let fee = fixed_fee
-		.checked_mul(&FixedU128::from(n_coins as u128))?
-		.checked_div(&FixedU128::from(4 * (n_coins - 1) as u128))?;
-
 	let updated_reserves: Vec<AssetReserve> = initial_reserves
 		.iter()
 		.enumerate()
@@ -215,8 +211,34 @@ pub fn calculate_shares_for_amount<const D: u8>(
 		})
 		.collect::<Option<Vec<AssetReserve>>>()?;
 
+	calculate_shares_removed::<D>(initial_reserves, &updated_reserves, amplification, share_issuance, fee)
+}
+
+/// Calculate amount of shares to be burned when LP withdraws liquidity from the pool.
+///
+/// `updated_reserves` are reserves of all pool assets after the withdrawal.
+/// Fee is applied to the difference between the withdrawn amounts and the amounts which would be withdrawn proportionally.
+pub fn calculate_shares_removed<const D: u8>(
+	initial_reserves: &[AssetReserve],
+	updated_reserves: &[AssetReserve],
+	amplification: Balance,
+	share_issuance: Balance,
+	fee: Permill,
+) -> Option<Balance> {
+	if initial_reserves.len() != updated_reserves.len() {
+		return None;
+	}
+	let n_coins = initial_reserves.len();
+	if n_coins <= 1 {
+		return None;
+	}
+	let fixed_fee = FixedU128::from(fee);
+	let fee = fixed_fee
+		.checked_mul(&FixedU128::from(n_coins as u128))?
+		.checked_div(&FixedU128::from(4 * (n_coins - 1) as u128))?;
+
 	let initial_d = calculate_d::<D>(initial_reserves, amplification)?;
-	let updated_d = calculate_d::<D>(&updated_reserves, amplification)?;
+	let updated_d = calculate_d::<D>(updated_reserves, amplification)?;
 	let (d1, d0) = to_u256!(updated_d, initial_d);
 	let adjusted_reserves: Vec<AssetReserve> = updated_reserves
 		.iter()
@@ -614,6 +636,44 @@ pub fn calculate_amplification(
 	}
 }
 
+/// Calculate dynamic trade fee of a pool.
+///
+/// Fee is interpolated between `min_fee` and `max_fee` by the sum of pool imbalance and net volume ratio.
+///
+/// Pool imbalance is the difference between the largest and the smallest normalized reserve relative
+/// to the sum of all reserves. Net volume ratio is the largest `|amount_in - amount_out| / liquidity`
+/// of provided oracle entries.
+pub fn calculate_dynamic_fee(
+	reserves: &[AssetReserve],
+	volumes: &[OracleEntry],
+	min_fee: Permill,
+	max_fee: Permill,
+) -> Option<Permill> {
+	let reserves = normalize_reserves(reserves);
+	let total = reserves.iter().try_fold(0u128, |acc, v| acc.checked_add(*v))?;
+	if total.is_zero() {
+		return Some(min_fee);
+	}
+	let max_reserve = reserves.iter().max()?;
+	let min_reserve = reserves.iter().min()?;
+	let imbalance = FixedU128::checked_from_rational(max_reserve.saturating_sub(*min_reserve), total)?;
+
+	let volume_ratio = volumes
+		.iter()
+		.filter(|entry| !entry.liquidity.is_zero())
+		.map(|entry| {
+			FixedU128::checked_from_rational(entry.amount_in.abs_diff(entry.amount_out), entry.liquidity)
+				.unwrap_or(FixedU128::one())
+		})
+		.max()
+		.unwrap_or_default();
+
+	let intensity = imbalance.saturating_add(volume_ratio).min(FixedU128::one());
+	let intensity = Permill::from_rational(intensity.into_inner(), FixedU128::DIV);
+
+	Some(min_fee.saturating_add(intensity * max_fee.saturating_sub(min_fee)))
+}
+
 #[inline]
 fn has_converged(v0: U256, v1: U256, precision: U256) -> bool {
 	let diff = abs_diff(v0, v1);
@@ -643,7 +703,8 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+/// Convert reserves to the precision used in pool calculations.
+pub fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
 	reserves
 		.iter()
 		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
@@ -0,0 +1,89 @@
+use crate::dynamic_fees::types::OracleEntry;
+use crate::stableswap::calculate_dynamic_fee;
+use crate::stableswap::tests::ONE;
+use crate::stableswap::types::AssetReserve;
+use sp_arithmetic::Permill;
+
+const MIN_FEE: Permill = Permill::from_percent(1);
+const MAX_FEE: Permill = Permill::from_percent(5);
+
+#[test]
+fn dynamic_fee_should_be_min_fee_when_pool_is_balanced_and_there_is_no_volume() {
+	let reserves = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let fee = calculate_dynamic_fee(&reserves, &[], MIN_FEE, MAX_FEE);
+
+	assert_eq!(fee, Some(MIN_FEE));
+}
+
+#[test]
+fn dynamic_fee_should_compare_normalized_reserves() {
+	let reserves = [
+		AssetReserve::new(1_000 * ONE, 12),
+		AssetReserve::new(1_000 * 1_000_000, 6),
+	];
+
+	let fee = calculate_dynamic_fee(&reserves, &[], MIN_FEE, MAX_FEE);
+
+	assert_eq!(fee, Some(MIN_FEE));
+}
+
+#[test]
+fn dynamic_fee_should_increase_when_pool_is_imbalanced() {
+	// imbalance = (3000 - 1000) / 4000 = 0.5
+	let reserves = [AssetReserve::new(3_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+
+	let fee = calculate_dynamic_fee(&reserves, &[], MIN_FEE, MAX_FEE);
+
+	assert_eq!(fee, Some(Permill::from_percent(3)));
+}
+
+#[test]
+fn dynamic_fee_should_increase_with_net_volume() {
+	let reserves = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let volumes = [
+		OracleEntry {
+			amount_in: 100 * ONE,
+			amount_out: 0,
+			liquidity: 1_000 * ONE,
+		},
+		OracleEntry {
+			amount_in: 50 * ONE,
+			amount_out: 300 * ONE,
+			liquidity: 1_000 * ONE,
+		},
+	];
+
+	let fee = calculate_dynamic_fee(&reserves, &volumes, MIN_FEE, MAX_FEE);
+
+	// largest volume ratio is 0.25
+	assert_eq!(fee, Some(Permill::from_percent(2)));
+}
+
+#[test]
+fn dynamic_fee_should_not_exceed_max_fee() {
+	let reserves = [AssetReserve::new(3_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let volumes = [OracleEntry {
+		amount_in: 2_000 * ONE,
+		amount_out: 0,
+		liquidity: 1_000 * ONE,
+	}];
+
+	let fee = calculate_dynamic_fee(&reserves, &volumes, MIN_FEE, MAX_FEE);
+
+	assert_eq!(fee, Some(MAX_FEE));
+}
+
+#[test]
+fn dynamic_fee_should_ignore_volume_when_liquidity_is_zero() {
+	let reserves = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let volumes = [OracleEntry {
+		amount_in: 2_000 * ONE,
+		amount_out: 0,
+		liquidity: 0,
+	}];
+
+	let fee = calculate_dynamic_fee(&reserves, &volumes, MIN_FEE, MAX_FEE);
+
+	assert_eq!(fee, Some(MIN_FEE));
+}
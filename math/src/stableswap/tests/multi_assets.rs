@@ -126,6 +126,76 @@ fn calculate_share_for_amount_should_return_correct_shares_when_fee_applied() {
 	assert_eq!(result, (99999975001371, 50023249592));
 }
 
+#[test]
+fn calculate_shares_removed_should_return_same_shares_as_calculate_shares_for_amount_when_one_asset_is_withdrawn() {
+	let amp = 100_u128;
+
+	let fee = Permill::from_float(0.001);
+
+	let balances = [AssetReserve::new(10_000_000_000_000_000, 12); MAX_BALANCES];
+	let mut updated_balances = balances;
+	updated_balances[0].amount -= 100_000_000_000_000;
+
+	let issuance: Balance = 20_000_000_000_000_000_000_000;
+
+	let result = calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, fee).unwrap();
+
+	assert_eq!(
+		result,
+		calculate_shares_for_amount::<D_ITERATIONS>(&balances, 0, 100_000_000_000_000, amp, issuance, fee).unwrap()
+	);
+}
+
+#[test]
+fn calculate_shares_removed_should_not_apply_fee_when_withdrawal_is_balanced() {
+	let amp = 100_u128;
+
+	let balances = [AssetReserve::new(10_000_000_000_000_000, 12); MAX_BALANCES];
+	let updated_balances = [AssetReserve::new(9_900_000_000_000_000, 12); MAX_BALANCES];
+
+	let issuance: Balance = 20_000_000_000_000_000_000_000;
+
+	let without_fee =
+		calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, Permill::zero()).unwrap();
+	let with_fee =
+		calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, Permill::from_percent(1))
+			.unwrap();
+
+	assert_eq!(without_fee, with_fee);
+	assert!(without_fee.abs_diff(issuance / 100) <= 1);
+}
+
+#[test]
+fn calculate_shares_removed_should_charge_fee_when_withdrawal_is_imbalanced() {
+	let amp = 100_u128;
+
+	let balances = [AssetReserve::new(10_000_000_000_000_000, 12); MAX_BALANCES];
+	let mut updated_balances = balances;
+	updated_balances[0].amount -= 200_000_000_000_000;
+	updated_balances[3].amount -= 50_000_000_000_000;
+
+	let issuance: Balance = 20_000_000_000_000_000_000_000;
+
+	let without_fee =
+		calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, Permill::zero()).unwrap();
+	let with_fee =
+		calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, amp, issuance, Permill::from_percent(1))
+			.unwrap();
+
+	assert!(with_fee > without_fee);
+}
+
+#[test]
+fn calculate_shares_removed_should_fail_when_reserves_length_differs() {
+	let balances = [AssetReserve::new(10_000, 12); MAX_BALANCES];
+	let updated_balances = [AssetReserve::new(10_000, 12); 2];
+
+	assert!(
+		calculate_shares_removed::<D_ITERATIONS>(&balances, &updated_balances, 100, 1_000_000, Permill::zero())
+			.is_none()
+	);
+}
+
 #[test]
 fn calculate_shares_should_work_when_correct_input_provided() {
 	let amp = 100_u128;
@@ -28,7 +28,7 @@ use fc_db::kv::Backend as FrontierBackend;
 pub use fc_rpc::{EthBlockDataCacheTask, StorageOverride, StorageOverrideHandler};
 pub use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
 use fp_rpc::{ConvertTransaction, ConvertTransactionRuntimeApi, EthereumRuntimeRPCApi};
-use hydradx_runtime::{opaque::Block, AccountId, Balance, Index};
+use hydradx_runtime::{opaque::Block, AccountId, AssetId, Balance, BlockNumber, Index};
 use sc_client_api::{
 	backend::{Backend, StateBackend, StorageProvider},
 	client::BlockchainEvents,
@@ -116,10 +116,14 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilderApi<Block>,
+	C::Api: pallet_route_executor_rpc::RouterRuntimeApi<Block, AssetId, Balance>,
+	C::Api: pallet_ema_oracle_rpc::EmaOracleRuntimeApi<Block, BlockNumber>,
 	P: TransactionPool + Sync + Send + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use pallet_ema_oracle_rpc::{EmaOracle, EmaOracleApiServer};
+	use pallet_route_executor_rpc::{RouterQuote, RouterQuoteApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
@@ -134,6 +138,8 @@ where
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(RouterQuote::new(client.clone()).into_rpc())?;
+	module.merge(EmaOracle::new(client.clone()).into_rpc())?;
 	module.merge(StateMigration::new(client, backend, deny_unsafe).into_rpc())?;
 
 	Ok(module)
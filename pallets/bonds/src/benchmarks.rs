@@ -19,16 +19,65 @@
 
 use super::*;
 
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::{assert_ok, traits::EnsureOrigin};
 use frame_system::RawOrigin;
 
+use hydradx_traits::router::{PoolType, TradeExecution};
 use orml_traits::MultiCurrency;
-use primitives::{constants::time::unix_time::MONTH, AssetId, Balance};
+use primitives::{
+	constants::time::unix_time::{DAY, MONTH, WEEK},
+	AssetId, Balance,
+};
 
 pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 pub const ONE: Balance = 1_000_000_000_000;
 pub const HDX: AssetId = 0;
+pub const PAYMENT_ASSET: AssetId = 1_000;
+
+fn issue_bonds<T: Config + pallet_timestamp::Config>() -> Result<(T::AccountId, AssetId), DispatchError>
+where
+	T::Moment: From<u64>,
+{
+	pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+	let origin = T::IssueOrigin::try_successful_origin().unwrap();
+	let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+	let maturity = NOW + 12 * MONTH;
+
+	T::Currency::deposit(HDX, &issuer, (1_000 * ONE).into())?;
+	crate::Pallet::<T>::issue(
+		RawOrigin::Signed(issuer.clone()).into(),
+		HDX,
+		(500 * ONE).into(),
+		maturity,
+	)?;
+
+	let bond_id = crate::Pallet::<T>::bond_id((HDX, maturity)).unwrap();
+
+	Ok((issuer, bond_id))
+}
+
+fn create_pool<T: Config + pallet_timestamp::Config>() -> Result<(T::AccountId, AssetId), DispatchError>
+where
+	T::Moment: From<u64>,
+{
+	let (issuer, bond_id) = issue_bonds::<T>()?;
+
+	crate::Pallet::<T>::create_pool(
+		RawOrigin::Signed(issuer.clone()).into(),
+		bond_id,
+		Permill::from_percent(1),
+		(100 * ONE).into(),
+		(150 * ONE).into(),
+	)?;
+
+	let trader: T::AccountId = account("trader", 0, 0);
+	T::Currency::deposit(HDX, &trader, (100 * ONE).into())?;
+	T::Currency::transfer(bond_id, &issuer, &trader, (100 * ONE).into())?;
+
+	Ok((trader, bond_id))
+}
 
 benchmarks! {
 	 where_clause {
@@ -60,23 +109,209 @@ benchmarks! {
 		let origin = T::IssueOrigin::try_successful_origin().unwrap();
 		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
 		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 12 * MONTH;
+		let coupon = Some(CouponTerms { rate: Permill::from_percent(1), period: MONTH });
 		//NOTE: bonds are insufficient so issuer must ED for it
-		T::Currency::deposit(HDX, &issuer, amount + (100 * ONE).into())?;
-
-		let maturity = NOW + MONTH;
+		T::Currency::deposit(HDX, &issuer, (1_000 * ONE).into())?;
 
+		// coupon-bearing bonds with unclaimed coupons are the worst case
+		T::BenchmarkHelper::register_asset(PAYMENT_ASSET)?;
+		assert_ok!(crate::Pallet::<T>::create_auction(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity, coupon, PAYMENT_ASSET, FixedU128::from_rational(95, 100), FixedU128::from_rational(80, 100), NOW, NOW + WEEK));
 		assert_ok!(crate::Pallet::<T>::issue(RawOrigin::Signed(issuer.clone()).into(), HDX, amount, maturity));
 
 		let fee = <T as Config>::ProtocolFee::get().mul_ceil(amount);
 		let amount_without_fee: T::Balance = amount.checked_sub(&fee).unwrap();
 
-		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 2 * MONTH).into());
+		pallet_timestamp::Pallet::<T>::set_timestamp((maturity + MONTH).into());
 
 		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
 
 	}: _(RawOrigin::Signed(issuer.clone()), bond_id, amount_without_fee)
 	verify {
 		assert_eq!(T::Currency::free_balance(bond_id, &issuer), 0u32.into());
+		assert!(CouponPositions::<T>::get(bond_id, &issuer).is_none());
+	}
+
+	create_auction {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 12 * MONTH;
+		let coupon = Some(CouponTerms { rate: Permill::from_percent(1), period: MONTH });
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		T::BenchmarkHelper::register_asset(PAYMENT_ASSET)?;
+
+	}: _(RawOrigin::Signed(issuer), HDX, (100 * ONE).into(), maturity, coupon, PAYMENT_ASSET, FixedU128::from_rational(95, 100), FixedU128::from_rational(80, 100), NOW, NOW + WEEK)
+	verify {
+		assert!(Auctions::<T>::get(0).is_some());
+	}
+
+	bid {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 12 * MONTH;
+		let coupon = Some(CouponTerms { rate: Permill::from_percent(1), period: MONTH });
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		T::BenchmarkHelper::register_asset(PAYMENT_ASSET)?;
+		assert_ok!(crate::Pallet::<T>::create_auction(RawOrigin::Signed(issuer).into(), HDX, (100 * ONE).into(), maturity, coupon, PAYMENT_ASSET, FixedU128::from_rational(95, 100), FixedU128::from_rational(80, 100), NOW, NOW + WEEK));
+
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		T::Currency::deposit(HDX, &bidder, amount)?;
+		T::Currency::deposit(PAYMENT_ASSET, &bidder, amount)?;
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + DAY).into());
+
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+
+	}: _(RawOrigin::Signed(bidder.clone()), 0, (10 * ONE).into(), (10 * ONE).into())
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &bidder), (10 * ONE).into());
+	}
+
+	close_auction {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 12 * MONTH;
+		let coupon = Some(CouponTerms { rate: Permill::from_percent(1), period: MONTH });
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		T::BenchmarkHelper::register_asset(PAYMENT_ASSET)?;
+		assert_ok!(crate::Pallet::<T>::create_auction(RawOrigin::Signed(issuer.clone()).into(), HDX, (100 * ONE).into(), maturity, coupon, PAYMENT_ASSET, FixedU128::from_rational(95, 100), FixedU128::from_rational(80, 100), NOW, NOW + WEEK));
+
+	}: _(RawOrigin::Signed(issuer), 0)
+	verify {
+		assert!(Auctions::<T>::get(0).is_none());
+	}
+
+	claim_coupons {
+		pallet_timestamp::Pallet::<T>::set_timestamp(NOW.into());
+
+		let origin = T::IssueOrigin::try_successful_origin().unwrap();
+		let issuer = T::IssueOrigin::ensure_origin(origin).unwrap();
+		let amount: T::Balance = (200 * ONE).into();
+		let maturity = NOW + 12 * MONTH;
+		let coupon = Some(CouponTerms { rate: Permill::from_percent(1), period: MONTH });
+
+		T::Currency::deposit(HDX, &issuer, amount)?;
+		T::BenchmarkHelper::register_asset(PAYMENT_ASSET)?;
+		assert_ok!(crate::Pallet::<T>::create_auction(RawOrigin::Signed(issuer).into(), HDX, (100 * ONE).into(), maturity, coupon, PAYMENT_ASSET, FixedU128::from_rational(95, 100), FixedU128::from_rational(80, 100), NOW, NOW + WEEK));
+
+		let bidder: T::AccountId = account("bidder", 0, 0);
+		T::Currency::deposit(HDX, &bidder, amount)?;
+		T::Currency::deposit(PAYMENT_ASSET, &bidder, amount)?;
+		assert_ok!(crate::Pallet::<T>::bid(RawOrigin::Signed(bidder.clone()).into(), 0, (10 * ONE).into(), (10 * ONE).into()));
+
+		let bond_id = Bonds::<T>::iter_keys().next().unwrap();
+		let balance_before = T::Currency::free_balance(HDX, &bidder);
+
+		pallet_timestamp::Pallet::<T>::set_timestamp((NOW + 3 * MONTH).into());
+
+	}: _(RawOrigin::Signed(bidder.clone()), bond_id)
+	verify {
+		assert!(T::Currency::free_balance(HDX, &bidder) > balance_before);
+	}
+
+	create_pool {
+		let (issuer, bond_id) = issue_bonds::<T>()?;
+
+	}: _(RawOrigin::Signed(issuer.clone()), bond_id, Permill::from_percent(1), (100 * ONE).into(), (150 * ONE).into())
+	verify {
+		assert!(Pools::<T>::get(bond_id).is_some());
+	}
+
+	add_liquidity {
+		let (trader, bond_id) = create_pool::<T>()?;
+
+	}: _(RawOrigin::Signed(trader.clone()), bond_id, (10 * ONE).into(), (15 * ONE).into())
+	verify {
+		let share_asset = Pools::<T>::get(bond_id).unwrap().share_asset;
+		assert_eq!(T::Currency::free_balance(share_asset, &trader), (10 * ONE).into());
+	}
+
+	remove_liquidity {
+		let (issuer, bond_id) = issue_bonds::<T>()?;
+		assert_ok!(crate::Pallet::<T>::create_pool(RawOrigin::Signed(issuer.clone()).into(), bond_id, Permill::from_percent(1), (100 * ONE).into(), (150 * ONE).into()));
+
+		// removing the last shares removes the pool
+	}: _(RawOrigin::Signed(issuer.clone()), bond_id, (100 * ONE).into())
+	verify {
+		assert!(Pools::<T>::get(bond_id).is_none());
+	}
+
+	sell {
+		let (trader, bond_id) = create_pool::<T>()?;
+
+		// selling the underlying asset looks up the pool in both directions
+	}: _(RawOrigin::Signed(trader.clone()), HDX, bond_id, (10 * ONE).into(), 0u32.into())
+	verify {
+		assert_eq!(T::Currency::free_balance(HDX, &trader), (90 * ONE).into());
+	}
+
+	buy {
+		let (trader, bond_id) = create_pool::<T>()?;
+
+	}: _(RawOrigin::Signed(trader.clone()), bond_id, HDX, (10 * ONE).into(), (100 * ONE).into())
+	verify {
+		assert_eq!(T::Currency::free_balance(bond_id, &trader), (110 * ONE).into());
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// if c == 1, calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		let (trader, bond_id) = create_pool::<T>()?;
+		let amount: T::Balance = (10 * ONE).into();
+
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance>>::calculate_sell(PoolType::Bonds, HDX, bond_id, amount).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance>>::execute_sell(RawOrigin::Signed(trader.clone()).into(), PoolType::Bonds, HDX, bond_id, amount, 0u32.into()).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(HDX, &trader), (90 * ONE).into());
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..3;	// number of times calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		let (trader, bond_id) = create_pool::<T>()?;
+		let amount: T::Balance = (10 * ONE).into();
+
+	}: {
+		for _ in 1..c {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance>>::calculate_buy(PoolType::Bonds, HDX, bond_id, amount).is_ok());
+		}
+		if e != 0 {
+			assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance>>::execute_buy(RawOrigin::Signed(trader.clone()).into(), PoolType::Bonds, HDX, bond_id, amount, (100 * ONE).into()).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(bond_id, &trader), (110 * ONE).into());
+		}
+	}
+
+	calculate_spot_price_with_fee {
+		let (_, bond_id) = create_pool::<T>()?;
+
+	}: {
+		assert!(<crate::Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance>>::calculate_spot_price_with_fee(PoolType::Bonds, HDX, bond_id).is_ok());
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
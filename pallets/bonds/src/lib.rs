@@ -38,20 +38,53 @@
 //! * Bonds can be both partially or fully redeemed.
 //! * The amount of the underlying asset an account receives is 1:1 to the `amount` of the bonds redeemed.
 //! * Anyone who holds the bonds is able to redeem them.
+//! * Unclaimed coupons of coupon-bearing bonds are paid out when the bonds are redeemed.
+//!
+//! ## Bond auctions
+//! * Bonds can be sold at a discount through a descending-price (Dutch) auction.
+//! * The issuer provides the underlying asset when the auction is created, minus the protocol fee.
+//! * Bonds are sold for a payment asset chosen by the issuer, which needs to be different from the underlying asset.
+//! * The price is the amount of the payment asset paid for one bond and decreases linearly from the start price
+//!   to the floor price during the auction.
+//! * Bidders pay the current price in the payment asset to the issuer and receive newly issued bonds.
+//! * The issuer can close the auction at any time and receives the underlying asset of unsold bonds back.
+//!
+//! ## Coupons
+//! * Coupon schedule is set when the bonds are registered by an auction and can't be changed later.
+//! * A coupon is paid every coupon period until maturity, as a fraction of the face value of the bonds.
+//! * Coupons are paid in the underlying asset and are funded by the issuer when the bonds are issued.
+//! * Coupons accrue to the account the bonds were issued to, by `issue` or in an auction, for the bonds it still
+//!   holds when the coupons are claimed. Transferred bonds don't carry the coupons to the receiver.
+//! * Coupons are settled only by the extrinsics of this pallet, transfers of the bonds are not affected.
+//! * Accounts in `CouponExemptAccounts`, e.g. pool, AMM and pallet accounts, don't accrue coupons.
+//!   Coupons of the bonds issued to them are not funded.
+//! * Coupons of the ended periods are paid out when the bonds are redeemed.
+//!
+//! ## Bond pools
+//! * Bonds can be traded against their underlying asset in a dedicated pool, one pool per bonds.
+//! * The pool uses the YieldSpace invariant, so the price of the bonds converges to the price of the underlying asset
+//!   as the maturity gets closer. Time to maturity of the bonds needs to be shorter than `PoolTimeScale`.
+//! * Liquidity is provided in both assets, proportionally to the pool reserves, and is represented by the pool's
+//!   share asset.
+//! * Trade fee is kept in the pool for the liquidity providers.
+//! * Pools are used by the router via `PoolType::Bonds`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Decode, Encode};
 use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchResult, Get},
 	sp_runtime::{
-		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub},
-		DispatchError, Permill, Saturating,
+		helpers_128bit::multiply_by_rational_with_rounding,
+		traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedMul, CheckedSub, Zero},
+		ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding, RuntimeDebug, Saturating,
 	},
 	traits::{Contains, Time},
 	PalletId,
 };
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use scale_info::TypeInfo;
 use sp_core::MaxEncodedLen;
 use sp_std::{mem, vec::Vec};
 
@@ -68,11 +101,110 @@ mod tests;
 #[cfg(any(feature = "runtime-benchmarks", test))]
 mod benchmarks;
 
+mod trade_execution;
 pub mod weights;
 
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+pub type AuctionId = u32;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId) -> DispatchResult;
+}
+
+/// Coupon terms requested for new bonds.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CouponTerms {
+	/// Coupon paid per period, as a fraction of the face value of the bonds.
+	pub rate: Permill,
+	/// Length of a coupon period in milliseconds.
+	pub period: Moment,
+}
+
+/// Coupon schedule of registered bonds.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CouponSchedule {
+	/// Coupon paid per period, as a fraction of the face value of the bonds.
+	pub rate: Permill,
+	/// Length of a coupon period in milliseconds.
+	pub period: Moment,
+	/// Unix time in milliseconds when the first coupon period starts.
+	pub start: Moment,
+	/// Number of coupon periods until maturity.
+	pub periods: u32,
+}
+
+impl CouponSchedule {
+	pub fn terms(&self) -> CouponTerms {
+		CouponTerms {
+			rate: self.rate,
+			period: self.period,
+		}
+	}
+
+	/// Number of coupon periods which have ended at `now`.
+	pub fn elapsed_periods(&self, now: Moment) -> u32 {
+		let elapsed = now.saturating_sub(self.start) / self.period;
+		elapsed.min(self.periods as Moment) as u32
+	}
+}
+
+/// Coupon-bearing bonds held by an account.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CouponPosition<Balance> {
+	/// Amount of bonds the coupons are paid for.
+	pub amount: Balance,
+	/// Number of coupon periods which have already been paid.
+	pub claimed_periods: u32,
+}
+
+/// Descending-price auction of bonds.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Auction<AccountId, Balance> {
+	pub issuer: AccountId,
+	pub bond_id: AssetId,
+	/// Asset the bonds are paid in.
+	pub payment_asset: AssetId,
+	/// Amount of bonds left for sale.
+	pub remaining: Balance,
+	/// Price of one bond in the payment asset at the start of the auction.
+	pub start_price: FixedU128,
+	/// Price of one bond in the payment asset at the end of the auction.
+	pub floor_price: FixedU128,
+	/// Unix time in milliseconds.
+	pub start: Moment,
+	/// Unix time in milliseconds.
+	pub end: Moment,
+	/// Coupon period from which the coupons of the unsold bonds are funded.
+	pub coupons_funded_from: u32,
+}
+
+impl<AccountId, Balance> Auction<AccountId, Balance> {
+	/// Current price of the bonds, decreasing linearly from `start_price` to `floor_price`.
+	pub fn price_at(&self, now: Moment) -> FixedU128 {
+		let duration = self.end.saturating_sub(self.start);
+		let elapsed = now.saturating_sub(self.start).min(duration);
+
+		let price_range = self.start_price.saturating_sub(self.floor_price);
+		let discount = price_range.saturating_mul(FixedU128::saturating_from_rational(elapsed, duration));
+
+		self.start_price.saturating_sub(discount)
+	}
+}
+
+/// Pool of bonds and their underlying asset.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BondPool {
+	/// Underlying asset of the bonds.
+	pub underlying_asset_id: AssetId,
+	/// Asset representing the liquidity provided to the pool.
+	pub share_asset: AssetId,
+	/// Trade fee of the pool.
+	pub fee: Permill,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -97,7 +229,8 @@ pub mod pallet {
 			+ CheckedSub
 			+ AtLeast32BitUnsigned
 			+ MaxEncodedLen
-			+ From<u128>;
+			+ From<u128>
+			+ Into<u128>;
 
 		/// Multi currency mechanism.
 		type Currency: MultiCurrency<Self::AccountId, CurrencyId = AssetId, Balance = Self::Balance>;
@@ -121,6 +254,9 @@ pub mod pallet {
 		/// Asset types that are permitted to be used as underlying assets.
 		type AssetTypeWhitelist: Contains<AssetKind>;
 
+		/// Accounts which don't accrue coupons, e.g. pool, AMM and pallet accounts.
+		type CouponExemptAccounts: Contains<Self::AccountId>;
+
 		/// Protocol fee.
 		#[pallet::constant]
 		type ProtocolFee: Get<Permill>;
@@ -129,8 +265,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type FeeReceiver: Get<Self::AccountId>;
 
+		/// Time scale of the bond pools in milliseconds.
+		/// Time to maturity of the bonds traded in a pool needs to be shorter.
+		#[pallet::constant]
+		type PoolTimeScale: Get<Moment>;
+
+		/// Minimum amount of each asset in a bond pool.
+		#[pallet::constant]
+		type MinPoolLiquidity: Get<Self::Balance>;
+
+		/// Minimum amount of a trade in a bond pool.
+		#[pallet::constant]
+		type MinTradingLimit: Get<Self::Balance>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
 
 	#[pallet::storage]
@@ -145,6 +297,35 @@ pub mod pallet {
 	#[pallet::getter(fn bond)]
 	pub(super) type Bonds<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, (AssetId, Moment)>;
 
+	#[pallet::storage]
+	/// Coupon schedules of coupon-bearing bonds.
+	/// Maps bond ID -> coupon schedule
+	#[pallet::getter(fn coupons)]
+	pub(super) type Coupons<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, CouponSchedule>;
+
+	#[pallet::storage]
+	/// Coupon-bearing bonds held by accounts.
+	/// Maps (bond ID, account) -> coupon position
+	#[pallet::getter(fn coupon_position)]
+	pub(super) type CouponPositions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, AssetId, Blake2_128Concat, T::AccountId, CouponPosition<T::Balance>>;
+
+	#[pallet::storage]
+	/// Bond auctions in progress.
+	/// Maps auction ID -> auction
+	#[pallet::getter(fn auction)]
+	pub(super) type Auctions<T: Config> = StorageMap<_, Blake2_128Concat, AuctionId, Auction<T::AccountId, T::Balance>>;
+
+	#[pallet::storage]
+	/// Identifier of the next auction.
+	pub(super) type NextAuctionId<T: Config> = StorageValue<_, AuctionId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Bond pools.
+	/// Maps bond ID -> pool
+	#[pallet::getter(fn pool)]
+	pub(super) type Pools<T: Config> = StorageMap<_, Blake2_128Concat, AssetId, BondPool>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -168,6 +349,88 @@ pub mod pallet {
 			bond_id: AssetId,
 			amount: T::Balance,
 		},
+		/// Coupon schedule was set for new bonds
+		CouponScheduleSet {
+			bond_id: AssetId,
+			rate: Permill,
+			period: Moment,
+			periods: u32,
+		},
+		/// Bond auction was created
+		AuctionCreated {
+			auction_id: AuctionId,
+			issuer: T::AccountId,
+			bond_id: AssetId,
+			payment_asset: AssetId,
+			amount: T::Balance,
+			fee: T::Balance,
+			start_price: FixedU128,
+			floor_price: FixedU128,
+			start: Moment,
+			end: Moment,
+		},
+		/// Bonds were bought in an auction
+		AuctionBid {
+			auction_id: AuctionId,
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+			price: FixedU128,
+			paid: T::Balance,
+		},
+		/// Bond auction was closed
+		AuctionClosed { auction_id: AuctionId, unsold: T::Balance },
+		/// Coupons were claimed
+		CouponsClaimed {
+			who: T::AccountId,
+			bond_id: AssetId,
+			amount: T::Balance,
+		},
+		/// Bond pool was created
+		PoolCreated {
+			who: T::AccountId,
+			bond_id: AssetId,
+			share_asset: AssetId,
+			fee: Permill,
+			underlying_amount: T::Balance,
+			bond_amount: T::Balance,
+		},
+		/// Liquidity was added to a bond pool
+		LiquidityAdded {
+			who: T::AccountId,
+			bond_id: AssetId,
+			underlying_amount: T::Balance,
+			bond_amount: T::Balance,
+			shares: T::Balance,
+		},
+		/// Liquidity was removed from a bond pool
+		LiquidityRemoved {
+			who: T::AccountId,
+			bond_id: AssetId,
+			underlying_amount: T::Balance,
+			bond_amount: T::Balance,
+			shares: T::Balance,
+		},
+		/// Sell was executed in a bond pool
+		SellExecuted {
+			who: T::AccountId,
+			bond_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+			fee: T::Balance,
+		},
+		/// Buy was executed in a bond pool
+		BuyExecuted {
+			who: T::AccountId,
+			bond_id: AssetId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+			fee: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -187,6 +450,52 @@ pub mod pallet {
 		InvalidBondName,
 		/// Bond's name parsing was now successful
 		FailToParseName,
+		/// Coupon terms are not valid
+		InvalidCouponTerms,
+		/// Coupon terms don't match the coupon schedule of registered bonds
+		CouponTermsMismatch,
+		/// Auction parameters are not valid
+		InvalidAuction,
+		/// Auction does not exist
+		AuctionNotFound,
+		/// Auction is not in progress
+		AuctionNotActive,
+		/// Account is not the issuer of the auction
+		NotIssuer,
+		/// Amount of bonds is zero
+		ZeroAmount,
+		/// Not enough bonds left for sale in the auction
+		InsufficientBondsForSale,
+		/// Payment for the bonds exceeds the limit
+		PaymentLimitExceeded,
+		/// There are no coupons to claim
+		NothingToClaim,
+		/// Bond pool already exists
+		PoolAlreadyExists,
+		/// Bond pool does not exist
+		PoolNotFound,
+		/// Pool fee is not valid
+		InvalidPoolFee,
+		/// Time to maturity of the bonds is longer than the pool time scale
+		MaturityTooFar,
+		/// Asset pair is not traded in a bond pool
+		InvalidAssetPair,
+		/// Liquidity of the pool would be lower than the minimum pool liquidity
+		InsufficientLiquidity,
+		/// Account does not have enough pool shares
+		InsufficientShares,
+		/// Trade amount is lower than the minimum trading limit
+		InsufficientTradingAmount,
+		/// Amount of the asset exceeds the limit
+		AssetBalanceLimitExceeded,
+		/// Amount out is lower than the limit
+		BuyLimitNotReached,
+		/// Amount in exceeds the limit
+		SellLimitExceeded,
+		/// Calculation of the trade failed
+		TradeCalculationFailed,
+		/// Name of the pool share asset is not valid
+		InvalidShareAssetName,
 	}
 
 	#[pallet::call]
@@ -195,6 +504,8 @@ pub mod pallet {
 		/// New asset id is registered and assigned to the bonds.
 		/// The number of bonds the issuer receives is 1:1 to the `amount` of the underlying asset
 		/// minus the protocol fee.
+		/// When issuing coupon-bearing bonds, the issuer funds the coupons of the remaining coupon periods,
+		/// unless it's in `CouponExemptAccounts`.
 		/// The bond asset is registered with the empty string for the asset name,
 		/// and with the same existential deposit as of the underlying asset.
 		/// Bonds can be redeemed for the underlying asset once mature.
@@ -217,52 +528,27 @@ pub mod pallet {
 		pub fn issue(origin: OriginFor<T>, asset_id: AssetId, amount: T::Balance, maturity: Moment) -> DispatchResult {
 			let who = T::IssueOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				T::AssetTypeWhitelist::contains(
-					&T::AssetRegistry::asset_type(asset_id).ok_or(Error::<T>::AssetNotFound)?
-				),
-				Error::<T>::DisallowedAsset
-			);
-
 			let fee = T::ProtocolFee::get().mul_ceil(amount);
 			let amount_without_fee = amount.saturating_sub(fee);
 			let pallet_account = Self::pallet_account_id();
 
-			let bond_id = match BondIds::<T>::get((asset_id, maturity)) {
-				Some(bond_id) => bond_id,
-				None => {
-					// register new bonds
-					ensure!(maturity >= T::TimestampProvider::now(), Error::<T>::InvalidMaturity);
-
-					let ed = T::ExistentialDeposits::get(&asset_id);
-					let b_name = Self::bond_name(asset_id, maturity);
-					let bond_id = T::AssetRegistry::register_insufficient_asset(
-						None,
-						Some(b_name.try_into().map_err(|_| Error::<T>::InvalidBondName)?),
-						AssetKind::Bond,
-						Some(ed),
-						None,
-						None,
-						None,
-						None,
-					)?;
+			let bond_id = Self::get_or_register_bond(&who, asset_id, maturity, None)?;
 
-					Bonds::<T>::insert(bond_id, (asset_id, maturity));
-					BondIds::<T>::insert((asset_id, maturity), bond_id);
+			T::Currency::transfer(asset_id, &who, &pallet_account, amount_without_fee)?;
+			T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
 
-					Self::deposit_event(Event::TokenCreated {
-						issuer: who.clone(),
-						asset_id,
-						bond_id,
-						maturity,
-					});
+			if let Some(schedule) = Self::coupons(bond_id).filter(|_| !T::CouponExemptAccounts::contains(&who)) {
+				let elapsed_periods = schedule.elapsed_periods(T::TimestampProvider::now());
+				let coupons = Self::coupon_amount(
+					&schedule,
+					amount_without_fee,
+					schedule.periods.saturating_sub(elapsed_periods),
+				)?;
 
-					bond_id
-				}
-			};
+				T::Currency::transfer(asset_id, &who, &pallet_account, coupons)?;
+				Self::add_coupon_position(&who, bond_id, asset_id, &schedule, amount_without_fee)?;
+			}
 
-			T::Currency::transfer(asset_id, &who, &pallet_account, amount_without_fee)?;
-			T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
 			T::Currency::deposit(bond_id, &who, amount_without_fee)?;
 
 			Self::deposit_event(Event::Issued {
@@ -279,6 +565,7 @@ pub mod pallet {
 		/// The amount of the underlying asset the `origin` receives is 1:1 to the `amount` of the bonds.
 		/// Anyone who holds the bonds is able to redeem them.
 		/// Bonds can be both partially or fully redeemed.
+		/// Unclaimed coupons of coupon-bearing bonds are paid out before the bonds are redeemed.
 		///
 		/// Parameters:
 		/// - `origin`: account id
@@ -297,6 +584,10 @@ pub mod pallet {
 			let now = T::TimestampProvider::now();
 			ensure!(now >= maturity, Error::<T>::NotMature);
 
+			if let Some(schedule) = Self::coupons(bond_id) {
+				Self::remove_coupon_position(&who, bond_id, underlying_asset_id, &schedule, amount)?;
+			}
+
 			T::Currency::withdraw(bond_id, &who, amount)?;
 
 			let pallet_account = Self::pallet_account_id();
@@ -306,6 +597,583 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Create a descending-price auction of new bonds.
+		/// The issuer provides the underlying asset for the bonds offered in the auction, minus the protocol fee.
+		/// The bonds are paid in `payment_asset`, which needs to be different from the underlying asset.
+		/// The price of one bond in the payment asset decreases linearly
+		/// from `start_price` at `start` to `floor_price` at `end`.
+		/// When the auction registers new bonds, `coupon` terms are set as their coupon schedule,
+		/// otherwise `coupon` terms need to match the coupon schedule of the registered bonds.
+		/// The issuer funds the coupons of the bonds offered in the auction.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of new bonds, needs to be `T::IssueOrigin`
+		/// - `asset_id`: underlying asset id
+		/// - `amount`: the amount of the underlying asset
+		/// - `maturity`: Unix time in milliseconds, when the bonds will be mature.
+		/// - `coupon`: coupon terms of the bonds, `None` for zero-coupon bonds
+		/// - `payment_asset`: asset id the bonds are paid in
+		/// - `start_price`: price of one bond in the payment asset at the start of the auction
+		/// - `floor_price`: price of one bond in the payment asset at the end of the auction
+		/// - `start`: Unix time in milliseconds, when the auction starts.
+		/// - `end`: Unix time in milliseconds, when the auction ends.
+		///
+		/// Emits `AuctionCreated` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		#[allow(clippy::too_many_arguments)]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			asset_id: AssetId,
+			amount: T::Balance,
+			maturity: Moment,
+			coupon: Option<CouponTerms>,
+			payment_asset: AssetId,
+			start_price: FixedU128,
+			floor_price: FixedU128,
+			start: Moment,
+			end: Moment,
+		) -> DispatchResult {
+			let who = T::IssueOrigin::ensure_origin(origin)?;
+
+			let now = T::TimestampProvider::now();
+			ensure!(
+				!amount.is_zero()
+					&& payment_asset != asset_id
+					&& floor_price > FixedU128::from_inner(0)
+					&& floor_price <= start_price
+					&& now <= start && start < end
+					&& end <= maturity,
+				Error::<T>::InvalidAuction
+			);
+			ensure!(
+				T::AssetRegistry::asset_type(payment_asset).is_some(),
+				Error::<T>::AssetNotFound
+			);
+
+			let bond_id = Self::get_or_register_bond(&who, asset_id, maturity, coupon)?;
+			let schedule = Self::coupons(bond_id);
+			ensure!(schedule.map(|s| s.terms()) == coupon, Error::<T>::CouponTermsMismatch);
+
+			let fee = T::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee = amount.saturating_sub(fee);
+			let pallet_account = Self::pallet_account_id();
+
+			T::Currency::transfer(asset_id, &who, &pallet_account, amount_without_fee)?;
+			T::Currency::transfer(asset_id, &who, &T::FeeReceiver::get(), fee)?;
+
+			let coupons_funded_from = match schedule {
+				Some(schedule) => {
+					let elapsed_periods = schedule.elapsed_periods(now);
+					let coupons = Self::coupon_amount(
+						&schedule,
+						amount_without_fee,
+						schedule.periods.saturating_sub(elapsed_periods),
+					)?;
+					T::Currency::transfer(asset_id, &who, &pallet_account, coupons)?;
+
+					elapsed_periods
+				}
+				None => 0,
+			};
+
+			let auction_id = NextAuctionId::<T>::try_mutate(|next_id| -> Result<AuctionId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			Auctions::<T>::insert(
+				auction_id,
+				Auction {
+					issuer: who.clone(),
+					bond_id,
+					payment_asset,
+					remaining: amount_without_fee,
+					start_price,
+					floor_price,
+					start,
+					end,
+					coupons_funded_from,
+				},
+			);
+
+			Self::deposit_event(Event::AuctionCreated {
+				auction_id,
+				issuer: who,
+				bond_id,
+				payment_asset,
+				amount: amount_without_fee,
+				fee,
+				start_price,
+				floor_price,
+				start,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Buy bonds in an auction at the current price.
+		/// The payment in the payment asset of the auction is transferred to the issuer of the auction
+		/// and the bidder receives newly issued bonds.
+		/// The auction is closed when all bonds are sold.
+		/// Coupons of the bonds bought by an account in `CouponExemptAccounts` are returned to the issuer.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `auction_id`: auction id
+		/// - `amount`: the amount of the bonds to buy
+		/// - `max_payment`: the maximum amount of the payment asset to pay for the bonds
+		///
+		/// Emits `AuctionBid` event when successful.
+		/// Emits `AuctionClosed` event when all bonds were sold.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::bid())]
+		pub fn bid(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			amount: T::Balance,
+			max_payment: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			Auctions::<T>::try_mutate_exists(auction_id, |maybe_auction| -> DispatchResult {
+				let auction = maybe_auction.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+
+				let now = T::TimestampProvider::now();
+				ensure!(auction.start <= now && now < auction.end, Error::<T>::AuctionNotActive);
+				ensure!(amount <= auction.remaining, Error::<T>::InsufficientBondsForSale);
+
+				let price = auction.price_at(now);
+				let payment = Self::mul_div(amount, price.into_inner().into(), FixedU128::DIV.into(), Rounding::Up)?;
+				ensure!(payment <= max_payment, Error::<T>::PaymentLimitExceeded);
+
+				let bond_id = auction.bond_id;
+				let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+				T::Currency::transfer(auction.payment_asset, &who, &auction.issuer, payment)?;
+
+				if let Some(schedule) = Self::coupons(bond_id) {
+					let is_exempt = T::CouponExemptAccounts::contains(&who);
+					// coupons of the periods elapsed since the auction was created are not paid,
+					// exempt accounts don't accrue coupons at all
+					let paid_from = if is_exempt {
+						schedule.periods
+					} else {
+						schedule.elapsed_periods(now)
+					};
+					let unused_coupons =
+						Self::coupon_amount(&schedule, amount, paid_from.saturating_sub(auction.coupons_funded_from))?;
+					T::Currency::transfer(
+						underlying_asset_id,
+						&Self::pallet_account_id(),
+						&auction.issuer,
+						unused_coupons,
+					)?;
+
+					if !is_exempt {
+						Self::add_coupon_position(&who, bond_id, underlying_asset_id, &schedule, amount)?;
+					}
+				}
+
+				T::Currency::deposit(bond_id, &who, amount)?;
+
+				auction.remaining = auction.remaining.saturating_sub(amount);
+
+				Self::deposit_event(Event::AuctionBid {
+					auction_id,
+					who,
+					bond_id,
+					amount,
+					price,
+					paid: payment,
+				});
+
+				if auction.remaining.is_zero() {
+					*maybe_auction = None;
+
+					Self::deposit_event(Event::AuctionClosed {
+						auction_id,
+						unsold: Zero::zero(),
+					});
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Close an auction.
+		/// The underlying asset and the coupons of the unsold bonds are returned to the issuer.
+		/// The auction can be closed by its issuer at any time.
+		///
+		/// Parameters:
+		/// - `origin`: issuer of the auction
+		/// - `auction_id`: auction id
+		///
+		/// Emits `AuctionClosed` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_auction())]
+		pub fn close_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let auction = Self::auction(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(who == auction.issuer, Error::<T>::NotIssuer);
+
+			let (underlying_asset_id, _) = Self::bond(auction.bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			let coupons = match Self::coupons(auction.bond_id) {
+				Some(schedule) => Self::coupon_amount(
+					&schedule,
+					auction.remaining,
+					schedule.periods.saturating_sub(auction.coupons_funded_from),
+				)?,
+				None => Zero::zero(),
+			};
+
+			T::Currency::transfer(
+				underlying_asset_id,
+				&Self::pallet_account_id(),
+				&who,
+				auction
+					.remaining
+					.checked_add(&coupons)
+					.ok_or(ArithmeticError::Overflow)?,
+			)?;
+
+			Auctions::<T>::remove(auction_id);
+
+			Self::deposit_event(Event::AuctionClosed {
+				auction_id,
+				unsold: auction.remaining,
+			});
+
+			Ok(())
+		}
+
+		/// Claim coupons of coupon-bearing bonds.
+		/// Coupons are paid in the underlying asset for all ended coupon periods which have not been claimed yet.
+		/// Coupons are paid for the bonds held by the account.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		///
+		/// Emits `CouponsClaimed` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_coupons())]
+		pub fn claim_coupons(origin: OriginFor<T>, bond_id: AssetId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (underlying_asset_id, _) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+			let schedule = Self::coupons(bond_id).ok_or(Error::<T>::NothingToClaim)?;
+
+			let claimed = Self::do_claim_coupons(&who, bond_id, underlying_asset_id, &schedule)?;
+			ensure!(!claimed.is_zero(), Error::<T>::NothingToClaim);
+
+			Ok(())
+		}
+
+		/// Create a pool of bonds and their underlying asset.
+		/// The pool uses the YieldSpace invariant, so the price of the bonds converges to the price
+		/// of the underlying asset as the maturity gets closer.
+		/// The initial amounts of the assets determine the initial price of the bonds.
+		/// The creator receives pool shares equal to `underlying_amount`.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `fee`: trade fee of the pool
+		/// - `underlying_amount`: the amount of the underlying asset provided as initial liquidity
+		/// - `bond_amount`: the amount of the bonds provided as initial liquidity
+		///
+		/// Emits `PoolCreated` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			fee: Permill,
+			underlying_amount: T::Balance,
+			bond_amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Pools::<T>::contains_key(bond_id), Error::<T>::PoolAlreadyExists);
+			ensure!(fee < Permill::one(), Error::<T>::InvalidPoolFee);
+
+			let (underlying_asset_id, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+
+			let now = T::TimestampProvider::now();
+			ensure!(now < maturity, Error::<T>::InvalidMaturity);
+			ensure!(
+				maturity.saturating_sub(now) < T::PoolTimeScale::get(),
+				Error::<T>::MaturityTooFar
+			);
+
+			ensure!(
+				underlying_amount >= T::MinPoolLiquidity::get() && bond_amount >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidity
+			);
+
+			// share asset is kept registered when the pool is removed and reused when the pool is created again
+			let share_asset = T::AssetRegistry::get_or_register_insufficient_asset(
+				Self::pool_share_name(bond_id)
+					.try_into()
+					.map_err(|_| Error::<T>::InvalidShareAssetName)?,
+				AssetKind::XYK,
+				Some(T::ExistentialDeposits::get(&underlying_asset_id)),
+				None,
+				None,
+				None,
+				None,
+			)?;
+
+			let pool_account = Self::pool_account_id(bond_id);
+			T::Currency::transfer(underlying_asset_id, &who, &pool_account, underlying_amount)?;
+			T::Currency::transfer(bond_id, &who, &pool_account, bond_amount)?;
+			T::Currency::deposit(share_asset, &who, underlying_amount)?;
+
+			Pools::<T>::insert(
+				bond_id,
+				BondPool {
+					underlying_asset_id,
+					share_asset,
+					fee,
+				},
+			);
+
+			Self::deposit_event(Event::PoolCreated {
+				who,
+				bond_id,
+				share_asset,
+				fee,
+				underlying_amount,
+				bond_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Add liquidity to a bond pool.
+		/// The amount of the bonds is calculated from `underlying_amount` to keep the ratio of the pool reserves.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `underlying_amount`: the amount of the underlying asset to add
+		/// - `max_bond_amount`: the maximum amount of the bonds to add
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity())]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			bond_id: AssetId,
+			underlying_amount: T::Balance,
+			max_bond_amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!underlying_amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let pool = Self::pool(bond_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			let pool_account = Self::pool_account_id(bond_id);
+			let underlying_reserve = T::Currency::free_balance(pool.underlying_asset_id, &pool_account);
+			let bond_reserve = T::Currency::free_balance(bond_id, &pool_account);
+			let total_shares = T::Currency::total_issuance(pool.share_asset);
+
+			let bond_amount = Self::mul_div(underlying_amount, bond_reserve, underlying_reserve, Rounding::Up)?;
+			ensure!(bond_amount <= max_bond_amount, Error::<T>::AssetBalanceLimitExceeded);
+
+			let shares = Self::mul_div(underlying_amount, total_shares, underlying_reserve, Rounding::Down)?;
+			ensure!(!shares.is_zero(), Error::<T>::ZeroAmount);
+
+			T::Currency::transfer(pool.underlying_asset_id, &who, &pool_account, underlying_amount)?;
+			T::Currency::transfer(bond_id, &who, &pool_account, bond_amount)?;
+			T::Currency::deposit(pool.share_asset, &who, shares)?;
+
+			Self::deposit_event(Event::LiquidityAdded {
+				who,
+				bond_id,
+				underlying_amount,
+				bond_amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from a bond pool.
+		/// The account receives both assets proportionally to the pool reserves.
+		/// The pool is removed when all shares are removed.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `bond_id`: bond asset id
+		/// - `shares`: the amount of the pool shares to remove
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
+		pub fn remove_liquidity(origin: OriginFor<T>, bond_id: AssetId, shares: T::Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!shares.is_zero(), Error::<T>::ZeroAmount);
+
+			let pool = Self::pool(bond_id).ok_or(Error::<T>::PoolNotFound)?;
+
+			ensure!(
+				shares <= T::Currency::free_balance(pool.share_asset, &who),
+				Error::<T>::InsufficientShares
+			);
+
+			let pool_account = Self::pool_account_id(bond_id);
+			let underlying_reserve = T::Currency::free_balance(pool.underlying_asset_id, &pool_account);
+			let bond_reserve = T::Currency::free_balance(bond_id, &pool_account);
+			let total_shares = T::Currency::total_issuance(pool.share_asset);
+
+			let underlying_amount = Self::mul_div(underlying_reserve, shares, total_shares, Rounding::Down)?;
+			let bond_amount = Self::mul_div(bond_reserve, shares, total_shares, Rounding::Down)?;
+
+			let remaining_shares = total_shares.saturating_sub(shares);
+			if !remaining_shares.is_zero() {
+				ensure!(
+					underlying_reserve.saturating_sub(underlying_amount) >= T::MinPoolLiquidity::get()
+						&& bond_reserve.saturating_sub(bond_amount) >= T::MinPoolLiquidity::get(),
+					Error::<T>::InsufficientLiquidity
+				);
+			}
+
+			T::Currency::withdraw(pool.share_asset, &who, shares)?;
+			T::Currency::transfer(pool.underlying_asset_id, &pool_account, &who, underlying_amount)?;
+			T::Currency::transfer(bond_id, &pool_account, &who, bond_amount)?;
+
+			if remaining_shares.is_zero() {
+				Pools::<T>::remove(bond_id);
+			}
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				who,
+				bond_id,
+				underlying_amount,
+				bond_amount,
+				shares,
+			});
+
+			Ok(())
+		}
+
+		/// Sell bonds for the underlying asset or the underlying asset for bonds in a bond pool.
+		/// Trade fee is deducted from the amount out.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `asset_in`: asset id of the sold asset
+		/// - `asset_out`: asset id of the bought asset
+		/// - `amount`: the amount of `asset_in` to sell
+		/// - `min_buy_amount`: the minimum amount of `asset_out` to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell())]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: T::Balance,
+			min_buy_amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bond_id = Self::pool_bond_id(asset_in, asset_out).ok_or(Error::<T>::InvalidAssetPair)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (amount_out, fee) = Self::calculate_sell_amount(bond_id, asset_in, asset_out, amount)?;
+			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			let pool_account = Self::pool_account_id(bond_id);
+			T::Currency::transfer(asset_in, &who, &pool_account, amount)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount_out)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				bond_id,
+				asset_in,
+				asset_out,
+				amount_in: amount,
+				amount_out,
+				fee,
+			});
+
+			Ok(())
+		}
+
+		/// Buy bonds for the underlying asset or the underlying asset for bonds in a bond pool.
+		/// Trade fee is added to the amount in.
+		///
+		/// Parameters:
+		/// - `origin`: account id
+		/// - `asset_out`: asset id of the bought asset
+		/// - `asset_in`: asset id of the sold asset
+		/// - `amount`: the amount of `asset_out` to buy
+		/// - `max_sell_amount`: the maximum amount of `asset_in` to pay
+		///
+		/// Emits `BuyExecuted` event when successful.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: AssetId,
+			asset_in: AssetId,
+			amount: T::Balance,
+			max_sell_amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bond_id = Self::pool_bond_id(asset_in, asset_out).ok_or(Error::<T>::InvalidAssetPair)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (amount_in, fee) = Self::calculate_buy_amount(bond_id, asset_in, asset_out, amount)?;
+			ensure!(amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+
+			let pool_account = Self::pool_account_id(bond_id);
+			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
+			T::Currency::transfer(asset_out, &pool_account, &who, amount)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				bond_id,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out: amount,
+				fee,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -318,6 +1186,299 @@ impl<T: Config> Pallet<T> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The account ID of the bond pool.
+	pub fn pool_account_id(bond_id: AssetId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(bond_id)
+	}
+
+	/// Return id of the bonds traded in a pool with the given assets.
+	pub fn pool_bond_id(asset_a: AssetId, asset_b: AssetId) -> Option<AssetId> {
+		let is_pool = |bond_id: AssetId, asset_id: AssetId| {
+			Self::pool(bond_id).map(|pool| pool.underlying_asset_id) == Some(asset_id)
+		};
+
+		if is_pool(asset_a, asset_b) {
+			Some(asset_a)
+		} else if is_pool(asset_b, asset_a) {
+			Some(asset_b)
+		} else {
+			None
+		}
+	}
+
+	/// Return reserves of the traded assets and time to maturity of the bonds in a bond pool.
+	fn pool_state(
+		bond_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+	) -> Result<(T::Balance, T::Balance, Moment), DispatchError> {
+		let (_, maturity) = Self::bond(bond_id).ok_or(Error::<T>::NotRegistered)?;
+		let pool_account = Self::pool_account_id(bond_id);
+
+		let in_reserve = T::Currency::free_balance(asset_in, &pool_account);
+		let out_reserve = T::Currency::free_balance(asset_out, &pool_account);
+		let time_to_maturity = maturity.saturating_sub(T::TimestampProvider::now());
+
+		Ok((in_reserve, out_reserve, time_to_maturity))
+	}
+
+	/// Calculate the amount out of a sell in a bond pool.
+	/// Returns the amount out without the trade fee and the fee.
+	pub fn calculate_sell_amount(
+		bond_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: T::Balance,
+	) -> Result<(T::Balance, T::Balance), DispatchError> {
+		let pool = Self::pool(bond_id).ok_or(Error::<T>::PoolNotFound)?;
+		let (in_reserve, out_reserve, time_to_maturity) = Self::pool_state(bond_id, asset_in, asset_out)?;
+
+		let amount_out: T::Balance = hydra_dx_math::bonds::calculate_out_given_in(
+			in_reserve.into(),
+			out_reserve.into(),
+			amount_in.into(),
+			time_to_maturity,
+			T::PoolTimeScale::get(),
+		)
+		.map_err(|_| Error::<T>::TradeCalculationFailed)?
+		.into();
+
+		let fee = pool.fee.mul_ceil(amount_out);
+		let amount_out_without_fee = amount_out.checked_sub(&fee).ok_or(Error::<T>::TradeCalculationFailed)?;
+		ensure!(!amount_out_without_fee.is_zero(), Error::<T>::InsufficientTradingAmount);
+
+		Ok((amount_out_without_fee, fee))
+	}
+
+	/// Calculate the amount in of a buy in a bond pool.
+	/// Returns the amount in including the trade fee and the fee.
+	pub fn calculate_buy_amount(
+		bond_id: AssetId,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: T::Balance,
+	) -> Result<(T::Balance, T::Balance), DispatchError> {
+		let pool = Self::pool(bond_id).ok_or(Error::<T>::PoolNotFound)?;
+		let (in_reserve, out_reserve, time_to_maturity) = Self::pool_state(bond_id, asset_in, asset_out)?;
+
+		ensure!(amount_out < out_reserve, Error::<T>::InsufficientLiquidity);
+
+		let amount_in: T::Balance = hydra_dx_math::bonds::calculate_in_given_out(
+			in_reserve.into(),
+			out_reserve.into(),
+			amount_out.into(),
+			time_to_maturity,
+			T::PoolTimeScale::get(),
+		)
+		.map_err(|_| Error::<T>::TradeCalculationFailed)?
+		.into();
+
+		let fee = pool.fee.mul_ceil(amount_in);
+		let amount_in_with_fee = amount_in.checked_add(&fee).ok_or(ArithmeticError::Overflow)?;
+
+		Ok((amount_in_with_fee, fee))
+	}
+
+	/// Multiply `a` by `b` and divide by `c`, rounding the result by `rounding`.
+	fn mul_div(a: T::Balance, b: T::Balance, c: T::Balance, rounding: Rounding) -> Result<T::Balance, DispatchError> {
+		multiply_by_rational_with_rounding(a.into(), b.into(), c.into(), rounding)
+			.map(Into::into)
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	/// Return id of the bonds with given underlying asset and maturity.
+	/// New bonds are registered with the `coupon` terms if they don't exist yet.
+	fn get_or_register_bond(
+		issuer: &T::AccountId,
+		asset_id: AssetId,
+		maturity: Moment,
+		coupon: Option<CouponTerms>,
+	) -> Result<AssetId, DispatchError> {
+		ensure!(
+			T::AssetTypeWhitelist::contains(&T::AssetRegistry::asset_type(asset_id).ok_or(Error::<T>::AssetNotFound)?),
+			Error::<T>::DisallowedAsset
+		);
+
+		if let Some(bond_id) = BondIds::<T>::get((asset_id, maturity)) {
+			return Ok(bond_id);
+		}
+
+		// register new bonds
+		let now = T::TimestampProvider::now();
+		ensure!(maturity >= now, Error::<T>::InvalidMaturity);
+
+		let schedule = coupon
+			.map(|terms| -> Result<CouponSchedule, DispatchError> {
+				ensure!(
+					terms.rate > Permill::from_parts(0) && terms.period > 0,
+					Error::<T>::InvalidCouponTerms
+				);
+
+				let periods = maturity.saturating_sub(now) / terms.period;
+				let periods = u32::try_from(periods).map_err(|_| Error::<T>::InvalidCouponTerms)?;
+				ensure!(periods > 0, Error::<T>::InvalidCouponTerms);
+
+				Ok(CouponSchedule {
+					rate: terms.rate,
+					period: terms.period,
+					start: now,
+					periods,
+				})
+			})
+			.transpose()?;
+
+		let ed = T::ExistentialDeposits::get(&asset_id);
+		let b_name = Self::bond_name(asset_id, maturity);
+		let bond_id = T::AssetRegistry::register_insufficient_asset(
+			None,
+			Some(b_name.try_into().map_err(|_| Error::<T>::InvalidBondName)?),
+			AssetKind::Bond,
+			Some(ed),
+			None,
+			None,
+			None,
+			None,
+		)?;
+
+		Bonds::<T>::insert(bond_id, (asset_id, maturity));
+		BondIds::<T>::insert((asset_id, maturity), bond_id);
+
+		Self::deposit_event(Event::TokenCreated {
+			issuer: issuer.clone(),
+			asset_id,
+			bond_id,
+			maturity,
+		});
+
+		if let Some(schedule) = schedule {
+			Coupons::<T>::insert(bond_id, schedule);
+
+			Self::deposit_event(Event::CouponScheduleSet {
+				bond_id,
+				rate: schedule.rate,
+				period: schedule.period,
+				periods: schedule.periods,
+			});
+		}
+
+		Ok(bond_id)
+	}
+
+	/// Coupons paid for `amount` of bonds for `periods` coupon periods.
+	fn coupon_amount(schedule: &CouponSchedule, amount: T::Balance, periods: u32) -> Result<T::Balance, DispatchError> {
+		schedule
+			.rate
+			.mul_floor(amount)
+			.checked_mul(&periods.into())
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	/// Add newly acquired bonds to the coupon position of `who`.
+	/// Coupons of the already ended periods are paid out before the position is increased.
+	/// Needs to be called before the bonds are deposited to `who`.
+	fn add_coupon_position(
+		who: &T::AccountId,
+		bond_id: AssetId,
+		underlying_asset_id: AssetId,
+		schedule: &CouponSchedule,
+		amount: T::Balance,
+	) -> DispatchResult {
+		Self::do_claim_coupons(who, bond_id, underlying_asset_id, schedule)?;
+
+		let claimed_periods = schedule.elapsed_periods(T::TimestampProvider::now());
+
+		CouponPositions::<T>::try_mutate(bond_id, who, |maybe_position| -> DispatchResult {
+			let position = maybe_position.get_or_insert(CouponPosition {
+				amount: Zero::zero(),
+				claimed_periods,
+			});
+
+			position.amount = position.amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
+
+			Ok(())
+		})
+	}
+
+	/// Remove bonds leaving the account of `who` from its coupon position.
+	/// Coupons of the already ended periods are paid out before the position is decreased.
+	/// Needs to be called before the bonds are withdrawn from `who`.
+	/// Returns the amount of the coupon-bearing bonds removed from the position.
+	fn remove_coupon_position(
+		who: &T::AccountId,
+		bond_id: AssetId,
+		underlying_asset_id: AssetId,
+		schedule: &CouponSchedule,
+		amount: T::Balance,
+	) -> Result<T::Balance, DispatchError> {
+		Self::do_claim_coupons(who, bond_id, underlying_asset_id, schedule)?;
+
+		CouponPositions::<T>::try_mutate_exists(bond_id, who, |maybe_position| -> Result<T::Balance, DispatchError> {
+			let Some(position) = maybe_position else {
+				return Ok(Zero::zero());
+			};
+
+			// bonds without coupons leave the account first
+			let remaining_balance = T::Currency::free_balance(bond_id, who).saturating_sub(amount);
+			let removed = position.amount.saturating_sub(remaining_balance);
+
+			position.amount = position.amount.saturating_sub(removed);
+			if position.amount.is_zero() {
+				*maybe_position = None;
+			}
+
+			Ok(removed)
+		})
+	}
+
+	/// Pay coupons of the ended coupon periods to `who`.
+	/// Coupons are paid only for bonds of the coupon position which are still held by `who`.
+	fn do_claim_coupons(
+		who: &T::AccountId,
+		bond_id: AssetId,
+		underlying_asset_id: AssetId,
+		schedule: &CouponSchedule,
+	) -> Result<T::Balance, DispatchError> {
+		let elapsed_periods = schedule.elapsed_periods(T::TimestampProvider::now());
+
+		let coupons = CouponPositions::<T>::try_mutate_exists(
+			bond_id,
+			who,
+			|maybe_position| -> Result<T::Balance, DispatchError> {
+				let Some(position) = maybe_position else {
+					return Ok(Zero::zero());
+				};
+
+				let eligible_amount = position.amount.min(T::Currency::free_balance(bond_id, who));
+				let coupons = Self::coupon_amount(
+					schedule,
+					eligible_amount,
+					elapsed_periods.saturating_sub(position.claimed_periods),
+				)?;
+
+				position.amount = eligible_amount;
+				position.claimed_periods = elapsed_periods;
+
+				if position.amount.is_zero() {
+					*maybe_position = None;
+				}
+
+				Ok(coupons)
+			},
+		)?;
+
+		if !coupons.is_zero() {
+			T::Currency::transfer(underlying_asset_id, &Self::pallet_account_id(), who, coupons)?;
+
+			Self::deposit_event(Event::CouponsClaimed {
+				who: who.clone(),
+				bond_id,
+				amount: coupons,
+			});
+		}
+
+		Ok(coupons)
+	}
+
 	/// Return bond token name
 	pub fn bond_name(asset_id: AssetId, when: Moment) -> Vec<u8> {
 		let mut buf: Vec<u8> = Vec::new();
@@ -329,6 +1490,16 @@ impl<T: Config> Pallet<T> {
 		buf
 	}
 
+	/// Name of the share asset of a bond pool.
+	pub fn pool_share_name(bond_id: AssetId) -> Vec<u8> {
+		let mut buf: Vec<u8> = Vec::new();
+
+		buf.extend_from_slice(b"pool.");
+		buf.extend_from_slice(&bond_id.to_le_bytes());
+
+		buf
+	}
+
 	pub fn parse_bond_name(name: Vec<u8>) -> Result<AssetId, Error<T>> {
 		Ok(AssetId::from_le_bytes(
 			name[..mem::size_of::<AssetId>()]
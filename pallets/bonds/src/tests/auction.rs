@@ -0,0 +1,599 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+fn price(percent: u128) -> FixedU128 {
+	FixedU128::from_rational(percent, 100)
+}
+
+fn payment(price: FixedU128, amount: Balance) -> Balance {
+	price.checked_mul_int(amount).unwrap()
+}
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(CHARLIE, DAI, INITIAL_BALANCE)])
+}
+
+#[test]
+fn create_auction_should_work_when_fee_is_zero() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let maturity = NOW + MONTH;
+		let amount = 100 * ONE;
+
+		// Act
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			maturity,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::TokenCreated {
+				issuer: ALICE,
+				asset_id: HDX,
+				bond_id,
+				maturity,
+			}
+			.into(),
+			Event::AuctionCreated {
+				auction_id: 0,
+				issuer: ALICE,
+				bond_id,
+				payment_asset: DAI,
+				amount,
+				fee: 0,
+				start_price: price(95),
+				floor_price: price(80),
+				start: NOW,
+				end: NOW + WEEK,
+			}
+			.into(),
+		]);
+
+		assert_eq!(
+			Bonds::auction(0),
+			Some(Auction {
+				issuer: ALICE,
+				bond_id,
+				payment_asset: DAI,
+				remaining: amount,
+				start_price: price(95),
+				floor_price: price(80),
+				start: NOW,
+				end: NOW + WEEK,
+				coupons_funded_from: 0,
+			})
+		);
+		assert_eq!(Bonds::coupons(bond_id), None);
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), 0);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), amount);
+	});
+}
+
+#[test]
+fn create_auction_should_work_when_fee_is_non_zero() {
+	ext()
+		.with_protocol_fee(Permill::from_percent(10))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let maturity = NOW + MONTH;
+			let amount = 100 * ONE;
+			let fee = <Test as Config>::ProtocolFee::get().mul_ceil(amount);
+			let amount_without_fee: Balance = amount.checked_sub(fee).unwrap();
+
+			// Act
+			let bond_id = next_asset_id();
+			assert_ok!(Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			));
+
+			// Assert
+			expect_events(vec![Event::AuctionCreated {
+				auction_id: 0,
+				issuer: ALICE,
+				bond_id,
+				payment_asset: DAI,
+				amount: amount_without_fee,
+				fee,
+				start_price: price(95),
+				floor_price: price(80),
+				start: NOW,
+				end: NOW + WEEK,
+			}
+			.into()]);
+
+			assert_eq!(Bonds::auction(0).unwrap().remaining, amount_without_fee);
+
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+			assert_eq!(Tokens::free_balance(HDX, &<Test as Config>::FeeReceiver::get()), fee);
+			assert_eq!(
+				Tokens::free_balance(HDX, &Bonds::pallet_account_id()),
+				amount_without_fee
+			);
+		});
+}
+
+#[test]
+fn create_auction_should_fail_when_parameters_are_invalid() {
+	ext().build().execute_with(|| {
+		let maturity = NOW + MONTH;
+		let amount = 100 * ONE;
+
+		// zero amount
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				0,
+				maturity,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidAuction
+		);
+
+		// floor price higher than start price
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				DAI,
+				price(80),
+				price(95),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidAuction
+		);
+
+		// zero floor price
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				DAI,
+				price(95),
+				price(0),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidAuction
+		);
+
+		// bonds paid in the underlying asset
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				HDX,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidAuction
+		);
+
+		// auction ends before it starts
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW + WEEK,
+				NOW
+			),
+			Error::<Test>::InvalidAuction
+		);
+
+		// auction ends after maturity
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				amount,
+				maturity,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				maturity + 1
+			),
+			Error::<Test>::InvalidAuction
+		);
+	});
+}
+
+#[test]
+fn create_auction_should_fail_when_payment_asset_is_not_registered() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
+
+#[test]
+fn create_auction_should_fail_when_called_from_wrong_origin() {
+	ext()
+		.add_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Bonds::create_auction(
+					RuntimeOrigin::signed(CHARLIE),
+					HDX,
+					100 * ONE,
+					NOW + MONTH,
+					None,
+					DAI,
+					price(95),
+					price(80),
+					NOW,
+					NOW + WEEK
+				),
+				DispatchError::BadOrigin
+			);
+		});
+}
+
+#[test]
+fn bid_should_work_when_price_decreases() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let bid_amount = 10 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW,
+			NOW + WEEK
+		));
+
+		Timestamp::set_timestamp(NOW + WEEK / 2);
+
+		// Act
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		// Assert
+		let current_price = FixedU128::from_rational(875, 1_000);
+		let paid = payment(current_price, bid_amount);
+
+		expect_events(vec![Event::AuctionBid {
+			auction_id: 0,
+			who: CHARLIE,
+			bond_id,
+			amount: bid_amount,
+			price: current_price,
+			paid,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::auction(0).unwrap().remaining, amount - bid_amount);
+
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), INITIAL_BALANCE - paid);
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), bid_amount);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), paid);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), amount);
+	});
+}
+
+#[test]
+fn bid_should_work_when_price_is_higher_than_one() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(250),
+			price(250),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Act
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, 25 * ONE));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), INITIAL_BALANCE - 25 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 25 * ONE);
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), bid_amount);
+	});
+}
+
+#[test]
+fn bid_should_close_auction_when_all_bonds_are_sold() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+
+		let bond_id = next_asset_id();
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(90),
+			price(90),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Act
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, amount, amount));
+
+		// Assert
+		expect_events(vec![Event::AuctionClosed {
+			auction_id: 0,
+			unsold: 0,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::auction(0), None);
+		assert_eq!(Tokens::free_balance(bond_id, &CHARLIE), amount);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), payment(price(90), amount));
+	});
+}
+
+#[test]
+fn bid_should_fail_when_auction_is_not_active() {
+	ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW + DAY,
+			NOW + WEEK
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, ONE, ONE),
+			Error::<Test>::AuctionNotActive
+		);
+
+		Timestamp::set_timestamp(NOW + WEEK);
+
+		assert_noop!(
+			Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, ONE, ONE),
+			Error::<Test>::AuctionNotActive
+		);
+	});
+}
+
+#[test]
+fn bid_should_fail_when_auction_does_not_exist() {
+	ext().build().execute_with(|| {
+		assert_noop!(
+			Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, ONE, ONE),
+			Error::<Test>::AuctionNotFound
+		);
+	});
+}
+
+#[test]
+fn bid_should_fail_when_amount_is_invalid() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, 0, ONE),
+			Error::<Test>::ZeroAmount
+		);
+		assert_noop!(
+			Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, amount + 1, 2 * amount),
+			Error::<Test>::InsufficientBondsForSale
+		);
+	});
+}
+
+#[test]
+fn bid_should_fail_when_payment_exceeds_limit() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::bid(
+				RuntimeOrigin::signed(CHARLIE),
+				0,
+				bid_amount,
+				payment(price(95), bid_amount) - 1
+			),
+			Error::<Test>::PaymentLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn close_auction_should_return_unsold_bonds_to_issuer() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let bid_amount = 10 * ONE;
+
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(90),
+			price(90),
+			NOW,
+			NOW + WEEK
+		));
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		// Act
+		assert_ok!(Bonds::close_auction(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		expect_events(vec![Event::AuctionClosed {
+			auction_id: 0,
+			unsold: amount - bid_amount,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::auction(0), None);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - bid_amount);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), payment(price(90), bid_amount));
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), bid_amount);
+	});
+}
+
+#[test]
+fn close_auction_should_fail_when_called_by_other_account() {
+	ext().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Bonds::create_auction(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100 * ONE,
+			NOW + MONTH,
+			None,
+			DAI,
+			price(95),
+			price(80),
+			NOW,
+			NOW + WEEK
+		));
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::close_auction(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::NotIssuer
+		);
+		assert_noop!(
+			Bonds::close_auction(RuntimeOrigin::signed(ALICE), 1),
+			Error::<Test>::AuctionNotFound
+		);
+	});
+}
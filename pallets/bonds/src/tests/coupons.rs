@@ -0,0 +1,498 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+pub use pretty_assertions::assert_eq;
+
+const COUPON: CouponTerms = CouponTerms {
+	rate: Permill::from_percent(1),
+	period: MONTH,
+};
+
+fn price(percent: u128) -> FixedU128 {
+	FixedU128::from_rational(percent, 100)
+}
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(DAI, NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token)
+		.add_endowed_accounts(vec![(CHARLIE, DAI, INITIAL_BALANCE), (CHARLIE, HDX, INITIAL_BALANCE)])
+}
+
+fn create_coupon_auction(amount: Balance, start_price: FixedU128, end: Moment) -> AssetId {
+	let bond_id = next_asset_id();
+	assert_ok!(Bonds::create_auction(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		amount,
+		NOW + 12 * MONTH,
+		Some(COUPON),
+		DAI,
+		start_price,
+		start_price,
+		NOW,
+		end
+	));
+	bond_id
+}
+
+#[test]
+fn create_auction_should_set_coupon_schedule_when_bonds_are_registered() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let coupons = 12 * Permill::from_percent(1).mul_floor(amount);
+
+		// Act
+		let bond_id = create_coupon_auction(amount, price(90), NOW + WEEK);
+
+		// Assert
+		expect_events(vec![Event::CouponScheduleSet {
+			bond_id,
+			rate: COUPON.rate,
+			period: COUPON.period,
+			periods: 12,
+		}
+		.into()]);
+
+		assert_eq!(
+			Bonds::coupons(bond_id),
+			Some(CouponSchedule {
+				rate: COUPON.rate,
+				period: COUPON.period,
+				start: NOW,
+				periods: 12,
+			})
+		);
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount - coupons);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), amount + coupons);
+	});
+}
+
+#[test]
+fn create_auction_should_fail_when_coupon_terms_are_invalid() {
+	ext().build().execute_with(|| {
+		// coupon period longer than maturity
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				Some(CouponTerms {
+					rate: Permill::from_percent(1),
+					period: 2 * MONTH,
+				}),
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidCouponTerms
+		);
+
+		// zero coupon rate
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				100 * ONE,
+				NOW + MONTH,
+				Some(CouponTerms {
+					rate: Permill::from_parts(0),
+					period: WEEK,
+				}),
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::InvalidCouponTerms
+		);
+	});
+}
+
+#[test]
+fn create_auction_should_fail_when_coupon_terms_do_not_match_registered_bonds() {
+	ext().build().execute_with(|| {
+		// Arrange
+		create_coupon_auction(10 * ONE, price(90), NOW + WEEK);
+
+		// Act & Assert
+		assert_noop!(
+			Bonds::create_auction(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				10 * ONE,
+				NOW + 12 * MONTH,
+				None,
+				DAI,
+				price(95),
+				price(80),
+				NOW,
+				NOW + WEEK
+			),
+			Error::<Test>::CouponTermsMismatch
+		);
+	});
+}
+
+#[test]
+fn claim_coupons_should_pay_coupons_of_ended_periods() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+		let coupon = Permill::from_percent(1).mul_floor(bid_amount);
+
+		let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		Timestamp::set_timestamp(NOW + 3 * MONTH + DAY);
+
+		// Act
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id));
+
+		// Assert
+		expect_events(vec![Event::CouponsClaimed {
+			who: CHARLIE,
+			bond_id,
+			amount: 3 * coupon,
+		}
+		.into()]);
+
+		assert_eq!(
+			Bonds::coupon_position(bond_id, CHARLIE),
+			Some(CouponPosition {
+				amount: bid_amount,
+				claimed_periods: 3,
+			})
+		);
+		assert_eq!(Tokens::free_balance(HDX, &CHARLIE), INITIAL_BALANCE + 3 * coupon);
+
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn claim_coupons_should_pay_all_coupons_when_bonds_are_mature() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+		let coupon = Permill::from_percent(1).mul_floor(bid_amount);
+
+		let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+		assert_ok!(Bonds::close_auction(RuntimeOrigin::signed(ALICE), 0));
+
+		Timestamp::set_timestamp(NOW + 24 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id));
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(CHARLIE), bond_id, bid_amount));
+
+		// Assert
+		expect_events(vec![Event::CouponsClaimed {
+			who: CHARLIE,
+			bond_id,
+			amount: 12 * coupon,
+		}
+		.into()]);
+
+		// all coupons and bonds of the auction were paid out
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+	});
+}
+
+#[test]
+fn redeem_should_pay_unclaimed_coupons() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+		let redeem_amount = 4 * ONE;
+		let coupon = Permill::from_percent(1).mul_floor(bid_amount);
+
+		let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+		assert_ok!(Bonds::close_auction(RuntimeOrigin::signed(ALICE), 0));
+
+		Timestamp::set_timestamp(NOW + 24 * MONTH);
+
+		// Act
+		assert_ok!(Bonds::redeem(RuntimeOrigin::signed(CHARLIE), bond_id, redeem_amount));
+
+		// Assert
+		expect_events(vec![
+			Event::CouponsClaimed {
+				who: CHARLIE,
+				bond_id,
+				amount: 12 * coupon,
+			}
+			.into(),
+			Event::Redeemed {
+				who: CHARLIE,
+				bond_id,
+				amount: redeem_amount,
+			}
+			.into(),
+		]);
+
+		assert_eq!(
+			Tokens::free_balance(HDX, &CHARLIE),
+			INITIAL_BALANCE + 12 * coupon + redeem_amount
+		);
+		assert_eq!(
+			Bonds::coupon_position(bond_id, CHARLIE),
+			Some(CouponPosition {
+				amount: bid_amount - redeem_amount,
+				claimed_periods: 12,
+			})
+		);
+
+		assert_ok!(Bonds::redeem(
+			RuntimeOrigin::signed(CHARLIE),
+			bond_id,
+			bid_amount - redeem_amount
+		));
+
+		// all coupons and bonds of the auction were paid out
+		assert_eq!(Bonds::coupon_position(bond_id, CHARLIE), None);
+		assert_eq!(Tokens::free_balance(HDX, &Bonds::pallet_account_id()), 0);
+	});
+}
+
+#[test]
+fn transfer_should_not_move_coupon_position() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 10 * ONE;
+		let transferred = 4 * ONE;
+
+		let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		Timestamp::set_timestamp(NOW + MONTH + DAY);
+
+		// Act
+		assert_ok!(Tokens::transfer(
+			RuntimeOrigin::signed(CHARLIE),
+			BOB,
+			bond_id,
+			transferred
+		));
+
+		// Assert
+		assert_eq!(
+			Bonds::coupon_position(bond_id, CHARLIE),
+			Some(CouponPosition {
+				amount: bid_amount,
+				claimed_periods: 0,
+			})
+		);
+		assert_eq!(Bonds::coupon_position(bond_id, BOB), None);
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH + DAY);
+
+		// coupons are paid only for the bonds still held by the account
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id));
+		expect_events(vec![Event::CouponsClaimed {
+			who: CHARLIE,
+			bond_id,
+			amount: 2 * Permill::from_percent(1).mul_floor(bid_amount - transferred),
+		}
+		.into()]);
+
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(BOB), bond_id),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn bonds_provided_to_pool_should_not_accrue_coupons() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let bid_amount = 100 * ONE;
+		let pool_liquidity = 50 * ONE;
+
+		let bond_id = create_coupon_auction(bid_amount, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+		assert_ok!(Bonds::create_pool(
+			RuntimeOrigin::signed(CHARLIE),
+			bond_id,
+			Permill::from_percent(1),
+			pool_liquidity,
+			pool_liquidity
+		));
+		let pool_account = Bonds::pool_account_id(bond_id);
+
+		Timestamp::set_timestamp(NOW + MONTH + DAY);
+
+		// Act
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id));
+
+		// Assert
+		expect_events(vec![Event::CouponsClaimed {
+			who: CHARLIE,
+			bond_id,
+			amount: Permill::from_percent(1).mul_floor(bid_amount - pool_liquidity),
+		}
+		.into()]);
+
+		assert_eq!(Bonds::coupon_position(bond_id, pool_account), None);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account), pool_liquidity);
+		assert_eq!(Tokens::free_balance(bond_id, &pool_account), pool_liquidity);
+	});
+}
+
+#[test]
+fn bid_should_return_all_coupons_to_issuer_when_bidder_is_exempt() {
+	ext()
+		.add_endowed_accounts(vec![(TREASURY, DAI, INITIAL_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let bid_amount = 10 * ONE;
+			let coupons = 12 * Permill::from_percent(1).mul_floor(bid_amount);
+
+			let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+			let alice_balance = Tokens::free_balance(HDX, &ALICE);
+
+			// Act
+			assert_ok!(Bonds::bid(RuntimeOrigin::signed(TREASURY), 0, bid_amount, bid_amount));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_balance + coupons);
+			assert_eq!(Tokens::free_balance(bond_id, &TREASURY), bid_amount);
+			assert_eq!(Bonds::coupon_position(bond_id, TREASURY), None);
+
+			Timestamp::set_timestamp(NOW + MONTH + DAY);
+
+			assert_noop!(
+				Bonds::claim_coupons(RuntimeOrigin::signed(TREASURY), bond_id),
+				Error::<Test>::NothingToClaim
+			);
+		});
+}
+
+#[test]
+fn bid_should_return_coupons_of_ended_periods_to_issuer() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let bid_amount = 10 * ONE;
+		let coupon = Permill::from_percent(1).mul_floor(bid_amount);
+		let paid = price(90).checked_mul_int(bid_amount).unwrap();
+
+		let bond_id = create_coupon_auction(amount, price(90), NOW + 2 * MONTH);
+		let alice_balance = Tokens::free_balance(HDX, &ALICE);
+
+		Timestamp::set_timestamp(NOW + MONTH + DAY);
+
+		// Act
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		// Assert
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), alice_balance + coupon);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), paid);
+		assert_eq!(
+			Bonds::coupon_position(bond_id, CHARLIE),
+			Some(CouponPosition {
+				amount: bid_amount,
+				claimed_periods: 1,
+			})
+		);
+		assert_noop!(
+			Bonds::claim_coupons(RuntimeOrigin::signed(CHARLIE), bond_id),
+			Error::<Test>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn close_auction_should_return_coupons_of_unsold_bonds_to_issuer() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 100 * ONE;
+		let bid_amount = 10 * ONE;
+		let coupons = 12 * Permill::from_percent(1).mul_floor(bid_amount);
+
+		create_coupon_auction(amount, price(90), NOW + WEEK);
+		assert_ok!(Bonds::bid(RuntimeOrigin::signed(CHARLIE), 0, bid_amount, bid_amount));
+
+		// Act
+		assert_ok!(Bonds::close_auction(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - bid_amount - coupons
+		);
+		assert_eq!(
+			Tokens::free_balance(HDX, &Bonds::pallet_account_id()),
+			bid_amount + coupons
+		);
+	});
+}
+
+#[test]
+fn issue_should_fund_coupons_when_bonds_are_coupon_bearing() {
+	ext().build().execute_with(|| {
+		// Arrange
+		let amount = 10 * ONE;
+		let coupon = Permill::from_percent(1).mul_floor(amount);
+
+		let bond_id = create_coupon_auction(100 * ONE, price(90), NOW + WEEK);
+		assert_ok!(Bonds::close_auction(RuntimeOrigin::signed(ALICE), 0));
+
+		Timestamp::set_timestamp(NOW + 2 * MONTH + DAY);
+
+		// Act
+		assert_ok!(Bonds::issue(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			amount,
+			NOW + 12 * MONTH
+		));
+
+		// Assert
+		assert_eq!(
+			Tokens::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - amount - 10 * coupon
+		);
+		assert_eq!(
+			Bonds::coupon_position(bond_id, ALICE),
+			Some(CouponPosition {
+				amount,
+				claimed_periods: 2,
+			})
+		);
+
+		Timestamp::set_timestamp(NOW + 3 * MONTH + DAY);
+
+		assert_ok!(Bonds::claim_coupons(RuntimeOrigin::signed(ALICE), bond_id));
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), INITIAL_BALANCE - amount - 9 * coupon);
+	});
+}
@@ -32,7 +32,7 @@ use sp_runtime::BoundedVec;
 use std::{cell::RefCell, collections::HashMap};
 
 use hydradx_traits::registry::{Create, Inspect};
-use orml_traits::parameter_type_with_key;
+use orml_traits::{currency::MutationHooks, parameter_type_with_key};
 pub use primitives::constants::{
 	currency::NATIVE_EXISTENTIAL_DEPOSIT,
 	time::{
@@ -56,6 +56,7 @@ pub const INITIAL_BALANCE: Balance = 1_000 * ONE;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
 pub const TREASURY: AccountId = 400;
 
 pub const NOW: Moment = 1689844300000; // unix time in milliseconds
@@ -63,6 +64,8 @@ pub const NOW: Moment = 1689844300000; // unix time in milliseconds
 thread_local! {
 	// maps AssetId -> existential deposit
 	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, (Balance, AssetKind)>> = RefCell::new(HashMap::default());
+	// maps asset name -> AssetId
+	pub static REGISTERED_NAMES: RefCell<HashMap<Vec<u8>, AssetId>> = RefCell::new(HashMap::default());
 	pub static PROTOCOL_FEE: RefCell<Permill> = const { RefCell::new(Permill::from_percent(0)) };
 }
 
@@ -80,6 +83,9 @@ parameter_types! {
 	pub ProtocolFee: Permill = PROTOCOL_FEE.with(|v| *v.borrow());
 	pub TreasuryAccount: AccountId = TREASURY;
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const PoolTimeScale: Moment = 5 * 365 * DAY;
+	pub const MinPoolLiquidity: Balance = 1_000_000;
+	pub const MinTradingLimit: Balance = 1_000;
 }
 
 parameter_type_with_key! {
@@ -102,6 +108,13 @@ impl Contains<AssetKind> for AssetTypeWhitelist {
 	}
 }
 
+pub struct CouponExemptAccounts;
+impl Contains<AccountId> for CouponExemptAccounts {
+	fn contains(a: &AccountId) -> bool {
+		*a == TREASURY
+	}
+}
+
 impl pallet_bonds::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Balance = Balance;
@@ -112,9 +125,15 @@ impl pallet_bonds::Config for Test {
 	type PalletId = BondsPalletId;
 	type IssueOrigin = EnsureSignedBy<AliceOrBob, AccountId>;
 	type AssetTypeWhitelist = AssetTypeWhitelist;
+	type CouponExemptAccounts = CouponExemptAccounts;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type PoolTimeScale = PoolTimeScale;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
 	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry<Test>;
 }
 
 impl frame_system::Config for Test {
@@ -156,7 +175,7 @@ impl orml_tokens::Config for Test {
 	type CurrencyId = AssetId;
 	type WeightInfo = ();
 	type ExistentialDeposits = ExistentialDeposits;
-	type CurrencyHooks = ();
+	type CurrencyHooks = CurrencyHooks;
 	type MaxLocks = ();
 	type MaxReserves = ();
 	type ReserveIdentifier = ();
@@ -174,8 +193,31 @@ impl pallet_timestamp::Config for Test {
 	type WeightInfo = ();
 }
 
+pub struct CurrencyHooks;
+impl MutationHooks<AccountId, AssetId, Balance> for CurrencyHooks {
+	type OnDust = ();
+	type OnSlash = ();
+	type PreDeposit = ();
+	type PostDeposit = ();
+	type PreTransfer = ();
+	type PostTransfer = ();
+	type OnNewTokenAccount = ();
+	type OnKilledTokenAccount = ();
+}
+
 pub struct DummyRegistry<T>(sp_std::marker::PhantomData<T>);
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: Config> BenchmarkHelper<AssetId> for DummyRegistry<T> {
+	fn register_asset(asset_id: AssetId) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut()
+				.insert(asset_id, (NATIVE_EXISTENTIAL_DEPOSIT, AssetKind::Token));
+		});
+		Ok(())
+	}
+}
+
 impl<T: Config> Create<Balance> for DummyRegistry<T> {
 	type Error = DispatchError;
 	type Name = BoundedVec<u8, ConstU32<20>>;
@@ -214,16 +256,26 @@ impl<T: Config> Create<Balance> for DummyRegistry<T> {
 		Ok(assigned)
 	}
 	fn get_or_register_asset(
-		_name: Self::Name,
-		_kind: AssetKind,
-		_existential_deposit: Option<Balance>,
+		name: Self::Name,
+		kind: AssetKind,
+		existential_deposit: Option<Balance>,
 		_symbol: Option<Self::Symbol>,
 		_decimals: Option<u8>,
 		_location: Option<Self::Location>,
 		_xcm_rate_limit: Option<Balance>,
 		_is_sufficient: bool,
 	) -> Result<Self::AssetId, Self::Error> {
-		unimplemented!()
+		if let Some(asset_id) = REGISTERED_NAMES.with(|v| v.borrow().get(&name.to_vec()).cloned()) {
+			return Ok(asset_id);
+		}
+
+		let assigned = REGISTERED_ASSETS.with(|v| {
+			let l = v.borrow().len();
+			v.borrow_mut().insert(l as u32, (existential_deposit.unwrap(), kind));
+			l as u32
+		});
+		REGISTERED_NAMES.with(|v| v.borrow_mut().insert(name.to_vec(), assigned));
+		Ok(assigned)
 	}
 }
 
@@ -1,5 +1,8 @@
+mod auction;
+mod coupons;
 mod issue;
 pub mod mock;
+mod pool;
 mod redeem;
 #[allow(clippy::module_inception)]
 mod tests;
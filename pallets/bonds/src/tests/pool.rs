@@ -0,0 +1,544 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::tests::mock::*;
+use crate::*;
+pub type Bonds = Pallet<Test>;
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
+pub use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+const UNDERLYING_LIQUIDITY: Balance = 100 * ONE;
+const BOND_LIQUIDITY: Balance = 150 * ONE;
+
+fn issue_bonds(maturity: Moment) -> AssetId {
+	let bond_id = next_asset_id();
+	assert_ok!(Bonds::issue(RuntimeOrigin::signed(ALICE), HDX, 500 * ONE, maturity));
+	assert_ok!(Tokens::transfer(RuntimeOrigin::signed(ALICE), BOB, bond_id, 100 * ONE));
+	bond_id
+}
+
+fn create_pool(maturity: Moment, fee: Permill) -> AssetId {
+	let bond_id = issue_bonds(maturity);
+	assert_ok!(Bonds::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		bond_id,
+		fee,
+		UNDERLYING_LIQUIDITY,
+		BOND_LIQUIDITY
+	));
+	bond_id
+}
+
+fn test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts(vec![(BOB, HDX, INITIAL_BALANCE)])
+		.build()
+}
+
+#[test]
+fn create_pool_should_work() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = issue_bonds(NOW + 365 * DAY);
+		let fee = Permill::from_percent(1);
+		let share_asset = next_asset_id();
+
+		// Act
+		assert_ok!(Bonds::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			fee,
+			UNDERLYING_LIQUIDITY,
+			BOND_LIQUIDITY
+		));
+
+		// Assert
+		expect_events(vec![Event::PoolCreated {
+			who: ALICE,
+			bond_id,
+			share_asset,
+			fee,
+			underlying_amount: UNDERLYING_LIQUIDITY,
+			bond_amount: BOND_LIQUIDITY,
+		}
+		.into()]);
+
+		assert_eq!(
+			Bonds::pool(bond_id),
+			Some(BondPool {
+				underlying_asset_id: HDX,
+				share_asset,
+				fee,
+			})
+		);
+		assert_eq!(Tokens::free_balance(share_asset, &ALICE), UNDERLYING_LIQUIDITY);
+		assert_eq!(Tokens::total_issuance(share_asset), UNDERLYING_LIQUIDITY);
+
+		let pool_account = Bonds::pool_account_id(bond_id);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account), UNDERLYING_LIQUIDITY);
+		assert_eq!(Tokens::free_balance(bond_id, &pool_account), BOND_LIQUIDITY);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_already_exists() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(BOB),
+				bond_id,
+				Permill::from_percent(1),
+				UNDERLYING_LIQUIDITY,
+				UNDERLYING_LIQUIDITY
+			),
+			Error::<Test>::PoolAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_parameters_are_invalid() {
+	test_ext().execute_with(|| {
+		let bond_id = issue_bonds(NOW + 365 * DAY);
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				Permill::from_percent(100),
+				UNDERLYING_LIQUIDITY,
+				BOND_LIQUIDITY
+			),
+			Error::<Test>::InvalidPoolFee
+		);
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				Permill::from_percent(1),
+				UNDERLYING_LIQUIDITY,
+				BOND_LIQUIDITY
+			),
+			Error::<Test>::NotRegistered
+		);
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				Permill::from_percent(1),
+				<Test as Config>::MinPoolLiquidity::get() - 1,
+				BOND_LIQUIDITY
+			),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_maturity_is_too_far() {
+	test_ext().execute_with(|| {
+		let bond_id = issue_bonds(NOW + <Test as Config>::PoolTimeScale::get());
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				Permill::from_percent(1),
+				UNDERLYING_LIQUIDITY,
+				BOND_LIQUIDITY
+			),
+			Error::<Test>::MaturityTooFar
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_bonds_are_mature() {
+	test_ext().execute_with(|| {
+		let maturity = NOW + 365 * DAY;
+		let bond_id = issue_bonds(maturity);
+
+		Timestamp::set_timestamp(maturity);
+
+		assert_noop!(
+			Bonds::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				bond_id,
+				Permill::from_percent(1),
+				UNDERLYING_LIQUIDITY,
+				BOND_LIQUIDITY
+			),
+			Error::<Test>::InvalidMaturity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_keep_ratio_of_reserves() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let amount = 10 * ONE;
+		let expected_bond_amount = 15 * ONE;
+
+		// Act
+		assert_ok!(Bonds::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			bond_id,
+			amount,
+			expected_bond_amount
+		));
+
+		// Assert
+		expect_events(vec![Event::LiquidityAdded {
+			who: BOB,
+			bond_id,
+			underlying_amount: amount,
+			bond_amount: expected_bond_amount,
+			shares: amount,
+		}
+		.into()]);
+
+		let share_asset = Bonds::pool(bond_id).unwrap().share_asset;
+		assert_eq!(Tokens::free_balance(share_asset, &BOB), amount);
+		assert_eq!(Tokens::total_issuance(share_asset), UNDERLYING_LIQUIDITY + amount);
+
+		let pool_account = Bonds::pool_account_id(bond_id);
+		assert_eq!(Tokens::free_balance(HDX, &pool_account), UNDERLYING_LIQUIDITY + amount);
+		assert_eq!(
+			Tokens::free_balance(bond_id, &pool_account),
+			BOND_LIQUIDITY + expected_bond_amount
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_bond_amount_exceeds_limit() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_noop!(
+			Bonds::add_liquidity(RuntimeOrigin::signed(BOB), bond_id, 10 * ONE, 15 * ONE - 1),
+			Error::<Test>::AssetBalanceLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_remove_pool_when_all_shares_are_removed() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let share_asset = Bonds::pool(bond_id).unwrap().share_asset;
+		let hdx_balance = Tokens::free_balance(HDX, &ALICE);
+		let bond_balance = Tokens::free_balance(bond_id, &ALICE);
+
+		// Act
+		assert_ok!(Bonds::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			UNDERLYING_LIQUIDITY
+		));
+
+		// Assert
+		expect_events(vec![Event::LiquidityRemoved {
+			who: ALICE,
+			bond_id,
+			underlying_amount: UNDERLYING_LIQUIDITY,
+			bond_amount: BOND_LIQUIDITY,
+			shares: UNDERLYING_LIQUIDITY,
+		}
+		.into()]);
+
+		assert_eq!(Bonds::pool(bond_id), None);
+		assert_eq!(Tokens::free_balance(share_asset, &ALICE), 0);
+		assert_eq!(Tokens::total_issuance(share_asset), 0);
+
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), hdx_balance + UNDERLYING_LIQUIDITY);
+		assert_eq!(Tokens::free_balance(bond_id, &ALICE), bond_balance + BOND_LIQUIDITY);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_shares_are_insufficient() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_noop!(
+			Bonds::remove_liquidity(RuntimeOrigin::signed(BOB), bond_id, ONE),
+			Error::<Test>::InsufficientShares
+		);
+		assert_noop!(
+			Bonds::remove_liquidity(RuntimeOrigin::signed(ALICE), bond_id, UNDERLYING_LIQUIDITY + 1),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn sell_bonds_should_price_bonds_below_par() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let amount = 10 * ONE;
+		let hdx_balance = Tokens::free_balance(HDX, &BOB);
+
+		let (expected, fee) = Bonds::calculate_sell_amount(bond_id, bond_id, HDX, amount).unwrap();
+		assert_eq!(
+			<Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				PoolType::Bonds,
+				bond_id,
+				HDX,
+				amount,
+			),
+			Ok(expected)
+		);
+
+		// Act
+		assert_ok!(Bonds::sell(RuntimeOrigin::signed(BOB), bond_id, HDX, amount, expected));
+
+		// Assert
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			bond_id,
+			asset_in: bond_id,
+			asset_out: HDX,
+			amount_in: amount,
+			amount_out: expected,
+			fee,
+		}
+		.into()]);
+
+		let amount_out = Tokens::free_balance(HDX, &BOB) - hdx_balance;
+		assert_eq!(amount_out, expected);
+		assert!(amount_out < amount);
+		assert!(fee > 0);
+
+		let pool_account = Bonds::pool_account_id(bond_id);
+		assert_eq!(
+			Tokens::free_balance(HDX, &pool_account),
+			UNDERLYING_LIQUIDITY - amount_out
+		);
+		assert_eq!(Tokens::free_balance(bond_id, &pool_account), BOND_LIQUIDITY + amount);
+	});
+}
+
+#[test]
+fn buy_bonds_should_price_bonds_below_par() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let amount = 10 * ONE;
+		let hdx_balance = Tokens::free_balance(HDX, &BOB);
+
+		let expected = <Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+			PoolType::Bonds,
+			HDX,
+			bond_id,
+			amount,
+		)
+		.unwrap();
+
+		// Act
+		assert_ok!(Bonds::buy(RuntimeOrigin::signed(BOB), bond_id, HDX, amount, expected));
+
+		// Assert
+		let amount_in = hdx_balance - Tokens::free_balance(HDX, &BOB);
+		assert_eq!(amount_in, expected);
+		assert!(amount_in < amount);
+
+		let pool_account = Bonds::pool_account_id(bond_id);
+		assert_eq!(
+			Tokens::free_balance(HDX, &pool_account),
+			UNDERLYING_LIQUIDITY + amount_in
+		);
+		assert_eq!(Tokens::free_balance(bond_id, &pool_account), BOND_LIQUIDITY - amount);
+	});
+}
+
+#[test]
+fn sell_should_converge_to_par_at_maturity() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let maturity = NOW + 365 * DAY;
+		let bond_id = create_pool(maturity, Permill::from_percent(0));
+		let amount = 10 * ONE;
+
+		let before_maturity = Bonds::calculate_sell_amount(bond_id, bond_id, HDX, amount).unwrap().0;
+
+		// Act
+		Timestamp::set_timestamp(maturity);
+		let at_maturity = Bonds::calculate_sell_amount(bond_id, bond_id, HDX, amount).unwrap().0;
+
+		// Assert
+		assert!(before_maturity < at_maturity);
+		assert!(at_maturity <= amount);
+		assert!(amount - at_maturity <= 2);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_limits_are_not_respected() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_noop!(
+			Bonds::sell(RuntimeOrigin::signed(BOB), bond_id, HDX, 10 * ONE, 10 * ONE),
+			Error::<Test>::BuyLimitNotReached
+		);
+		assert_noop!(
+			Bonds::buy(RuntimeOrigin::signed(BOB), bond_id, HDX, 10 * ONE, ONE),
+			Error::<Test>::SellLimitExceeded
+		);
+		assert_noop!(
+			Bonds::sell(
+				RuntimeOrigin::signed(BOB),
+				bond_id,
+				HDX,
+				<Test as Config>::MinTradingLimit::get() - 1,
+				0
+			),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_assets_are_not_in_pool() {
+	test_ext().execute_with(|| {
+		let bond_id = issue_bonds(NOW + 365 * DAY);
+
+		assert_noop!(
+			Bonds::sell(RuntimeOrigin::signed(BOB), bond_id, HDX, 10 * ONE, 0),
+			Error::<Test>::InvalidAssetPair
+		);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_support_other_pool_types() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_eq!(
+			<Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				PoolType::XYK,
+				bond_id,
+				HDX,
+				ONE,
+			),
+			Err(ExecutorError::NotSupported)
+		);
+	});
+}
+
+#[test]
+fn trade_execution_should_fail_when_amount_is_below_trading_limit() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let amount = <Test as Config>::MinTradingLimit::get() - 1;
+
+		assert_eq!(
+			<Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_sell(
+				PoolType::Bonds,
+				bond_id,
+				HDX,
+				amount,
+			),
+			Err(ExecutorError::Error(Error::<Test>::InsufficientTradingAmount.into()))
+		);
+		assert_eq!(
+			<Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_buy(
+				PoolType::Bonds,
+				HDX,
+				bond_id,
+				amount,
+			),
+			Err(ExecutorError::Error(Error::<Test>::InsufficientTradingAmount.into()))
+		);
+	});
+}
+
+#[test]
+fn spot_price_should_be_below_par_before_maturity() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		let price =
+			<Bonds as TradeExecution<RuntimeOrigin, AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(
+				PoolType::Bonds,
+				HDX,
+				bond_id,
+			)
+			.unwrap();
+
+		assert!(price < FixedU128::from(1));
+	});
+}
+
+#[test]
+fn pools_provider_should_return_pools() {
+	test_ext().execute_with(|| {
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+
+		assert_eq!(Bonds::pools(u32::MAX), vec![(PoolType::Bonds, vec![HDX, bond_id])]);
+	});
+}
+
+#[test]
+fn pools_provider_should_return_pools_within_max_assets() {
+	test_ext().execute_with(|| {
+		assert_ok!(Tokens::deposit(HDX, &ALICE, 1_000 * ONE));
+		create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		create_pool(NOW + 300 * DAY, Permill::from_percent(1));
+
+		assert_eq!(Bonds::pools(3).len(), 1);
+		assert_eq!(Bonds::pools(4).len(), 2);
+	});
+}
+
+#[test]
+fn create_pool_should_reuse_share_asset_when_pool_is_created_again() {
+	test_ext().execute_with(|| {
+		// Arrange
+		let bond_id = create_pool(NOW + 365 * DAY, Permill::from_percent(1));
+		let share_asset = Bonds::pool(bond_id).unwrap().share_asset;
+		assert_ok!(Bonds::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			UNDERLYING_LIQUIDITY
+		));
+
+		// Act
+		assert_ok!(Bonds::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			bond_id,
+			Permill::from_percent(1),
+			UNDERLYING_LIQUIDITY,
+			BOND_LIQUIDITY
+		));
+
+		// Assert
+		assert_eq!(Bonds::pool(bond_id).unwrap().share_asset, share_asset);
+		assert_eq!(Tokens::free_balance(share_asset, &ALICE), UNDERLYING_LIQUIDITY);
+	});
+}
@@ -35,3 +35,24 @@ fn parse_name_should_work() {
 	let n = Pallet::<Test>::bond_name(789_970_979, 1689844300000_u64);
 	assert_eq!(Pallet::<Test>::parse_bond_name(n), Ok(789_970_979));
 }
+
+#[test]
+fn auction_price_should_decrease_linearly_to_floor_price() {
+	let auction = Auction {
+		issuer: ALICE,
+		bond_id: 1,
+		payment_asset: DAI,
+		remaining: ONE,
+		start_price: FixedU128::from_rational(95, 100),
+		floor_price: FixedU128::from_rational(80, 100),
+		start: NOW,
+		end: NOW + WEEK,
+		coupons_funded_from: 0,
+	};
+
+	assert_eq!(auction.price_at(NOW - DAY), FixedU128::from_rational(95, 100));
+	assert_eq!(auction.price_at(NOW), FixedU128::from_rational(95, 100));
+	assert_eq!(auction.price_at(NOW + WEEK / 2), FixedU128::from_rational(875, 1000));
+	assert_eq!(auction.price_at(NOW + WEEK), FixedU128::from_rational(80, 100));
+	assert_eq!(auction.price_at(NOW + 2 * WEEK), FixedU128::from_rational(80, 100));
+}
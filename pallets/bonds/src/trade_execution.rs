@@ -0,0 +1,159 @@
+use crate::{Config, Error, Pallet, Pools};
+use frame_support::ensure;
+use frame_support::traits::{Get, Time};
+use hydradx_traits::router::{take_pools, ExecutorError, PoolType, PoolsProvider, TradeExecution};
+use orml_traits::MultiCurrency;
+use primitives::AssetId;
+use sp_runtime::traits::Zero;
+use sp_runtime::DispatchError::Corruption;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, T::Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: T::Balance,
+	) -> Result<T::Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let bond_id = Self::pool_bond_id(asset_in, asset_out)
+			.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+		ensure!(
+			amount_in >= T::MinTradingLimit::get(),
+			ExecutorError::Error(Error::<T>::InsufficientTradingAmount.into())
+		);
+
+		let (amount_out, _) =
+			Self::calculate_sell_amount(bond_id, asset_in, asset_out, amount_in).map_err(ExecutorError::Error)?;
+
+		Ok(amount_out)
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: T::Balance,
+	) -> Result<T::Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let bond_id = Self::pool_bond_id(asset_in, asset_out)
+			.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+		ensure!(
+			amount_out >= T::MinTradingLimit::get(),
+			ExecutorError::Error(Error::<T>::InsufficientTradingAmount.into())
+		);
+
+		let (amount_in, _) =
+			Self::calculate_buy_amount(bond_id, asset_in, asset_out, amount_out).map_err(ExecutorError::Error)?;
+
+		Ok(amount_in)
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: T::Balance,
+		min_limit: T::Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<AssetId>,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: T::Balance,
+		max_limit: T::Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<T::Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let bond_id = Self::pool_bond_id(asset_a, asset_b)
+			.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+
+		let liquidity = T::Currency::free_balance(asset_a, &Self::pool_account_id(bond_id));
+
+		Ok(liquidity)
+	}
+
+	fn calculate_spot_price_with_fee(
+		pool_type: PoolType<AssetId>,
+		asset_a: AssetId,
+		asset_b: AssetId,
+	) -> Result<FixedU128, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::Bonds {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let bond_id = Self::pool_bond_id(asset_a, asset_b)
+			.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+		let pool = Self::pool(bond_id).ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
+		let (_, maturity) =
+			Self::bond(bond_id).ok_or_else(|| ExecutorError::Error(Error::<T>::NotRegistered.into()))?;
+
+		let pool_account = Self::pool_account_id(bond_id);
+		let asset_a_reserve = T::Currency::free_balance(asset_a, &pool_account);
+		let asset_b_reserve = T::Currency::free_balance(asset_b, &pool_account);
+		let time_to_maturity = maturity.saturating_sub(T::TimestampProvider::now());
+
+		let fee_rate = if pool.fee.is_zero() {
+			None
+		} else {
+			Some((pool.fee.deconstruct(), 1_000_000))
+		};
+
+		let spot_price_with_fee = hydra_dx_math::bonds::calculate_spot_price_with_fee(
+			asset_a_reserve.into(),
+			asset_b_reserve.into(),
+			time_to_maturity,
+			T::PoolTimeScale::get(),
+			fee_rate,
+		)
+		.map_err(|_| ExecutorError::Error(ArithmeticError::Overflow.into()))?
+		.reciprocal()
+		.ok_or(ExecutorError::Error(Corruption))?;
+
+		Ok(spot_price_with_fee)
+	}
+}
+
+impl<T: Config> PoolsProvider<AssetId> for Pallet<T> {
+	fn pools(max_assets: u32) -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		take_pools(
+			Pools::<T>::iter().map(|(bond_id, pool)| (PoolType::Bonds, vec![pool.underlying_asset_id, bond_id])),
+			max_assets,
+		)
+	}
+}
@@ -52,6 +52,18 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
 	fn issue() -> Weight;
 	fn redeem() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid() -> Weight;
+	fn close_auction() -> Weight;
+	fn claim_coupons() -> Weight;
+	fn create_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn sell() -> Weight;
+	fn buy() -> Weight;
+	fn router_execution_sell(c: u32, e: u32, ) -> Weight;
+	fn router_execution_buy(c: u32, e: u32, ) -> Weight;
+	fn calculate_spot_price_with_fee() -> Weight;
 }
 
 /// Weights for pallet_bonds using the hydraDX node and recommended hardware.
@@ -128,4 +140,157 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(13_u64))
 			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
+	/// Storage: `AssetRegistry::Assets` (r:1 w:1)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::BondIds` (r:1 w:1)
+	/// Proof: `Bonds::BondIds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::NextAssetId` (r:1 w:1)
+	/// Proof: `AssetRegistry::NextAssetId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::AssetIds` (r:1 w:1)
+	/// Proof: `AssetRegistry::AssetIds` (`max_values`: None, `max_size`: Some(53), added: 2528, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Coupons` (r:1 w:1)
+	/// Proof: `Bonds::Coupons` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::NextAuctionId` (r:1 w:1)
+	/// Proof: `Bonds::NextAuctionId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Auctions` (r:0 w:1)
+	/// Proof: `Bonds::Auctions` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Bonds` (r:0 w:1)
+	/// Proof: `Bonds::Bonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	fn create_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1393`
+		//  Estimated: `8799`
+		// Minimum execution time: 214_381_000 picoseconds.
+		Weight::from_parts(216_027_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Bonds::Auctions` (r:1 w:1)
+	/// Proof: `Bonds::Auctions` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	/// Proof: `Bonds::Bonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Coupons` (r:1 w:0)
+	/// Proof: `Bonds::Coupons` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::CouponPositions` (r:1 w:1)
+	/// Proof: `Bonds::CouponPositions` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:1)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Proof: `AssetRegistry::Assets` (`max_values`: None, `max_size`: Some(125), added: 2600, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Proof: `Tokens::TotalIssuance` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `AssetRegistry::ExistentialDepositCounter` (r:1 w:1)
+	/// Proof: `AssetRegistry::ExistentialDepositCounter` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn bid() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2241`
+		//  Estimated: `8799`
+		// Minimum execution time: 232_618_000 picoseconds.
+		Weight::from_parts(234_409_000, 8799)
+			.saturating_add(RocksDbWeight::get().reads(12_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Bonds::Auctions` (r:1 w:1)
+	/// Proof: `Bonds::Auctions` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	/// Proof: `Bonds::Bonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Coupons` (r:1 w:0)
+	/// Proof: `Bonds::Coupons` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn close_auction() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1736`
+		//  Estimated: `6196`
+		// Minimum execution time: 98_712_000 picoseconds.
+		Weight::from_parts(99_836_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	/// Proof: `Bonds::Bonds` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::Coupons` (r:1 w:0)
+	/// Proof: `Bonds::Coupons` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `Bonds::CouponPositions` (r:1 w:1)
+	/// Proof: `Bonds::CouponPositions` (`max_values`: None, `max_size`: Some(72), added: 2547, mode: `MaxEncodedLen`)
+	/// Storage: `Tokens::Accounts` (r:1 w:0)
+	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_coupons() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1982`
+		//  Estimated: `6196`
+		// Minimum execution time: 112_094_000 picoseconds.
+		Weight::from_parts(113_250_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Share asset is registered and the initial liquidity is transferred and minted as in `issue`.
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	/// Storage: `Bonds::Pools` (r:1 w:1)
+	fn create_pool() -> Weight {
+		Self::issue()
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 1_u64))
+	}
+	/// Liquidity is transferred and the shares are minted as in `issue`.
+	/// Storage: `Bonds::Pools` (r:1 w:0)
+	fn add_liquidity() -> Weight {
+		Self::issue()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Shares are burnt and the liquidity is transferred as in `issue`, the pool is removed with the last shares.
+	/// Storage: `Bonds::Pools` (r:1 w:1)
+	fn remove_liquidity() -> Weight {
+		Self::issue()
+			.saturating_add(RocksDbWeight::get().reads_writes(1_u64, 1_u64))
+	}
+	/// Traded assets are transferred between the account and the pool as in `issue`.
+	/// The pool is looked up in both directions of the asset pair in the worst case.
+	/// Storage: `Bonds::Pools` (r:3 w:0)
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	fn sell() -> Weight {
+		Self::issue()
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+	/// Traded assets are transferred between the account and the pool as in `issue`.
+	/// The pool is looked up in both directions of the asset pair in the worst case.
+	/// Storage: `Bonds::Pools` (r:3 w:0)
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	fn buy() -> Weight {
+		Self::issue()
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
+	/// Each calculation reads the pool state as in `calculate_spot_price_with_fee`, the execution is a `sell`.
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_sell(c: u32, e: u32, ) -> Weight {
+		Self::calculate_spot_price_with_fee().saturating_mul(c.into())
+			.saturating_add(Self::sell().saturating_mul(e.into()))
+	}
+	/// Each calculation reads the pool state as in `calculate_spot_price_with_fee`, the execution is a `buy`.
+	/// The range of component `c` is `[1, 3]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_buy(c: u32, e: u32, ) -> Weight {
+		Self::calculate_spot_price_with_fee().saturating_mul(c.into())
+			.saturating_add(Self::buy().saturating_mul(e.into()))
+	}
+	/// Storage: `Bonds::Pools` (r:3 w:0)
+	/// Storage: `Bonds::Bonds` (r:1 w:0)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:1 w:0)
+	fn calculate_spot_price_with_fee() -> Weight {
+		RocksDbWeight::get().reads(7_u64)
+	}
 }
@@ -45,6 +45,13 @@ fn whitelist_storage_maps<T: Config>() {
 	}
 }
 
+fn rolling_limit() -> RollingVolumeLimit {
+	RollingVolumeLimit {
+		limit: (crate::MAX_LIMIT_VALUE, 1),
+		window: 100,
+	}
+}
+
 benchmarks! {
 	 where_clause {
 		where T::AssetId: From<u32>,
@@ -189,5 +196,54 @@ benchmarks! {
 		assert!(before_out != after_out);
 	}
 
+	set_rolling_volume_limit {
+		let asset_id = T::AssetId::from(2u32);
+		crate::Pallet::<T>::set_rolling_volume_limit(RawOrigin::Root.into(), asset_id, VolumeKind::Trade, Some(rolling_limit()))?;
+
+	}: _(RawOrigin::Root, asset_id, VolumeKind::Trade, None)
+	verify {
+		assert!(RollingVolumeLimitPerAsset::<T>::get(asset_id, VolumeKind::Trade).is_none());
+	}
+
+	set_price_deviation_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let limit = PriceDeviationLimit {
+			max_deviation: (1, 100),
+			cooldown: 10u32.into(),
+		};
+		crate::Pallet::<T>::set_price_deviation_limit(RawOrigin::Root.into(), asset_id, Some(limit))?;
+		TradingHaltedUntil::<T>::insert(asset_id, BlockNumberFor::<T>::from(10u32));
+
+	}: _(RawOrigin::Root, asset_id, None)
+	verify {
+		assert!(PriceDeviationLimitPerAsset::<T>::get(asset_id).is_none());
+		assert!(TradingHaltedUntil::<T>::get(asset_id).is_none());
+	}
+
+	ensure_price_deviation_limit {
+		let asset_id = T::AssetId::from(2u32);
+		let quote_asset = T::AssetId::from(3u32);
+		let source = *b"benchmrk";
+		let cooldown: BlockNumberFor<T> = 10u32.into();
+		let limit = PriceDeviationLimit {
+			max_deviation: (1, 100),
+			cooldown,
+		};
+		crate::Pallet::<T>::set_price_deviation_limit(RawOrigin::Root.into(), asset_id, Some(limit))?;
+
+		frame_system::Pallet::<T>::set_block_number(4u32.into());
+		T::BenchmarkHelper::set_oracle_price(asset_id, quote_asset, source, EmaPrice::new(1, 1))?;
+
+		// worst case: the expired halt is removed and the deviation from the oracle price halts the trading again
+		let block_num: BlockNumberFor<T> = 5u32.into();
+		frame_system::Pallet::<T>::set_block_number(block_num);
+		TradingHaltedUntil::<T>::insert(asset_id, block_num);
+	}: {
+		crate::Pallet::<T>::ensure_price_deviation_limit(asset_id, quote_asset, source, EmaPrice::new(1, 2))?
+	}
+	verify {
+		assert_eq!(TradingHaltedUntil::<T>::get(asset_id), Some(block_num + cooldown));
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
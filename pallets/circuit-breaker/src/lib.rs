@@ -23,9 +23,14 @@ use frame_support::weights::Weight;
 use frame_support::{ensure, pallet_prelude::DispatchResult, traits::Get};
 use frame_system::ensure_signed_or_root;
 use frame_system::pallet_prelude::{BlockNumberFor, OriginFor};
+use hydra_dx_math::ema::EmaPrice;
+use hydra_dx_math::rate_limiter::calculate_new_accumulated_amount;
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, Source};
 use scale_info::TypeInfo;
-use sp_core::MaxEncodedLen;
-use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Zero};
+use sp_core::{MaxEncodedLen, U512};
+use sp_runtime::traits::{
+	AtLeast32BitUnsigned, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, SaturatedConversion, Saturating, Zero,
+};
 use sp_runtime::{ArithmeticError, DispatchError, RuntimeDebug};
 
 pub mod weights;
@@ -39,6 +44,24 @@ mod tests;
 /// Max trade volume limit multiplier of liquidity that can be traded in a block
 pub const MAX_LIMIT_VALUE: u32 = 10_000;
 
+#[cfg(any(feature = "runtime-benchmarks", test))]
+pub trait BenchmarkHelper<AssetId> {
+	/// Provide the oracle price of `asset_id` quoted in `quote_asset` for the given source.
+	fn set_oracle_price(asset_id: AssetId, quote_asset: AssetId, source: Source, price: EmaPrice) -> DispatchResult;
+}
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+impl<AssetId> BenchmarkHelper<AssetId> for () {
+	fn set_oracle_price(
+		_asset_id: AssetId,
+		_quote_asset: AssetId,
+		_source: Source,
+		_price: EmaPrice,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
 #[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
 #[scale_info(skip_type_params(T))]
 pub struct TradeVolumeLimit<T: Config> {
@@ -120,6 +143,82 @@ where
 	}
 }
 
+/// Kind of pool state change tracked by the rolling volume limits.
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub enum VolumeKind {
+	Trade,
+	AddLiquidity,
+	RemoveLiquidity,
+}
+
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct RollingVolumeLimit {
+	/// Maximum percentage of a pool's liquidity that can be moved within the window.
+	/// Represented as a non-zero fraction (nominator, denominator) with the max value being 10_000.
+	pub limit: (u32, u32),
+	/// Length of the window in blocks.
+	pub window: u32,
+}
+
+/// Volume accumulated within a rolling window. Decays linearly by the limit per window length.
+#[derive(Clone, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+#[scale_info(skip_type_params(T))]
+pub struct RollingVolume<T: Config> {
+	pub volume_in: T::Balance,
+	pub volume_out: T::Balance,
+	pub last_updated: BlockNumberFor<T>,
+}
+
+impl<T: Config> RollingVolume<T> {
+	/// Decays the accumulated volumes to `now` and adds the new amounts.
+	/// Amounts in opposite directions cancel each other out, so only the net volume is tracked.
+	pub fn update_amounts(
+		&mut self,
+		window: u32,
+		limit: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+		now: BlockNumberFor<T>,
+	) {
+		let blocks_since_last_update: u32 = now.saturating_sub(self.last_updated).saturated_into();
+		let accumulate = |incoming: T::Balance, accumulated: T::Balance| -> T::Balance {
+			calculate_new_accumulated_amount(
+				window,
+				limit.saturated_into(),
+				incoming.saturated_into(),
+				accumulated.saturated_into(),
+				blocks_since_last_update,
+			)
+			.into()
+		};
+
+		let volume_in = accumulate(amount_in, self.volume_in);
+		let volume_out = accumulate(amount_out, self.volume_out);
+		let netted = volume_in.min(volume_out);
+
+		self.volume_in = volume_in.saturating_sub(netted);
+		self.volume_out = volume_out.saturating_sub(netted);
+		self.last_updated = now;
+	}
+
+	pub fn check_limit(&self, limit: T::Balance) -> DispatchResult {
+		ensure!(
+			self.volume_in <= limit && self.volume_out <= limit,
+			Error::<T>::RollingVolumeLimitReached
+		);
+		Ok(())
+	}
+}
+
+#[derive(Clone, Copy, Encode, Decode, RuntimeDebug, MaxEncodedLen, TypeInfo, Eq, PartialEq)]
+pub struct PriceDeviationLimit<BlockNumber> {
+	/// Maximum allowed deviation of the spot price from the oracle price.
+	/// Represented as a non-zero fraction (nominator, denominator) with the max value being 10_000.
+	pub max_deviation: (u32, u32),
+	/// Number of blocks the trading of the asset is halted for once the limit is breached.
+	pub cooldown: BlockNumber,
+}
+
 // Re-export pallet items so that they can be accessed from the crate namespace.
 pub use pallet::*;
 pub use weights::WeightInfo;
@@ -220,6 +319,16 @@ pub mod pallet {
 		/// Omnipool's hub asset id. The limits are not tracked for this asset.
 		type OmnipoolHubAsset: Get<Self::AssetId>;
 
+		/// Oracle providing the reference prices for the price deviation limits.
+		type PriceOracle: AggregatedPriceOracle<Self::AssetId, BlockNumberFor<Self>, EmaPrice>;
+
+		/// Oracle period used as the reference price for the price deviation limits.
+		#[pallet::constant]
+		type PriceDeviationOraclePeriod: Get<OraclePeriod>;
+
+		#[cfg(any(feature = "runtime-benchmarks", test))]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -284,6 +393,31 @@ pub mod pallet {
 	pub type AllowedRemoveLiquidityAmountPerAsset<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AssetId, LiquidityLimit<T>>;
 
+	#[pallet::storage]
+	/// Rolling volume limits of assets set by set_rolling_volume_limit.
+	/// If not set, the volume is tracked per block only.
+	#[pallet::getter(fn rolling_volume_limit_per_asset)]
+	pub type RollingVolumeLimitPerAsset<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, VolumeKind, RollingVolumeLimit>;
+
+	#[pallet::storage]
+	/// Volumes accumulated within the rolling window per asset
+	#[pallet::getter(fn rolling_volume_per_asset)]
+	pub type RollingVolumePerAsset<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, VolumeKind, RollingVolume<T>>;
+
+	#[pallet::storage]
+	/// Price deviation limits of assets set by set_price_deviation_limit.
+	/// If not set, the spot price of the asset is not checked against the oracle.
+	#[pallet::getter(fn price_deviation_limit_per_asset)]
+	pub type PriceDeviationLimitPerAsset<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AssetId, PriceDeviationLimit<BlockNumberFor<T>>>;
+
+	#[pallet::storage]
+	/// Block number until which the trading of an asset is halted due to a breached price deviation limit.
+	#[pallet::getter(fn trading_halted_until)]
+	pub type TradingHaltedUntil<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, BlockNumberFor<T>>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -302,6 +436,24 @@ pub mod pallet {
 			asset_id: T::AssetId,
 			liquidity_limit: Option<(u32, u32)>,
 		},
+		/// Rolling volume limit of an asset was changed.
+		RollingVolumeLimitChanged {
+			asset_id: T::AssetId,
+			kind: VolumeKind,
+			limit: Option<RollingVolumeLimit>,
+		},
+		/// Price deviation limit of an asset was changed.
+		PriceDeviationLimitChanged {
+			asset_id: T::AssetId,
+			limit: Option<PriceDeviationLimit<BlockNumberFor<T>>>,
+		},
+		/// Spot price of an asset deviated from the oracle price by more than allowed. Trading of the asset is halted.
+		TradingHalted {
+			asset_id: T::AssetId,
+			spot_price: EmaPrice,
+			oracle_price: EmaPrice,
+			until: BlockNumberFor<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -319,6 +471,10 @@ pub mod pallet {
 		MaxLiquidityLimitPerBlockReached,
 		/// Asset is not allowed to have a limit
 		NotAllowed,
+		/// Maximum pool's liquidity limit per rolling window has been reached
+		RollingVolumeLimitReached,
+		/// Trading of the asset is halted because its price deviated too far from the oracle price
+		TradingHalted,
 	}
 
 	#[pallet::call]
@@ -422,6 +578,92 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set price deviation limit for an asset.
+		///
+		/// If a trade moves the spot price of the asset further from the oracle price than allowed,
+		/// trading of the asset is halted for the `cooldown` number of blocks.
+		/// Removing the limit also lifts an active trading halt of the asset.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `limit`: Optional price deviation limit. Max deviation is represented as a percentage
+		///
+		/// Emits `PriceDeviationLimitChanged` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_price_deviation_limit())]
+		pub fn set_price_deviation_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			limit: Option<PriceDeviationLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			match limit {
+				Some(limit) => {
+					Self::validate_limit(limit.max_deviation)?;
+					ensure!(!limit.cooldown.is_zero(), Error::<T>::InvalidLimitValue);
+
+					<PriceDeviationLimitPerAsset<T>>::insert(asset_id, limit);
+				}
+				None => {
+					<PriceDeviationLimitPerAsset<T>>::remove(asset_id);
+					<TradingHaltedUntil<T>>::remove(asset_id);
+				}
+			}
+
+			Self::deposit_event(Event::PriceDeviationLimitChanged { asset_id, limit });
+
+			Ok(())
+		}
+
+		/// Set rolling volume limit for an asset.
+		///
+		/// Rolling limits are enforced alongside the per block limits. The volume accumulated within
+		/// the window decays linearly, so the limit is replenished over the window length.
+		/// Removing the limit also resets the accumulated volume.
+		///
+		/// Parameters:
+		/// - `origin`: The dispatch origin for this call. Must be `TechnicalOrigin`
+		/// - `asset_id`: The identifier of an asset
+		/// - `kind`: Kind of the pool state change the limit applies to
+		/// - `limit`: Optional rolling volume limit represented as a percentage per window length
+		///
+		/// Emits `RollingVolumeLimitChanged` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_rolling_volume_limit())]
+		pub fn set_rolling_volume_limit(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			kind: VolumeKind,
+			limit: Option<RollingVolumeLimit>,
+		) -> DispatchResult {
+			T::TechnicalOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_id != T::OmnipoolHubAsset::get(), Error::<T>::NotAllowed);
+
+			match limit {
+				Some(limit) => {
+					Self::validate_limit(limit.limit)?;
+					ensure!(!limit.window.is_zero(), Error::<T>::InvalidLimitValue);
+
+					<RollingVolumeLimitPerAsset<T>>::insert(asset_id, kind, limit);
+				}
+				None => {
+					<RollingVolumeLimitPerAsset<T>>::remove(asset_id, kind);
+					<RollingVolumePerAsset<T>>::remove(asset_id, kind);
+				}
+			}
+
+			Self::deposit_event(Event::RollingVolumeLimitChanged { asset_id, kind, limit });
+
+			Ok(())
+		}
 	}
 }
 
@@ -543,6 +785,39 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_and_update_rolling_volume_limit(
+		asset_id: T::AssetId,
+		kind: VolumeKind,
+		initial_liquidity: T::Balance,
+		amount_in: T::Balance,
+		amount_out: T::Balance,
+	) -> DispatchResult {
+		// ignore Omnipool's hub asset
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(());
+		}
+
+		let Some(rolling_limit) = Pallet::<T>::rolling_volume_limit_per_asset(asset_id, kind) else {
+			return Ok(());
+		};
+
+		let limit = Self::calculate_limit(initial_liquidity, rolling_limit.limit)?;
+		let now = frame_system::Pallet::<T>::block_number();
+
+		let mut rolling_volume = Pallet::<T>::rolling_volume_per_asset(asset_id, kind).unwrap_or(RollingVolume {
+			volume_in: Zero::zero(),
+			volume_out: Zero::zero(),
+			last_updated: now,
+		});
+
+		rolling_volume.update_amounts(rolling_limit.window, limit, amount_in, amount_out, now);
+		rolling_volume.check_limit(limit)?;
+
+		<RollingVolumePerAsset<T>>::insert(asset_id, kind, rolling_volume);
+
+		Ok(())
+	}
+
 	pub fn validate_limit(limit: (u32, u32)) -> DispatchResult {
 		let (numerator, denominator) = (limit.0, limit.1);
 		ensure!(
@@ -568,6 +843,45 @@ impl<T: Config> Pallet<T> {
 			.ok_or_else(|| ArithmeticError::DivisionByZero.into())
 	}
 
+	/// Storage access of the rolling volume limit check of one asset. The benchmarks of the limit
+	/// checks only cover the per block limits, so it is added on top.
+	fn rolling_volume_limit_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 1)
+	}
+
+	/// Remove the limits and the tracked volumes of an asset which is no longer traded,
+	/// so they do not apply if the asset is added again.
+	pub fn remove_asset_limits(asset_id: T::AssetId) {
+		<TradeVolumeLimitPerAsset<T>>::remove(asset_id);
+		<AllowedTradeVolumeLimitPerAsset<T>>::remove(asset_id);
+		<LiquidityAddLimitPerAsset<T>>::remove(asset_id);
+		<AllowedAddLiquidityAmountPerAsset<T>>::remove(asset_id);
+		<LiquidityRemoveLimitPerAsset<T>>::remove(asset_id);
+		<AllowedRemoveLiquidityAmountPerAsset<T>>::remove(asset_id);
+		let _ = <RollingVolumeLimitPerAsset<T>>::clear_prefix(asset_id, u32::MAX, None);
+		let _ = <RollingVolumePerAsset<T>>::clear_prefix(asset_id, u32::MAX, None);
+		<PriceDeviationLimitPerAsset<T>>::remove(asset_id);
+		<TradingHaltedUntil<T>>::remove(asset_id);
+	}
+
+	/// Storage access of `remove_asset_limits`, one write per limit and volume of each kind.
+	pub fn remove_asset_limits_weight() -> Weight {
+		T::DbWeight::get().writes(14)
+	}
+
+	pub fn ensure_pool_state_change_limit_weight() -> Weight {
+		T::WeightInfo::ensure_pool_state_change_limit()
+			.saturating_add(Self::rolling_volume_limit_weight().saturating_mul(2))
+	}
+
+	pub fn ensure_add_liquidity_limit_weight() -> Weight {
+		T::WeightInfo::ensure_add_liquidity_limit().saturating_add(Self::rolling_volume_limit_weight())
+	}
+
+	pub fn ensure_remove_liquidity_limit_weight() -> Weight {
+		T::WeightInfo::ensure_remove_liquidity_limit().saturating_add(Self::rolling_volume_limit_weight())
+	}
+
 	pub fn ensure_pool_state_change_limit(
 		asset_in: T::AssetId,
 		asset_in_reserve: T::Balance,
@@ -579,8 +893,22 @@ impl<T: Config> Pallet<T> {
 		Pallet::<T>::initialize_trade_limit(asset_in, asset_in_reserve)?;
 		Pallet::<T>::initialize_trade_limit(asset_out, asset_out_reserve)?;
 		Pallet::<T>::ensure_and_update_trade_volume_limit(asset_in, amount_in, asset_out, amount_out)?;
-
-		Ok(T::WeightInfo::ensure_pool_state_change_limit())
+		Pallet::<T>::ensure_and_update_rolling_volume_limit(
+			asset_in,
+			VolumeKind::Trade,
+			asset_in_reserve,
+			amount_in,
+			Zero::zero(),
+		)?;
+		Pallet::<T>::ensure_and_update_rolling_volume_limit(
+			asset_out,
+			VolumeKind::Trade,
+			asset_out_reserve,
+			Zero::zero(),
+			amount_out,
+		)?;
+
+		Ok(Self::ensure_pool_state_change_limit_weight())
 	}
 
 	pub fn ensure_add_liquidity_limit(
@@ -596,8 +924,15 @@ impl<T: Config> Pallet<T> {
 
 		Pallet::<T>::calculate_and_store_liquidity_limits(asset_id, initial_liquidity)?;
 		Pallet::<T>::ensure_and_update_add_liquidity_limit(asset_id, added_liquidity)?;
-
-		Ok(T::WeightInfo::ensure_add_liquidity_limit())
+		Pallet::<T>::ensure_and_update_rolling_volume_limit(
+			asset_id,
+			VolumeKind::AddLiquidity,
+			initial_liquidity,
+			added_liquidity,
+			Zero::zero(),
+		)?;
+
+		Ok(Self::ensure_add_liquidity_limit_weight())
 	}
 
 	pub fn ensure_remove_liquidity_limit(
@@ -613,8 +948,81 @@ impl<T: Config> Pallet<T> {
 
 		Pallet::<T>::calculate_and_store_liquidity_limits(asset_id, initial_liquidity)?;
 		Pallet::<T>::ensure_and_update_remove_liquidity_limit(asset_id, removed_liquidity)?;
+		Pallet::<T>::ensure_and_update_rolling_volume_limit(
+			asset_id,
+			VolumeKind::RemoveLiquidity,
+			initial_liquidity,
+			Zero::zero(),
+			removed_liquidity,
+		)?;
+
+		Ok(Self::ensure_remove_liquidity_limit_weight())
+	}
+
+	/// Checks the spot price of `asset_id` in `quote_asset` after a trade against the oracle price.
+	///
+	/// Fails if trading of the asset is halted. If the price deviation limit of the asset is breached,
+	/// the trade is let through but trading of the asset is halted for the configured cooldown.
+	pub fn ensure_price_deviation_limit(
+		asset_id: T::AssetId,
+		quote_asset: T::AssetId,
+		source: Source,
+		spot_price: EmaPrice,
+	) -> Result<Weight, DispatchError> {
+		// we don't track price deviation for the Omnipool Hub asset
+		if asset_id == T::OmnipoolHubAsset::get() {
+			return Ok(Weight::zero());
+		}
+
+		let current_block = frame_system::Pallet::<T>::block_number();
+
+		if let Some(until) = Pallet::<T>::trading_halted_until(asset_id) {
+			ensure!(current_block >= until, Error::<T>::TradingHalted);
+			<TradingHaltedUntil<T>>::remove(asset_id);
+		}
+
+		let Some(limit) = Pallet::<T>::price_deviation_limit_per_asset(asset_id) else {
+			return Ok(T::WeightInfo::ensure_price_deviation_limit());
+		};
+
+		// the limit can't be enforced for assets without oracle
+		let Ok((oracle_price, _)) =
+			T::PriceOracle::get_price(asset_id, quote_asset, T::PriceDeviationOraclePeriod::get(), source)
+		else {
+			return Ok(T::WeightInfo::ensure_price_deviation_limit());
+		};
+
+		if Self::is_price_deviation_exceeded(spot_price, oracle_price, limit.max_deviation) {
+			let until = current_block.saturating_add(limit.cooldown);
+			<TradingHaltedUntil<T>>::insert(asset_id, until);
+
+			Self::deposit_event(Event::TradingHalted {
+				asset_id,
+				spot_price,
+				oracle_price,
+				until,
+			});
+		}
+
+		Ok(T::WeightInfo::ensure_price_deviation_limit())
+	}
+
+	/// Returns true if `|spot_price - oracle_price| / oracle_price > max_deviation`.
+	pub fn is_price_deviation_exceeded(
+		spot_price: EmaPrice,
+		oracle_price: EmaPrice,
+		max_deviation: (u32, u32),
+	) -> bool {
+		if oracle_price.is_zero() {
+			return false;
+		}
+
+		// compare the prices with common denominator to avoid precision loss
+		let spot = U512::from(spot_price.n).saturating_mul(U512::from(oracle_price.d));
+		let oracle = U512::from(oracle_price.n).saturating_mul(U512::from(spot_price.d));
+		let diff = spot.max(oracle).saturating_sub(spot.min(oracle));
 
-		Ok(T::WeightInfo::ensure_remove_liquidity_limit())
+		diff.saturating_mul(U512::from(max_deviation.1)) > oracle.saturating_mul(U512::from(max_deviation.0))
 	}
 
 	pub(crate) fn is_origin_whitelisted_or_root(origin: OriginFor<T>) -> Result<bool, DispatchError> {
@@ -22,6 +22,7 @@ pub use frame_support::{assert_noop, assert_ok, parameter_types};
 
 use frame_system::EnsureRoot;
 use hydra_dx_math::omnipool::types::BalanceUpdate;
+use hydradx_traits::{AggregatedPriceOracle, OraclePeriod, Source};
 use orml_traits::{parameter_type_with_key, GetByKey};
 use sp_core::H256;
 use sp_runtime::traits::{ConstU128, ConstU32, Zero};
@@ -60,6 +61,8 @@ pub const INITIAL_LIQUIDITY: Balance = 1_000_000;
 pub const REGISTERED_ASSET: AssetId = 1000;
 pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
 
+pub const OMNIPOOL_SOURCE: Source = *b"omnipool";
+
 pub const FIVE_PERCENT: (u32, u32) = (500, 10_000);
 pub const TEN_PERCENT: (u32, u32) = (1_000, 10_000);
 
@@ -76,6 +79,7 @@ thread_local! {
 	pub static MAX_NET_TRADE_VOLUME_LIMIT_PER_BLOCK: RefCell<(u32, u32)> = const { RefCell::new((2_000, 10_000)) }; // 20%
 	pub static MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((4_000, 10_000))) }; // 40%
 	pub static MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK: RefCell<Option<(u32, u32)>> = const { RefCell::new(Some((2_000, 10_000))) }; // 20%
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
 }
 
 frame_support::construct_runtime!(
@@ -131,6 +135,7 @@ parameter_types! {
 	pub DefaultMaxAddLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_ADD_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub DefaultMaxRemoveLiquidityLimitPerBlock: Option<(u32, u32)> = MAX_REMOVE_LIQUIDITY_LIMIT_PER_BLOCK.with(|v| *v.borrow());
 	pub const OmnipoolHubAsset: AssetId = LRNA;
+	pub const PriceDeviationOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 impl pallet_circuit_breaker::Config for Test {
@@ -143,9 +148,47 @@ impl pallet_circuit_breaker::Config for Test {
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxAddLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxRemoveLiquidityLimitPerBlock;
 	type OmnipoolHubAsset = OmnipoolHubAsset;
+	type PriceOracle = PriceOracleMock;
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
+	type BenchmarkHelper = PriceOracleMock;
 	type WeightInfo = ();
 }
 
+pub struct PriceOracleMock;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for PriceOracleMock {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.map(|price| (price, 1))
+			.ok_or(())
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+impl pallet_circuit_breaker::BenchmarkHelper<AssetId> for PriceOracleMock {
+	fn set_oracle_price(asset_id: AssetId, quote_asset: AssetId, _source: Source, price: EmaPrice) -> DispatchResult {
+		set_oracle_price(asset_id, quote_asset, price);
+		Ok(())
+	}
+}
+
+pub fn set_oracle_price(asset_a: AssetId, asset_b: AssetId, price: EmaPrice) {
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert((asset_a, asset_b), price);
+	});
+}
+
 pub struct CircuitBreakerWhitelist;
 
 impl Contains<AccountId> for CircuitBreakerWhitelist {
@@ -293,6 +336,15 @@ where
 			amount_out.into(),
 		)?;
 
+		for asset in [asset_in, asset_out] {
+			pallet_circuit_breaker::Pallet::<T>::ensure_price_deviation_limit(
+				asset.asset_id.into(),
+				LRNA.into(),
+				OMNIPOOL_SOURCE,
+				EmaPrice::new(asset.after.reserve, asset.after.hub_reserve),
+			)?;
+		}
+
 		Ok(Weight::zero())
 	}
 
@@ -300,6 +352,12 @@ where
 		Ok(Weight::zero())
 	}
 
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error> {
+		pallet_circuit_breaker::Pallet::<T>::remove_asset_limits(asset_id.into());
+
+		Ok(Weight::zero())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		todo!()
 	}
@@ -308,6 +366,10 @@ where
 		todo!()
 	}
 
+	fn on_asset_removed_weight() -> Weight {
+		todo!()
+	}
+
 	fn on_trade_fee(
 		_fee_account: AccountId,
 		_trader: AccountId,
@@ -444,6 +506,9 @@ impl Default for ExtBuilder {
 		POSITIONS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		ASSET_WEIGHT_CAP.with(|v| {
 			*v.borrow_mut() = Permill::from_percent(100);
 		});
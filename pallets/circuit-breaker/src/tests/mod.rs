@@ -3,5 +3,7 @@ mod ensure_add_liquidity_limit;
 mod ensure_remove_liquidity_limit;
 pub(crate) mod mock;
 mod omnipool;
+mod price_deviation;
 mod remove_liquidity_limit;
+mod rolling_volume;
 mod trade_volume;
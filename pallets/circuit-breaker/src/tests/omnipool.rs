@@ -608,3 +608,31 @@ fn remove_liquidity_should_fail_when_consequent_calls_exceed_liquidity_volume_li
 			);
 		});
 }
+
+#[test]
+fn remove_asset_should_remove_limits_of_asset() {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP2, 1_000, 2_000_000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 1_000_000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(CircuitBreaker::set_trade_volume_limit(
+				RuntimeOrigin::root(),
+				1_000,
+				(1, 100)
+			));
+			pallet_circuit_breaker::TradingHaltedUntil::<Test>::insert(1_000, 10);
+
+			// Act
+			assert_ok!(Omnipool::remove_asset(1_000));
+
+			// Assert
+			assert_eq!(
+				CircuitBreaker::trade_volume_limit_per_asset(1_000),
+				DefaultMaxNetTradeVolumeLimitPerBlock::get()
+			);
+			assert_eq!(CircuitBreaker::trading_halted_until(1_000), None);
+		});
+}
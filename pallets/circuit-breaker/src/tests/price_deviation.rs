@@ -0,0 +1,306 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+pub use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+use test_case::test_case;
+
+const TEN_PERCENT_DEVIATION: PriceDeviationLimit<u64> = PriceDeviationLimit {
+	max_deviation: TEN_PERCENT,
+	cooldown: 10,
+};
+
+fn pool_with_dot_and_aca() -> sp_io::TestExternalities {
+	let initial_liquidity = 10_000 * ONE;
+
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1_000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, DOT, 2_000_000 * ONE),
+			(LP1, ACA, 2_000_000 * ONE),
+			(TRADER, DOT, 2_000_000 * ONE),
+			(TRADER, ACA, 2_000_000 * ONE),
+		])
+		.with_registered_asset(DOT)
+		.with_registered_asset(ACA)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(DOT, FixedU128::from_float(0.65), LP1, initial_liquidity)
+		.with_token(ACA, FixedU128::from_float(0.65), LP1, initial_liquidity)
+		.build()
+}
+
+#[test]
+fn set_price_deviation_limit_should_store_new_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_eq!(CircuitBreaker::price_deviation_limit_per_asset(HDX), None);
+
+		// Act
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::price_deviation_limit_per_asset(HDX),
+			Some(TEN_PERCENT_DEVIATION)
+		);
+
+		expect_events(vec![crate::Event::PriceDeviationLimitChanged {
+			asset_id: HDX,
+			limit: Some(TEN_PERCENT_DEVIATION),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(RuntimeOrigin::signed(ALICE), HDX, Some(TEN_PERCENT_DEVIATION)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_price_deviation_limit_should_fail_when_setting_limit_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(RuntimeOrigin::root(), LRNA, Some(TEN_PERCENT_DEVIATION)),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test_case((0, 100), 10)]
+#[test_case((10, 0), 10)]
+#[test_case((MAX_LIMIT_VALUE + 1, 100), 10)]
+#[test_case((10, 100), 0)]
+fn set_price_deviation_limit_should_fail_when_limit_is_invalid(max_deviation: (u32, u32), cooldown: u64) {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_price_deviation_limit(
+				RuntimeOrigin::root(),
+				HDX,
+				Some(PriceDeviationLimit {
+					max_deviation,
+					cooldown
+				})
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn removing_price_deviation_limit_should_lift_trading_halt() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+		TradingHaltedUntil::<Test>::insert(HDX, 10);
+
+		// Act
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			None
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::price_deviation_limit_per_asset(HDX), None);
+		assert_eq!(CircuitBreaker::trading_halted_until(HDX), None);
+	});
+}
+
+#[test]
+fn sell_should_halt_trading_when_price_deviation_limit_exceeded() {
+	pool_with_dot_and_aca().execute_with(|| {
+		// Arrange
+		set_oracle_price(DOT, LRNA, EmaPrice::new(10_000, 6_500));
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+
+		// Act
+		// the trade moves the price of DOT by ~21%, but is still executed
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			1_000 * ONE,
+			10 * ONE
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trading_halted_until(DOT), Some(11));
+		assert_eq!(CircuitBreaker::trading_halted_until(ACA), None);
+
+		let dot = Omnipool::load_asset_state(DOT).unwrap();
+		System::assert_has_event(
+			crate::Event::TradingHalted {
+				asset_id: DOT,
+				spot_price: EmaPrice::new(dot.reserve, dot.hub_reserve),
+				oracle_price: EmaPrice::new(10_000, 6_500),
+				until: 11,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn trades_should_fail_when_trading_of_asset_is_halted() {
+	pool_with_dot_and_aca().execute_with(|| {
+		// Arrange
+		set_oracle_price(DOT, LRNA, EmaPrice::new(10_000, 6_500));
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			1_000 * ONE,
+			10 * ONE
+		));
+
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 10 * ONE, 0),
+			Error::<Test>::TradingHalted
+		);
+		assert_noop!(
+			Omnipool::sell(RuntimeOrigin::signed(TRADER), ACA, DOT, 10 * ONE, 0),
+			Error::<Test>::TradingHalted
+		);
+	});
+}
+
+#[test]
+fn trades_should_work_when_cooldown_is_over() {
+	pool_with_dot_and_aca().execute_with(|| {
+		// Arrange
+		set_oracle_price(DOT, LRNA, EmaPrice::new(10_000, 6_500));
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			1_000 * ONE,
+			10 * ONE
+		));
+
+		System::set_block_number(11);
+
+		// oracle caught up with the spot price
+		let dot = Omnipool::load_asset_state(DOT).unwrap();
+		set_oracle_price(DOT, LRNA, EmaPrice::new(dot.reserve, dot.hub_reserve));
+
+		// Act
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), ACA, DOT, 10 * ONE, 0));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trading_halted_until(DOT), None);
+	});
+}
+
+#[test]
+fn sell_should_not_halt_trading_when_price_deviation_limit_not_exceeded() {
+	pool_with_dot_and_aca().execute_with(|| {
+		// Arrange
+		set_oracle_price(DOT, LRNA, EmaPrice::new(10_000, 6_500));
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(PriceDeviationLimit {
+				max_deviation: (2_500, 10_000),
+				cooldown: 10,
+			})
+		));
+
+		// Act
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			1_000 * ONE,
+			10 * ONE
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trading_halted_until(DOT), None);
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(TRADER), DOT, ACA, 10 * ONE, 0));
+	});
+}
+
+#[test]
+fn sell_should_not_halt_trading_when_oracle_price_not_available() {
+	pool_with_dot_and_aca().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_price_deviation_limit(
+			RuntimeOrigin::root(),
+			DOT,
+			Some(TEN_PERCENT_DEVIATION)
+		));
+
+		// Act
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(TRADER),
+			DOT,
+			ACA,
+			1_000 * ONE,
+			10 * ONE
+		));
+
+		// Assert
+		assert_eq!(CircuitBreaker::trading_halted_until(DOT), None);
+	});
+}
+
+#[test_case(EmaPrice::new(110, 100), EmaPrice::new(100, 100), false)]
+#[test_case(EmaPrice::new(111, 100), EmaPrice::new(100, 100), true)]
+#[test_case(EmaPrice::new(90, 100), EmaPrice::new(100, 100), false)]
+#[test_case(EmaPrice::new(89, 100), EmaPrice::new(100, 100), true)]
+#[test_case(EmaPrice::new(u128::MAX, u128::MAX - 1), EmaPrice::new(1, 1), false)]
+#[test_case(EmaPrice::new(1, 1), EmaPrice::new(0, 1), false)]
+fn price_deviation_should_be_compared_to_oracle_price(spot_price: EmaPrice, oracle_price: EmaPrice, exceeded: bool) {
+	assert_eq!(
+		CircuitBreaker::is_price_deviation_exceeded(spot_price, oracle_price, TEN_PERCENT),
+		exceeded
+	);
+}
@@ -0,0 +1,376 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::*;
+use frame_support::assert_err;
+pub use pretty_assertions::assert_eq;
+use test_case::test_case;
+
+// 20% per 100 blocks
+const ROLLING_LIMIT: RollingVolumeLimit = RollingVolumeLimit {
+	limit: (2_000, 10_000),
+	window: 100,
+};
+
+fn next_block(block: u64) {
+	CircuitBreaker::on_finalize(block - 1);
+	System::set_block_number(block);
+}
+
+#[test]
+fn set_rolling_volume_limit_should_store_new_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			Some(ROLLING_LIMIT)
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::rolling_volume_limit_per_asset(HDX, VolumeKind::Trade),
+			Some(ROLLING_LIMIT)
+		);
+		assert_eq!(
+			CircuitBreaker::rolling_volume_limit_per_asset(HDX, VolumeKind::AddLiquidity),
+			None
+		);
+
+		expect_events(vec![crate::Event::RollingVolumeLimitChanged {
+			asset_id: HDX,
+			kind: VolumeKind::Trade,
+			limit: Some(ROLLING_LIMIT),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_rolling_volume_limit_should_fail_when_not_signed_by_technical_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_volume_limit(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				VolumeKind::Trade,
+				Some(ROLLING_LIMIT)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_rolling_volume_limit_should_fail_when_setting_limit_for_omnipool_hub_asset() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_volume_limit(
+				RuntimeOrigin::root(),
+				LRNA,
+				VolumeKind::Trade,
+				Some(ROLLING_LIMIT)
+			),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test_case((0, 100), 100)]
+#[test_case((MAX_LIMIT_VALUE + 1, 100), 100)]
+#[test_case((20, 100), 0)]
+fn set_rolling_volume_limit_should_fail_when_limit_is_invalid(limit: (u32, u32), window: u32) {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			CircuitBreaker::set_rolling_volume_limit(
+				RuntimeOrigin::root(),
+				HDX,
+				VolumeKind::Trade,
+				Some(RollingVolumeLimit { limit, window })
+			),
+			Error::<Test>::InvalidLimitValue
+		);
+	});
+}
+
+#[test]
+fn removing_rolling_volume_limit_should_reset_accumulated_volume() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			Some(ROLLING_LIMIT)
+		));
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			100_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			100_000,
+		));
+		assert!(CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::Trade).is_some());
+
+		// Act
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			None
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::rolling_volume_limit_per_asset(HDX, VolumeKind::Trade),
+			None
+		);
+		assert_eq!(CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::Trade), None);
+	});
+}
+
+#[test]
+fn rolling_volume_should_not_be_tracked_when_limit_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			100_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			100_000,
+		));
+
+		assert_eq!(CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::Trade), None);
+		assert_eq!(CircuitBreaker::rolling_volume_per_asset(DOT, VolumeKind::Trade), None);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_rolling_volume_limit_exceeded_in_consecutive_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			Some(ROLLING_LIMIT)
+		));
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			150_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+		));
+
+		// Act & Assert
+		// per block limit is reset, but only 1/100 of the rolling limit decayed
+		next_block(2);
+		assert_err!(
+			CircuitBreaker::ensure_pool_state_change_limit(
+				DOT,
+				INITIAL_LIQUIDITY,
+				150_000,
+				HDX,
+				INITIAL_LIQUIDITY,
+				150_000
+			),
+			Error::<Test>::RollingVolumeLimitReached
+		);
+	});
+}
+
+#[test]
+fn trade_should_work_when_rolling_volume_decayed() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			Some(ROLLING_LIMIT)
+		));
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			150_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+		));
+
+		// Act
+		// half of the window passed, so half of the limit decayed
+		next_block(51);
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			150_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::Trade).unwrap(),
+			RollingVolume {
+				volume_in: 0,
+				volume_out: 200_000,
+				last_updated: 51,
+			}
+		);
+	});
+}
+
+#[test]
+fn rolling_volume_should_be_netted_when_trading_in_opposite_directions() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::Trade,
+			Some(ROLLING_LIMIT)
+		));
+
+		// Act
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			DOT,
+			INITIAL_LIQUIDITY,
+			150_000,
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+		));
+		assert_ok!(CircuitBreaker::ensure_pool_state_change_limit(
+			HDX,
+			INITIAL_LIQUIDITY,
+			100_000,
+			DOT,
+			INITIAL_LIQUIDITY,
+			100_000,
+		));
+
+		// Assert
+		assert_eq!(
+			CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::Trade).unwrap(),
+			RollingVolume {
+				volume_in: 0,
+				volume_out: 50_000,
+				last_updated: 1,
+			}
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_rolling_volume_limit_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::AddLiquidity,
+			Some(RollingVolumeLimit {
+				limit: TEN_PERCENT,
+				window: 100,
+			})
+		));
+		assert_ok!(CircuitBreaker::ensure_add_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			80_000,
+		));
+
+		// Act & Assert
+		next_block(2);
+		assert_err!(
+			CircuitBreaker::ensure_add_liquidity_limit(RuntimeOrigin::signed(ALICE), HDX, INITIAL_LIQUIDITY, 30_000),
+			Error::<Test>::RollingVolumeLimitReached
+		);
+		assert_ok!(CircuitBreaker::ensure_add_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			21_000,
+		));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_rolling_volume_limit_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::RemoveLiquidity,
+			Some(RollingVolumeLimit {
+				limit: TEN_PERCENT,
+				window: 100,
+			})
+		));
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			INITIAL_LIQUIDITY,
+			80_000,
+		));
+
+		// Act & Assert
+		next_block(2);
+		assert_err!(
+			CircuitBreaker::ensure_remove_liquidity_limit(RuntimeOrigin::signed(ALICE), HDX, INITIAL_LIQUIDITY, 30_000),
+			Error::<Test>::RollingVolumeLimitReached
+		);
+	});
+}
+
+#[test]
+fn rolling_liquidity_limit_should_not_apply_to_whitelisted_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		assert_ok!(CircuitBreaker::set_rolling_volume_limit(
+			RuntimeOrigin::root(),
+			HDX,
+			VolumeKind::RemoveLiquidity,
+			Some(RollingVolumeLimit {
+				limit: TEN_PERCENT,
+				window: 100,
+			})
+		));
+
+		// Act & Assert
+		assert_ok!(CircuitBreaker::ensure_remove_liquidity_limit(
+			RuntimeOrigin::signed(WHITELISTED_ACCCOUNT),
+			HDX,
+			INITIAL_LIQUIDITY,
+			150_000,
+		));
+		assert_eq!(
+			CircuitBreaker::rolling_volume_per_asset(HDX, VolumeKind::RemoveLiquidity),
+			None
+		);
+	});
+}
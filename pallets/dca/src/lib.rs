@@ -55,6 +55,24 @@
 //!
 //! If a trade fails due to other types of errors, the order is terminated without any retry logic.
 //!
+//! ### Limit orders
+//!
+//! `LimitSell` and `LimitBuy` orders are checked every `period` blocks, and executed only when the short oracle price
+//! of the route reaches the limit price specified by the user. Until then, the order is replanned, and the execution fee
+//! is taken for every check.
+//! The limit price also determines the `min_amount_out` (sell) and `max_amount_in` (buy) trade limits.
+//!
+//! The deadline of a limit order can be at most `MaxLimitOrderDuration` blocks after scheduling.
+//! A limit order is terminated with `LimitOrderExpired` error once its deadline has passed.
+//!
+//! ## Pausing and updating a Schedule
+//!
+//! The owner can pause a schedule, which removes it from its planned block while keeping its remaining amount
+//! and the reserved budget. A paused schedule is planned again when it is resumed by the owner.
+//!
+//! The owner can also update the period and the budget (`total_amount`) of a schedule. The difference of the budget
+//! is reserved or unreserved, and the remaining amount is adjusted accordingly.
+//!
 //! ## Terminating a Schedule
 //!
 //! Both users and technical origin can terminate a DCA schedule. However, users can only terminate schedules that they own.
@@ -159,7 +177,10 @@ pub mod pallet {
 					&schedule,
 					&mut randomness_generator,
 				) {
-					if e == Error::<T>::PriceUnstable.into() || e == Error::<T>::Bumped.into() {
+					if e == Error::<T>::PriceUnstable.into()
+						|| e == Error::<T>::Bumped.into()
+						|| e == Error::<T>::LimitPriceNotReached.into()
+					{
 						continue;
 					} else {
 						Self::terminate_schedule(schedule_id, &schedule, e);
@@ -273,6 +294,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinimalPeriod: Get<u32>;
 
+		///Max number of blocks between scheduling a limit order and its deadline
+		#[pallet::constant]
+		type MaxLimitOrderDuration: Get<u32>;
+
 		///Chance of the random rescheduling
 		#[pallet::constant]
 		type BumpChance: Get<Percent>;
@@ -322,7 +347,7 @@ pub mod pallet {
 			who: T::AccountId,
 			period: BlockNumberFor<T>,
 			total_amount: Balance,
-			order: Order<T::AssetId>,
+			order: Order<T::AssetId, BlockNumberFor<T>>,
 		},
 		///The DCA is planned for blocknumber
 		ExecutionPlanned {
@@ -351,6 +376,17 @@ pub mod pallet {
 		},
 		///The DCA is completed and completely removed from the chain
 		Completed { id: ScheduleId, who: T::AccountId },
+		///The DCA is paused and not executed until it is resumed
+		Paused { id: ScheduleId, who: T::AccountId },
+		///The paused DCA is resumed
+		Resumed { id: ScheduleId, who: T::AccountId },
+		///The period and the budget of the DCA are updated
+		Updated {
+			id: ScheduleId,
+			who: T::AccountId,
+			period: BlockNumberFor<T>,
+			total_amount: Balance,
+		},
 		///Randomness generation failed possibly coming from missing data about relay chain
 		RandomnessGenerationFailed {
 			block: BlockNumberFor<T>,
@@ -396,6 +432,18 @@ pub mod pallet {
 		PeriodTooShort,
 		///Stability threshold cannot be higher than `MaxConfigurablePriceDifferenceBetweenBlock`
 		StabilityThresholdTooHigh,
+		///The limit price of the limit order should be greater than zero
+		InvalidLimitPrice,
+		///The limit price of the limit order has not been reached yet, leading to a new check
+		LimitPriceNotReached,
+		///The deadline of the limit order has passed
+		LimitOrderExpired,
+		///The deadline of the limit order is further than `MaxLimitOrderDuration` blocks
+		LimitOrderDeadlineTooFar,
+		///The schedule is already paused
+		ScheduleAlreadyPaused,
+		///The schedule is not paused
+		ScheduleNotPaused,
 	}
 
 	/// Id sequencer for schedules
@@ -431,6 +479,11 @@ pub mod pallet {
 	pub type ScheduleIdsPerBlock<T: Config> =
 		StorageMap<_, Blake2_128Concat, BlockNumberFor<T>, BoundedVec<ScheduleId, T::MaxSchedulePerBlock>, ValueQuery>;
 
+	/// Keep tracking of the paused schedules, which are not planned in any block
+	#[pallet::storage]
+	#[pallet::getter(fn paused_schedules)]
+	pub type PausedSchedules<T: Config> = StorageMap<_, Blake2_128Concat, ScheduleId, (), OptionQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Creates a new DCA (Dollar-Cost Averaging) schedule and plans the next execution
@@ -450,6 +503,13 @@ pub mod pallet {
 		/// the schedule will be terminated permanently.
 		/// In the case of a successful trade, the retry counter is reset.
 		///
+		/// If the route of the order is empty, a route is discovered on-chain by the router.
+		/// When the discovered route is better than the stored (or default) route of the asset pair,
+		/// it is pinned into the order, otherwise the route stays empty and the router route is used.
+		///
+		/// Limit orders are checked every `period` blocks, and executed only when the limit price is reached.
+		/// Their deadline must be in the future, at most `MaxLimitOrderDuration` blocks from now.
+		///
 		/// Parameters:
 		/// - `origin`: schedule owner
 		/// - `schedule`: schedule details
@@ -459,16 +519,19 @@ pub mod pallet {
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(<T as Config>::WeightInfo::schedule()
-			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>()))]
+			+ <T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(&schedule.order.get_route_or_default::<T::RouteProvider>())
+			+ if schedule.order.has_empty_route() { <T as Config>::AmmTradeWeights::discover_route_weight() } else { Weight::zero() })]
 		#[transactional]
 		pub fn schedule(
 			origin: OriginFor<T>,
-			schedule: Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+			mut schedule: Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
 			start_execution_block: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 			ensure!(who == schedule.owner, Error::<T>::Forbidden);
 
+			schedule.order.set_discovered_route::<T::RouteProvider>();
+
 			let min_budget = Self::convert_native_amount_to_currency(
 				schedule.order.get_asset_in(),
 				T::MinBudgetInNativeCurrency::get(),
@@ -491,12 +554,37 @@ pub mod pallet {
 
 			let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
 
+			if let Some(deadline) = schedule.order.get_deadline() {
+				let current_block_number = frame_system::Pallet::<T>::current_block_number();
+				ensure!(deadline > current_block_number, Error::<T>::BlockNumberIsNotInFuture);
+				ensure!(
+					deadline <= current_block_number.saturating_add(T::MaxLimitOrderDuration::get().into()),
+					Error::<T>::LimitOrderDeadlineTooFar
+				);
+			}
+
 			let amount_in = match schedule.order {
 				Order::Sell { amount_in, .. } => amount_in,
 				Order::Buy { amount_out, .. } => {
 					let route = schedule.order.get_route_or_default::<T::RouteProvider>();
 					Self::get_amount_in_for_buy(&amount_out, &route)?
 				}
+				Order::LimitSell {
+					amount_in, limit_price, ..
+				} => {
+					ensure!(limit_price.into_inner() > 0, Error::<T>::InvalidLimitPrice);
+					amount_in
+				}
+				Order::LimitBuy {
+					amount_out,
+					limit_price,
+					..
+				} => {
+					ensure!(limit_price.into_inner() > 0, Error::<T>::InvalidLimitPrice);
+					limit_price
+						.checked_mul_int(amount_out)
+						.ok_or(ArithmeticError::Overflow)?
+				}
 			};
 			let min_trade_amount_in_from_fee = transaction_fee.saturating_mul(FEE_MULTIPLIER_FOR_MIN_TRADE_LIMIT);
 			ensure!(
@@ -569,7 +657,7 @@ pub mod pallet {
 		/// Emits `Terminated` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::terminate())]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
 		#[transactional]
 		pub fn terminate(
 			origin: OriginFor<T>,
@@ -585,26 +673,11 @@ pub mod pallet {
 
 			Self::try_unreserve_all(schedule_id, &schedule);
 
-			let next_execution_block = next_execution_block.ok_or(Error::<T>::ScheduleNotFound)?;
-
-			//Remove schedule id from next execution block
-			ScheduleIdsPerBlock::<T>::try_mutate_exists(
-				next_execution_block,
-				|maybe_schedule_ids| -> DispatchResult {
-					let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
-
-					let index = schedule_ids
-						.binary_search(&schedule_id)
-						.map_err(|_| Error::<T>::ScheduleNotFound)?;
-
-					schedule_ids.remove(index);
-
-					if schedule_ids.is_empty() {
-						*maybe_schedule_ids = None;
-					}
-					Ok(())
-				},
-			)?;
+			//Paused schedules are not planned in any block
+			if !PausedSchedules::<T>::contains_key(schedule_id) {
+				let next_execution_block = next_execution_block.ok_or(Error::<T>::ScheduleNotFound)?;
+				Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
+			}
 
 			Self::remove_schedule_from_storages(&schedule.owner, schedule_id);
 
@@ -616,10 +689,157 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Pauses a DCA schedule, so it is not executed until it is resumed.
+		///
+		/// The schedule is removed from its next execution block, but it keeps its id,
+		/// its remaining amount and the named reserve of the budget.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `next_execution_block`: block number where the schedule is planned.
+		///
+		/// Emits `Paused` event when successful.
+		///
+		#[pallet::call_index(2)]
+		// Bounded by `terminate`, which removes the schedule from its block and from all storages
+		#[pallet::weight(<T as Config>::WeightInfo::terminate().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		#[transactional]
+		pub fn pause_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			next_execution_block: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			ensure!(
+				!PausedSchedules::<T>::contains_key(schedule_id),
+				Error::<T>::ScheduleAlreadyPaused
+			);
+
+			Self::remove_schedule_id_from_block(schedule_id, next_execution_block)?;
+			PausedSchedules::<T>::insert(schedule_id, ());
+
+			Self::deposit_event(Event::Paused { id: schedule_id, who });
+
+			Ok(())
+		}
+
+		/// Resumes a paused DCA schedule and plans its next execution.
+		///
+		/// If the block is not specified, the execution is planned for the next block.
+		/// If the given block is full, the execution will be planned in the subsequent block.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `start_execution_block`: first possible execution block for the resumed schedule
+		///
+		/// Emits `Resumed` and `ExecutionPlanned` event when successful.
+		///
+		#[pallet::call_index(3)]
+		// Bounded by `schedule`, which plans the schedule the same way with all blocks of the search full
+		#[pallet::weight(<T as Config>::WeightInfo::schedule().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
+		#[transactional]
+		pub fn resume_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			start_execution_block: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let schedule = Schedules::<T>::get(schedule_id).ok_or(Error::<T>::ScheduleNotFound)?;
+			ensure!(who == schedule.owner, Error::<T>::Forbidden);
+			PausedSchedules::<T>::take(schedule_id).ok_or(Error::<T>::ScheduleNotPaused)?;
+
+			Self::deposit_event(Event::Resumed {
+				id: schedule_id,
+				who: who.clone(),
+			});
+
+			let next_execution_block = Self::get_first_execution_block(start_execution_block)?;
+			let mut randomness_generator =
+				Self::get_randomness_generator(frame_system::Pallet::<T>::current_block_number(), Some(schedule_id));
+			Self::plan_schedule_for_block(&who, next_execution_block, schedule_id, &mut randomness_generator)?;
+
+			Ok(())
+		}
+
+		/// Updates the period and/or the budget of a DCA schedule in place, keeping its id.
+		///
+		/// When the `total_amount` is increased, the difference is reserved and added to the remaining amount.
+		/// When it is decreased, the difference is unreserved and subtracted from the remaining amount,
+		/// which still has to cover at least one more trade.
+		///
+		/// The new period is used when the schedule is replanned after its next execution.
+		///
+		/// Parameters:
+		/// - `origin`: schedule owner
+		/// - `schedule_id`: schedule id
+		/// - `period`: new period of the schedule, unchanged if not specified
+		/// - `total_amount`: new total amount (budget) of the schedule, unchanged if not specified
+		///
+		/// Emits `Updated` event when successful.
+		///
+		#[pallet::call_index(4)]
+		// Bounded by `schedule`, which validates the budget, reserves it and stores the schedule,
+		// and the buy calculation over the route of the schedule
+		#[pallet::weight(Pallet::<T>::update_schedule_weight(*schedule_id))]
+		#[transactional]
+		pub fn update_schedule(
+			origin: OriginFor<T>,
+			schedule_id: ScheduleId,
+			period: Option<BlockNumberFor<T>>,
+			total_amount: Option<Balance>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Schedules::<T>::try_mutate(schedule_id, |maybe_schedule| -> DispatchResult {
+				let schedule = maybe_schedule.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+				ensure!(who == schedule.owner, Error::<T>::Forbidden);
+
+				if let Some(period) = period {
+					ensure!(
+						period >= BlockNumberFor::<T>::from(T::MinimalPeriod::get()),
+						Error::<T>::PeriodTooShort
+					);
+					schedule.period = period;
+				}
+
+				if let Some(total_amount) = total_amount {
+					Self::update_budget(schedule_id, schedule, total_amount)?;
+					schedule.total_amount = total_amount;
+				}
+
+				Self::deposit_event(Event::Updated {
+					id: schedule_id,
+					who: who.clone(),
+					period: schedule.period,
+					total_amount: schedule.total_amount,
+				});
+
+				Ok(())
+			})
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Weight of `update_schedule`, including the buy calculation over the route of the schedule
+	/// which is needed to validate the new budget of a buy order.
+	pub fn update_schedule_weight(schedule_id: ScheduleId) -> Weight {
+		let route_weight = Schedules::<T>::get(schedule_id)
+			.map(|schedule| {
+				<T as Config>::AmmTradeWeights::calculate_buy_trade_amounts_weight(
+					&schedule.order.get_route_or_default::<T::RouteProvider>(),
+				)
+			})
+			.unwrap_or_default();
+
+		<T as Config>::WeightInfo::schedule().saturating_add(route_weight)
+	}
+
 	fn get_randomness_generator(current_blocknumber: BlockNumberFor<T>, salt: Option<u32>) -> StdRng {
 		match T::RandomnessProvider::generator(salt) {
 			Ok(generator) => generator,
@@ -670,8 +890,16 @@ impl<T: Config> Pallet<T> {
 			return Err(Error::<T>::Bumped.into());
 		}
 
+		if let Some(deadline) = schedule.order.get_deadline() {
+			ensure!(current_blocknumber <= deadline, Error::<T>::LimitOrderExpired);
+		}
+
 		Self::take_transaction_fee_from_user(schedule_id, schedule, weight_for_dca_execution)?;
 
+		if schedule.order.is_limit_order() {
+			Self::ensure_limit_price_is_reached(current_blocknumber, schedule_id, schedule, randomness_generator)?;
+		}
+
 		if Self::is_price_unstable(schedule) {
 			Self::deposit_event(Event::TradeFailed {
 				id: schedule_id,
@@ -686,6 +914,42 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_limit_price_is_reached(
+		current_blocknumber: BlockNumberFor<T>,
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		randomness_generator: &mut StdRng,
+	) -> DispatchResult {
+		if !Self::is_limit_price_reached(&schedule.order)? {
+			let next_check_block = current_blocknumber
+				.checked_add(&schedule.period)
+				.ok_or(ArithmeticError::Overflow)?;
+			Self::plan_schedule_for_block(&schedule.owner, next_check_block, schedule_id, randomness_generator)?;
+
+			return Err(Error::<T>::LimitPriceNotReached.into());
+		}
+
+		Ok(())
+	}
+
+	fn is_limit_price_reached(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Result<bool, DispatchError> {
+		let route = order.get_route_or_default::<T::RouteProvider>();
+
+		match order {
+			// The oracle price of the inversed route is the amount of `asset_out` per one unit of `asset_in`
+			Order::LimitSell { limit_price, .. } => {
+				let price = Self::get_price_from_short_oracle(&inverse_route(route))?;
+				Ok(price >= *limit_price)
+			}
+			// The oracle price of the route is the amount of `asset_in` per one unit of `asset_out`
+			Order::LimitBuy { limit_price, .. } => {
+				let price = Self::get_price_from_short_oracle(&route)?;
+				Ok(price <= *limit_price)
+			}
+			Order::Sell { .. } | Order::Buy { .. } => Ok(true),
+		}
+	}
+
 	#[transactional]
 	pub fn execute_trade(
 		schedule_id: ScheduleId,
@@ -771,6 +1035,61 @@ impl<T: Config> Pallet<T> {
 
 				T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route.to_vec())?;
 
+				Ok(AmountInAndOut {
+					amount_in,
+					amount_out: *amount_out,
+				})
+			}
+			Order::LimitSell {
+				asset_in,
+				asset_out,
+				amount_in,
+				limit_price,
+				..
+			} => {
+				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+				let remaining_amount =
+					RemainingAmounts::<T>::get(schedule_id).defensive_ok_or(Error::<T>::InvalidState)?;
+				let amount_to_sell = min(remaining_amount, *amount_in);
+
+				Self::unallocate_amount(schedule_id, schedule, amount_to_sell)?;
+
+				let min_amount_out = limit_price
+					.checked_mul_int(amount_to_sell)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				let trade_amounts = T::RouteExecutor::calculate_sell_trade_amounts(&route, amount_to_sell)?;
+				let last_trade = trade_amounts.last().defensive_ok_or(Error::<T>::InvalidState)?;
+				let amount_out = last_trade.amount_out;
+
+				ensure!(amount_out >= min_amount_out, Error::<T>::TradeLimitReached);
+
+				T::RouteExecutor::sell(origin, *asset_in, *asset_out, amount_to_sell, amount_out, route)?;
+
+				Ok(AmountInAndOut {
+					amount_in: amount_to_sell,
+					amount_out,
+				})
+			}
+			Order::LimitBuy {
+				asset_in,
+				asset_out,
+				amount_out,
+				limit_price,
+				..
+			} => {
+				let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+				let amount_in = Self::get_amount_in_for_buy(amount_out, &route)?;
+
+				Self::unallocate_amount(schedule_id, schedule, amount_in)?;
+
+				let max_amount_in = limit_price
+					.checked_mul_int(*amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+				ensure!(amount_in <= max_amount_in, Error::<T>::TradeLimitReached);
+
+				T::RouteExecutor::buy(origin, *asset_in, *asset_out, *amount_out, amount_in, route)?;
+
 				Ok(AmountInAndOut {
 					amount_in,
 					amount_out: *amount_out,
@@ -805,7 +1124,7 @@ impl<T: Config> Pallet<T> {
 		}
 
 		//In buy we complete with returning leftover, in sell we sell the leftover in the next trade
-		if let Order::Buy { amount_out, .. } = &schedule.order {
+		if let Order::Buy { amount_out, .. } | Order::LimitBuy { amount_out, .. } = &schedule.order {
 			let route = schedule.order.get_route_or_default::<T::RouteProvider>();
 			let amount_to_unreserve: Balance = Self::get_amount_in_for_buy(amount_out, &route)?;
 
@@ -905,7 +1224,7 @@ impl<T: Config> Pallet<T> {
 		Ok(first_trade.amount_in)
 	}
 
-	pub fn get_transaction_fee(order: &Order<T::AssetId>) -> Result<Balance, DispatchError> {
+	pub fn get_transaction_fee(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Result<Balance, DispatchError> {
 		Self::convert_weight_to_fee(Self::get_trade_weight(order), order.get_asset_in())
 	}
 
@@ -1105,10 +1424,10 @@ impl<T: Config> Pallet<T> {
 	}
 
 	// returns DCA overhead weight + router execution weight
-	fn get_trade_weight(order: &Order<T::AssetId>) -> Weight {
+	fn get_trade_weight(order: &Order<T::AssetId, BlockNumberFor<T>>) -> Weight {
 		let route = &order.get_route_or_default::<T::RouteProvider>();
 		match order {
-			Order::Sell { .. } => {
+			Order::Sell { .. } | Order::LimitSell { .. } => {
 				let on_initialize_weight =
 					if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(order.get_asset_in()) {
 						<T as Config>::WeightInfo::on_initialize_with_sell_trade()
@@ -1119,7 +1438,7 @@ impl<T: Config> Pallet<T> {
 				on_initialize_weight
 					.saturating_add(T::AmmTradeWeights::sell_and_calculate_sell_trade_amounts_weight(route))
 			}
-			Order::Buy { .. } => {
+			Order::Buy { .. } | Order::LimitBuy { .. } => {
 				let on_initialize_weight =
 					if T::SwappablePaymentAssetSupport::is_transaction_fee_currency(order.get_asset_in()) {
 						<T as Config>::WeightInfo::on_initialize_with_buy_trade()
@@ -1194,6 +1513,87 @@ impl<T: Config> Pallet<T> {
 		ScheduleOwnership::<T>::remove(owner, schedule_id);
 		RemainingAmounts::<T>::remove(schedule_id);
 		RetriesOnError::<T>::remove(schedule_id);
+		PausedSchedules::<T>::remove(schedule_id);
+	}
+
+	fn remove_schedule_id_from_block(schedule_id: ScheduleId, blocknumber: BlockNumberFor<T>) -> DispatchResult {
+		ScheduleIdsPerBlock::<T>::try_mutate_exists(blocknumber, |maybe_schedule_ids| -> DispatchResult {
+			let schedule_ids = maybe_schedule_ids.as_mut().ok_or(Error::<T>::ScheduleNotFound)?;
+
+			//Resumed schedules are appended to the block, so the ids are not necessarily sorted
+			let index = schedule_ids
+				.iter()
+				.position(|id| *id == schedule_id)
+				.ok_or(Error::<T>::ScheduleNotFound)?;
+
+			schedule_ids.remove(index);
+
+			if schedule_ids.is_empty() {
+				*maybe_schedule_ids = None;
+			}
+			Ok(())
+		})
+	}
+
+	/// Reserves or unreserves the difference between the new and the current total amount of the schedule,
+	/// and applies it to the remaining amount.
+	fn update_budget(
+		schedule_id: ScheduleId,
+		schedule: &Schedule<T::AccountId, T::AssetId, BlockNumberFor<T>>,
+		new_total_amount: Balance,
+	) -> DispatchResult {
+		let min_budget = Self::convert_native_amount_to_currency(
+			schedule.order.get_asset_in(),
+			T::MinBudgetInNativeCurrency::get(),
+		)?;
+		ensure!(
+			new_total_amount >= min_budget,
+			Error::<T>::TotalAmountIsSmallerThanMinBudget
+		);
+
+		let sold_currency = schedule.order.get_asset_in();
+
+		RemainingAmounts::<T>::try_mutate(schedule_id, |maybe_remaining_amount| -> DispatchResult {
+			let remaining_amount = maybe_remaining_amount
+				.as_mut()
+				.defensive_ok_or(Error::<T>::InvalidState)?;
+
+			if new_total_amount >= schedule.total_amount {
+				let top_up = new_total_amount.saturating_sub(schedule.total_amount);
+				T::Currencies::reserve_named(&T::NamedReserveId::get(), sold_currency, &schedule.owner, top_up)?;
+
+				*remaining_amount = remaining_amount.checked_add(top_up).ok_or(ArithmeticError::Overflow)?;
+			} else {
+				let decrease = schedule.total_amount.saturating_sub(new_total_amount);
+				*remaining_amount = remaining_amount.checked_sub(decrease).ok_or(Error::<T>::BudgetTooLow)?;
+
+				let transaction_fee = Self::get_transaction_fee(&schedule.order)?;
+				let amount_in = match schedule.order {
+					Order::Sell { amount_in, .. } | Order::LimitSell { amount_in, .. } => amount_in,
+					Order::Buy { amount_out, .. } => {
+						let route = schedule.order.get_route_or_default::<T::RouteProvider>();
+						Self::get_amount_in_for_buy(&amount_out, &route)?
+					}
+					Order::LimitBuy {
+						amount_out,
+						limit_price,
+						..
+					} => limit_price
+						.checked_mul_int(amount_out)
+						.ok_or(ArithmeticError::Overflow)?,
+				};
+				ensure!(
+					amount_in.saturating_add(transaction_fee) <= *remaining_amount,
+					Error::<T>::BudgetTooLow
+				);
+
+				let remaining_amount_if_insufficient_balance =
+					T::Currencies::unreserve_named(&T::NamedReserveId::get(), sold_currency, &schedule.owner, decrease);
+				ensure!(remaining_amount_if_insufficient_balance == 0, Error::<T>::InvalidState);
+			}
+
+			Ok(())
+		})
 	}
 }
 
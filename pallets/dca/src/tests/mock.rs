@@ -101,6 +101,7 @@ thread_local! {
 	pub static FEE_ASSET: RefCell<Vec<(u64,AssetId)>> = RefCell::new(vec![(ALICE,HDX)]);
 	pub static MIN_BUDGET: RefCell<Balance> = RefCell::new(*ORIGINAL_MIN_BUDGET_IN_NATIVE);
 	pub static BUY_EXECUTIONS: RefCell<Vec<BuyExecution>> = const { RefCell::new(vec![]) };
+	pub static DISCOVERED_ROUTE: RefCell<Option<Vec<Trade<AssetId>>>> = const { RefCell::new(None) };
 	pub static SELL_EXECUTIONS: RefCell<Vec<SellExecution>> = const { RefCell::new(vec![]) };
 	pub static SET_OMNIPOOL_ON: RefCell<bool> = const { RefCell::new(true) };
 	pub static MAX_PRICE_DIFFERENCE: RefCell<Permill> = RefCell::new(*ORIGINAL_MAX_PRICE_DIFFERENCE);
@@ -147,6 +148,8 @@ impl pallet_ema_oracle::Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = Everything;
 	type MaxUniqueEntries = ConstU32<20>;
+	type CheckpointInterval = frame_support::traits::ConstU64<0>;
+	type MaxCheckpoints = ConstU32<0>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -370,6 +373,9 @@ impl pallet_route_executor::Config for Test {
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type WeightInfo = ();
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = ();
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRoutePoolAssets = frame_support::traits::ConstU32<100>;
 	type EdToRefundCalculator = MockedEdCalculator;
 	type OraclePriceProvider = PriceProviderMock;
 	type OraclePeriod = RouteValidationOraclePeriod;
@@ -653,6 +659,7 @@ parameter_types! {
 	pub OmnipoolMaxAllowedPriceDifference: Permill = MAX_PRICE_DIFFERENCE.with(|v| *v.borrow());
 	pub MaxConfigurablePriceDifference: Permill = Permill::from_percent(20);
 	pub MinimalPeriod: u32 = 5;
+	pub MaxLimitOrderDuration: u32 = 1000;
 	pub BumpChance: Percent = Percent::from_percent(0);
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
@@ -690,6 +697,7 @@ impl Config for Test {
 	type MaxPriceDifferenceBetweenBlocks = OmnipoolMaxAllowedPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
 	type MinimalPeriod = MinimalPeriod;
+	type MaxLimitOrderDuration = MaxLimitOrderDuration;
 	type BumpChance = BumpChance;
 	type NamedReserveId = NamedReserveId;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
@@ -758,7 +766,11 @@ impl NativePriceOracle<AssetId, EmaPrice> for NativePriceOracleMock {
 
 pub struct DefaultRouteProvider;
 
-impl RouteProvider<AssetId> for DefaultRouteProvider {}
+impl RouteProvider<AssetId> for DefaultRouteProvider {
+	fn discover_route(_asset_pair: AssetPair<AssetId>) -> Option<Vec<Trade<AssetId>>> {
+		DISCOVERED_ROUTE.with(|v| v.borrow().clone())
+	}
+}
 
 pub struct ParentHashGetterMock {}
 
@@ -775,7 +787,9 @@ use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::to_u128_wrapper;
 use hydra_dx_math::types::Ratio;
 use hydradx_traits::fee::{InspectTransactionFeeCurrency, SwappablePaymentAssetTrader};
-use hydradx_traits::router::{ExecutorError, PoolType, RefundEdCalculator, RouteProvider, Trade, TradeExecution};
+use hydradx_traits::router::{
+	AssetPair, ExecutorError, PoolType, RefundEdCalculator, RouteProvider, Trade, TradeExecution,
+};
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_omnipool::traits::ExternalPriceProvider;
 use rand::prelude::StdRng;
@@ -7,8 +7,10 @@ use sp_runtime::{BoundedVec, Permill};
 
 pub mod mock;
 pub mod on_initialize;
+pub mod pause;
 pub mod schedule;
 pub mod terminate;
+pub mod update;
 
 #[macro_export]
 macro_rules! assert_balance {
@@ -20,7 +22,7 @@ macro_rules! assert_balance {
 struct ScheduleBuilder {
 	pub owner: Option<AccountId>,
 	pub period: Option<BlockNumber>,
-	pub order: Option<Order<AssetId>>,
+	pub order: Option<Order<AssetId, BlockNumber>>,
 	pub total_amount: Option<Balance>,
 	pub max_retries: Option<Option<u8>>,
 	pub slippage: Option<Option<Permill>>,
@@ -60,7 +62,7 @@ impl ScheduleBuilder {
 		self
 	}
 
-	fn with_order(mut self, buy_order: Order<AssetId>) -> ScheduleBuilder {
+	fn with_order(mut self, buy_order: Order<AssetId, BlockNumber>) -> ScheduleBuilder {
 		self.order = Some(buy_order);
 		self
 	}
@@ -124,5 +126,6 @@ macro_rules! assert_that_schedule_has_been_removed_from_storages {
 		assert!(DCA::owner_of($owner, $schedule_id).is_none());
 		assert!(DCA::remaining_amounts($schedule_id).is_none());
 		assert_eq!(DCA::retries_on_error($schedule_id), 0);
+		assert!(DCA::paused_schedules($schedule_id).is_none());
 	};
 }
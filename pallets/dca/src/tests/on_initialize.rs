@@ -31,6 +31,7 @@ use orml_traits::MultiReservableCurrency;
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
 use sp_runtime::DispatchError;
+use sp_runtime::FixedU128;
 use std::borrow::Borrow;
 use std::ops::RangeInclusive;
 
@@ -2660,6 +2661,270 @@ fn dca_schedule_should_still_take_fee_when_order_fails() {
 		});
 }
 
+#[test]
+fn limit_sell_should_be_executed_when_limit_price_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+			let amount_to_sell = ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::LimitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: amount_to_sell,
+					limit_price: FixedU128::from_rational(70, 100),
+					deadline: 1000,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			assert_executed_sell_trades!(vec![SellExecution {
+				asset_in: HDX,
+				asset_out: BTC,
+				amount_in: amount_to_sell,
+				min_buy_amount: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+			}]);
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: amount_to_sell,
+					amount_out: *AMOUNT_OUT_FOR_OMNIPOOL_SELL,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn limit_sell_should_be_replanned_with_fee_when_limit_price_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::LimitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					limit_price: FixedU128::from_rational(90, 100),
+					deadline: 1000,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			let fee_in_native = DCA::get_transaction_fee(&schedule.order).unwrap();
+			assert_number_of_executed_sell_trades!(0);
+			assert_balance!(TreasuryAccount::get(), HDX, fee_in_native);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount - fee_in_native
+			);
+			assert_eq!(
+				total_amount - fee_in_native,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+			assert_scheduled_ids!(602, vec![schedule_id]);
+		});
+}
+
+#[test]
+fn limit_buy_should_be_executed_when_limit_price_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 50 * ONE;
+			let amount_to_buy = 10 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::LimitBuy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: amount_to_buy,
+					limit_price: FixedU128::from(1),
+					deadline: 1000,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_buy_trades!(1);
+			expect_events(vec![
+				DcaEvent::TradeExecuted {
+					id: schedule_id,
+					who: ALICE,
+					amount_in: CALCULATED_AMOUNT_IN_FOR_OMNIPOOL_BUY,
+					amount_out: amount_to_buy,
+				}
+				.into(),
+				DcaEvent::ExecutionPlanned {
+					id: schedule_id,
+					who: ALICE,
+					block: 602,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn limit_buy_should_be_replanned_with_fee_when_limit_price_is_not_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 50 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::LimitBuy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: 10 * ONE,
+					limit_price: FixedU128::from_rational(70, 100),
+					deadline: 1000,
+					route: create_bounded_vec(vec![Trade {
+						pool: Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule.clone(),
+				Option::None
+			));
+
+			//Act
+			set_to_blocknumber(502);
+
+			//Assert
+			let schedule_id = 0;
+			let fee_in_native = DCA::get_transaction_fee(&schedule.order).unwrap();
+			assert_number_of_executed_buy_trades!(0);
+			assert_balance!(TreasuryAccount::get(), HDX, fee_in_native);
+			assert_eq!(
+				DCA::remaining_amounts(schedule_id).unwrap(),
+				total_amount - fee_in_native
+			);
+			assert_scheduled_ids!(602, vec![schedule_id]);
+		});
+}
+
+#[test]
+fn limit_order_should_be_terminated_when_deadline_has_passed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			proceed_to_blocknumber(1, 500);
+
+			let total_amount = 5 * ONE;
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(total_amount)
+				.with_period(ONE_HUNDRED_BLOCKS)
+				.with_order(Order::LimitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					limit_price: FixedU128::from_rational(90, 100),
+					deadline: 550,
+					route: create_bounded_vec(vec![Trade {
+						pool: PoolType::Omnipool,
+						asset_in: HDX,
+						asset_out: BTC,
+					}]),
+				})
+				.build();
+
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+			set_to_blocknumber(502);
+
+			//Act
+			set_to_blocknumber(602);
+
+			//Assert
+			let schedule_id = 0;
+			assert_number_of_executed_sell_trades!(0);
+			assert_eq!(
+				0,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+			assert_that_dca_is_terminated(ALICE, schedule_id, Error::<Test>::LimitOrderExpired.into());
+		});
+}
+
 pub fn proceed_to_blocknumber(from: u64, to: u64) {
 	for block_number in RangeInclusive::new(from, to) {
 		System::set_block_number(block_number);
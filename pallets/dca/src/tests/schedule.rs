@@ -25,6 +25,7 @@ use hydradx_traits::router::{PoolType, Trade};
 use orml_traits::NamedMultiReservableCurrency;
 use pretty_assertions::assert_eq;
 use sp_runtime::DispatchError::BadOrigin;
+use sp_runtime::{FixedPointNumber, FixedU128};
 use std::ops::RangeInclusive;
 
 #[test]
@@ -886,6 +887,146 @@ fn schedule_should_be_created_when_no_routes_specified() {
 		});
 }
 
+#[test]
+fn schedule_should_store_discovered_route_when_no_routes_specified() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let discovered_route = vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: BTC,
+				},
+			];
+			DISCOVERED_ROUTE.with(|v| *v.borrow_mut() = Some(discovered_route.clone()));
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(vec![]),
+				})
+				.build();
+
+			//Act
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None));
+
+			//Assert
+			let schedule_id = 0;
+			let stored_schedule = DCA::schedules(schedule_id).unwrap();
+			assert_eq!(
+				stored_schedule.order,
+				Order::Sell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					min_amount_out: Balance::MIN,
+					route: create_bounded_vec(discovered_route),
+				}
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_order_deadline_is_not_in_future() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(Order::LimitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					limit_price: FixedU128::from_rational(1, 2),
+					deadline: 500,
+					route: create_bounded_vec(vec![]),
+				})
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::BlockNumberIsNotInFuture
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_order_deadline_is_too_far() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(Order::LimitSell {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_in: ONE,
+					limit_price: FixedU128::from_rational(1, 2),
+					deadline: 500 + MaxLimitOrderDuration::get() as u64 + 1,
+					route: create_bounded_vec(vec![]),
+				})
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::LimitOrderDeadlineTooFar
+			);
+		});
+}
+
+#[test]
+fn schedule_should_fail_when_limit_price_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+
+			let schedule = ScheduleBuilder::new()
+				.with_total_amount(100 * ONE)
+				.with_order(Order::LimitBuy {
+					asset_in: HDX,
+					asset_out: BTC,
+					amount_out: ONE,
+					limit_price: FixedU128::from_inner(0),
+					deadline: 1000,
+					route: create_bounded_vec(vec![]),
+				})
+				.build();
+
+			//Act and assert
+			assert_noop!(
+				DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::None),
+				Error::<Test>::InvalidLimitPrice
+			);
+		});
+}
+
 #[test]
 fn thousands_of_dcas_can_be_scheduled_on_a_specific_block_because_of_salt_added_to_block_search_randomness() {
 	ExtBuilder::default()
@@ -0,0 +1,197 @@
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::tests::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn update_should_reserve_difference_when_total_amount_is_increased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				None,
+				Some(150 * ONE)
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(schedule_id).unwrap().total_amount, 150 * ONE);
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(150 * ONE));
+			assert_eq!(
+				150 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+
+			expect_events(vec![Event::Updated {
+				id: schedule_id,
+				who: ALICE,
+				period: ONE_HUNDRED_BLOCKS,
+				total_amount: 150 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn update_should_unreserve_difference_when_total_amount_is_decreased() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let total_amount = 100 * ONE;
+			let schedule = ScheduleBuilder::new().with_total_amount(total_amount).build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				None,
+				Some(60 * ONE)
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(schedule_id).unwrap().total_amount, 60 * ONE);
+			assert_eq!(DCA::remaining_amounts(schedule_id), Some(60 * ONE));
+			assert_eq!(
+				60 * ONE,
+				Currencies::reserved_balance_named(&NamedReserveId::get(), HDX, &ALICE)
+			);
+		});
+}
+
+#[test]
+fn update_should_change_period_and_keep_planned_execution() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			let schedule_id = 0;
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act
+			assert_ok!(DCA::update_schedule(
+				RuntimeOrigin::signed(ALICE),
+				schedule_id,
+				Some(50),
+				None
+			));
+
+			//Assert
+			assert_eq!(DCA::schedules(schedule_id).unwrap().period, 50);
+			assert_eq!(DCA::schedule_ids_per_block(600).to_vec(), vec![schedule_id]);
+		});
+}
+
+#[test]
+fn update_should_fail_when_period_is_too_short() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(ALICE), 0, Some(3), None),
+				Error::<Test>::PeriodTooShort
+			);
+		});
+}
+
+#[test]
+fn update_should_fail_when_remaining_budget_does_not_cover_one_trade() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().with_total_amount(100 * ONE).build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(ALICE), 0, None, Some(3_000_000)),
+				Error::<Test>::BudgetTooLow
+			);
+		});
+}
+
+#[test]
+fn update_should_fail_when_total_amount_is_smaller_than_min_budget() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().with_total_amount(100 * ONE).build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(ALICE), 0, None, Some(1_000)),
+				Error::<Test>::TotalAmountIsSmallerThanMinBudget
+			);
+		});
+}
+
+#[test]
+fn update_should_fail_when_called_by_not_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 10000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(500);
+			let schedule = ScheduleBuilder::new().build();
+			assert_ok!(DCA::schedule(RuntimeOrigin::signed(ALICE), schedule, Option::Some(600)));
+
+			//Act and assert
+			assert_noop!(
+				DCA::update_schedule(RuntimeOrigin::signed(BOB), 0, Some(200), None),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+pub fn set_block_number(n: u64) {
+	System::set_block_number(n);
+}
@@ -2,7 +2,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use hydradx_traits::router::{AssetPair, RouteProvider, Trade};
 use scale_info::TypeInfo;
 use sp_runtime::traits::ConstU32;
-use sp_runtime::{BoundedVec, Permill};
+use sp_runtime::{BoundedVec, FixedU128, Permill};
 use sp_std::vec::Vec;
 
 pub type Balance = u128;
@@ -17,6 +17,7 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	/// The owner of the schedule.
 	pub owner: AccountId,
 	/// The time period (in blocks) between two schedule executions.
+	/// For limit orders, it is also the period between two checks of the limit price.
 	pub period: BlockNumber,
 	/// The total amount (budget) the user wants to spend on the whole DCA.
 	/// Its currency is the sold (amount_in) currency specified in `order`.
@@ -31,11 +32,11 @@ pub struct Schedule<AccountId, AssetId, BlockNumber> {
 	/// The slippage limit used to calculate the `min_amount_out` and `max_amount_in` trade limits.
 	pub slippage: Option<Permill>,
 	/// The order containing information to execute a specific trade by the router.
-	pub order: Order<AssetId>,
+	pub order: Order<AssetId, BlockNumber>,
 }
 
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
-pub enum Order<AssetId> {
+pub enum Order<AssetId, BlockNumber> {
 	Sell {
 		asset_in: AssetId,
 		asset_out: AssetId,
@@ -50,16 +51,39 @@ pub enum Order<AssetId> {
 		max_amount_in: Balance,
 		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
 	},
+	/// Sells `amount_in` only when the short oracle price reaches `limit_price`,
+	/// expressed as the amount of `asset_out` received for one unit of `asset_in`.
+	LimitSell {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		limit_price: FixedU128,
+		deadline: BlockNumber,
+		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	},
+	/// Buys `amount_out` only when the short oracle price reaches `limit_price`,
+	/// expressed as the amount of `asset_in` spent for one unit of `asset_out`.
+	LimitBuy {
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		limit_price: FixedU128,
+		deadline: BlockNumber,
+		route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+	},
 }
 
-impl<AssetId> Order<AssetId>
+impl<AssetId, BlockNumber> Order<AssetId, BlockNumber>
 where
 	AssetId: Copy,
+	BlockNumber: Copy,
 {
 	pub fn get_asset_in(&self) -> AssetId {
 		let asset_in = match &self {
 			Order::Sell { asset_in, .. } => asset_in,
 			Order::Buy { asset_in, .. } => asset_in,
+			Order::LimitSell { asset_in, .. } => asset_in,
+			Order::LimitBuy { asset_in, .. } => asset_in,
 		};
 		*asset_in
 	}
@@ -68,19 +92,71 @@ where
 		let asset_out = match &self {
 			Order::Sell { asset_out, .. } => asset_out,
 			Order::Buy { asset_out, .. } => asset_out,
+			Order::LimitSell { asset_out, .. } => asset_out,
+			Order::LimitBuy { asset_out, .. } => asset_out,
 		};
 		*asset_out
 	}
 
-	pub fn get_route_or_default<Provider: RouteProvider<AssetId>>(&self) -> Vec<Trade<AssetId>> {
-		let route = match &self {
+	fn get_route(&self) -> &BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>> {
+		match &self {
 			Order::Sell { route, .. } => route,
 			Order::Buy { route, .. } => route,
-		};
+			Order::LimitSell { route, .. } => route,
+			Order::LimitBuy { route, .. } => route,
+		}
+	}
+
+	pub fn get_route_or_default<Provider: RouteProvider<AssetId>>(&self) -> Vec<Trade<AssetId>> {
+		let route = self.get_route();
 		if route.is_empty() {
 			Provider::get_route(AssetPair::new(self.get_asset_in(), self.get_asset_out()))
 		} else {
 			route.to_vec()
 		}
 	}
+
+	pub fn has_empty_route(&self) -> bool {
+		self.get_route().is_empty()
+	}
+
+	/// Returns the deadline of limit orders, after which the order expires.
+	pub fn get_deadline(&self) -> Option<BlockNumber> {
+		match &self {
+			Order::LimitSell { deadline, .. } => Some(*deadline),
+			Order::LimitBuy { deadline, .. } => Some(*deadline),
+			Order::Sell { .. } | Order::Buy { .. } => None,
+		}
+	}
+
+	pub fn is_limit_order(&self) -> bool {
+		matches!(self, Order::LimitSell { .. } | Order::LimitBuy { .. })
+	}
+
+	/// Pins the discovered route into the order if the route is empty and a better route than the default one is found.
+	pub fn set_discovered_route<Provider: RouteProvider<AssetId>>(&mut self)
+	where
+		AssetId: PartialEq,
+	{
+		if !self.has_empty_route() {
+			return;
+		}
+
+		let asset_pair = AssetPair::new(self.get_asset_in(), self.get_asset_out());
+		let Some(discovered_route) = Provider::discover_route(asset_pair) else {
+			return;
+		};
+		if discovered_route == Provider::get_route(asset_pair) {
+			return;
+		}
+
+		if let Ok(discovered_route) = BoundedVec::try_from(discovered_route) {
+			match self {
+				Order::Sell { route, .. } => *route = discovered_route,
+				Order::Buy { route, .. } => *route = discovered_route,
+				Order::LimitSell { route, .. } => *route = discovered_route,
+				Order::LimitBuy { route, .. } => *route = discovered_route,
+			}
+		}
+	}
 }
@@ -34,12 +34,16 @@
 //! * **Asset fee amplification:** The amplification parameter for asset fee.
 //! * **Protocol fee amplification:** The amplification parameter for protocol fee.
 //! * **Minimum and maximum fee:** The minimum and maximum fee value for asset or protocol fee.
+//! * **Volatility fee:** Additional asset fee proportional to the divergence of short and long term price of an asset.
 //!
 //! ### Storage
 //!
 //! The module stores last calculated fees as tuple of `(Fee, Fee, Block number)` where the first item is asset fee,
 //! the second one is protocol fee and the third one is block number indicating when the two fees were updated.
 //!
+//! Volatility fee is stored separately, as it does not depend on the previously calculated fee. It is added
+//! to the asset fee when fees are retrieved, and the sum is capped at the maximum asset fee.
+//!
 //! ## Interface
 //!
 //! ### Update and retrieve fee
@@ -52,6 +56,7 @@
 //! ### Prerequisites
 //!
 //! An oracle which provides volume in and out of an asset and liquidity.
+//! Optionally, an oracle which provides short and long term price of an asset.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -68,10 +73,10 @@ pub mod types;
 
 pub use pallet::*;
 
-use crate::traits::{Volume, VolumeProvider};
-use crate::types::{FeeEntry, FeeParams};
+use crate::traits::{VolatilityProvider, Volume, VolumeProvider};
+use crate::types::{FeeEntry, FeeParams, VolatilityFeeParams};
 use hydra_dx_math::dynamic_fees::types::OracleEntry;
-use hydra_dx_math::dynamic_fees::{recalculate_asset_fee, recalculate_protocol_fee};
+use hydra_dx_math::dynamic_fees::{calculate_volatility_fee, recalculate_asset_fee, recalculate_protocol_fee};
 
 type Balance = u128;
 
@@ -94,6 +99,12 @@ pub mod pallet {
 	pub type AssetFee<T: Config> =
 		StorageMap<_, Twox64Concat, T::AssetId, FeeEntry<T::Fee, BlockNumberFor<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	#[pallet::getter(fn volatility_fee)]
+	/// Stores last calculated volatility fee of an asset.
+	/// Updated together with the fees in `AssetFee`.
+	pub type AssetVolatilityFee<T: Config> = StorageMap<_, Twox64Concat, T::AssetId, T::Fee, ValueQuery>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
@@ -115,6 +126,12 @@ pub mod pallet {
 
 		#[pallet::constant]
 		type ProtocolFeeParameters: Get<FeeParams<Self::Fee>>;
+
+		/// Short and long term price provider implementation
+		type VolatilityOracle: VolatilityProvider<Self::AssetId>;
+
+		#[pallet::constant]
+		type VolatilityFeeParameters: Get<VolatilityFeeParams<Self::Fee>>;
 	}
 
 	#[pallet::event]
@@ -169,7 +186,7 @@ where
 
 		// Update only if it has not yet been updated this block
 		if block_number == current_fee_entry.timestamp {
-			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
+			return Self::with_volatility_fee(asset_id, current_fee_entry);
 		}
 
 		let delta_blocks: u128 = block_number
@@ -177,10 +194,10 @@ where
 			.saturated_into();
 
 		let Some(volume) = T::Oracle::asset_volume(asset_id) else {
-			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
+			return Self::with_volatility_fee(asset_id, current_fee_entry);
 		};
 		let Some(liquidity) = T::Oracle::asset_liquidity(asset_id) else {
-			return (current_fee_entry.asset_fee, current_fee_entry.protocol_fee);
+			return Self::with_volatility_fee(asset_id, current_fee_entry);
 		};
 
 		let asset_fee = recalculate_asset_fee(
@@ -204,6 +221,12 @@ where
 			protocol_fee_params.into(),
 		);
 
+		let volatility_fee = T::VolatilityOracle::asset_prices(asset_id)
+			.map(|(short_price, long_price)| {
+				calculate_volatility_fee(short_price, long_price, T::VolatilityFeeParameters::get().into())
+			})
+			.unwrap_or_default();
+
 		AssetFee::<T>::insert(
 			asset_id,
 			FeeEntry {
@@ -212,7 +235,22 @@ where
 				timestamp: block_number,
 			},
 		);
-		(asset_fee, protocol_fee)
+		AssetVolatilityFee::<T>::insert(asset_id, volatility_fee);
+
+		(
+			asset_fee.saturating_add(volatility_fee).min(asset_fee_params.max_fee),
+			protocol_fee,
+		)
+	}
+
+	fn with_volatility_fee(asset_id: T::AssetId, fee_entry: FeeEntry<T::Fee, BlockNumberFor<T>>) -> (T::Fee, T::Fee) {
+		(
+			fee_entry
+				.asset_fee
+				.saturating_add(Self::volatility_fee(asset_id))
+				.min(T::AssetFeeParameters::get().max_fee),
+			fee_entry.protocol_fee,
+		)
 	}
 }
 
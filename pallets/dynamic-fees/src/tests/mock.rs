@@ -21,13 +21,14 @@ use sp_std::prelude::*;
 use std::cell::RefCell;
 
 use crate::tests::oracle::Oracle;
-use crate::types::{FeeEntry, FeeParams};
-use crate::{Config, UpdateAndRetrieveFees, Volume, VolumeProvider};
+use crate::types::{FeeEntry, FeeParams, VolatilityFeeParams};
+use crate::{Config, UpdateAndRetrieveFees, VolatilityProvider, Volume, VolumeProvider};
 
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
+use hydra_dx_math::types::Ratio;
 use orml_traits::GetByKey;
 use sp_core::H256;
 use sp_runtime::{
@@ -51,6 +52,11 @@ thread_local! {
 	pub static BLOCK: RefCell<usize> = const { RefCell::new(0) };
 	pub static ASSET_FEE_PARAMS: RefCell<FeeParams<Fee>> = RefCell::new(fee_params_default());
 	pub static PROTOCOL_FEE_PARAMS: RefCell<FeeParams<Fee>> = RefCell::new(fee_params_default());
+	pub static VOLATILITY_FEE_PARAMS: RefCell<VolatilityFeeParams<Fee>> = RefCell::new(VolatilityFeeParams {
+		max_fee: Fee::zero(),
+		amplification: FixedU128::zero(),
+	});
+	pub static ORACLE_PRICES: RefCell<Option<(Ratio, Ratio)>> = const { RefCell::new(None) };
 }
 
 fn fee_params_default() -> FeeParams<Fee> {
@@ -105,6 +111,7 @@ impl frame_system::Config for Test {
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Fee>= ASSET_FEE_PARAMS.with(|v| *v.borrow());
 	pub ProtocolFeeParams: FeeParams<Fee>= PROTOCOL_FEE_PARAMS.with(|v| *v.borrow());
+	pub VolatilityFeeParameters: VolatilityFeeParams<Fee>= VOLATILITY_FEE_PARAMS.with(|v| *v.borrow());
 }
 
 impl Config for Test {
@@ -115,6 +122,8 @@ impl Config for Test {
 	type Oracle = OracleProvider;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type VolatilityOracle = PriceProvider;
+	type VolatilityFeeParameters = VolatilityFeeParameters;
 }
 
 pub struct ExtBuilder {
@@ -126,6 +135,15 @@ impl Default for ExtBuilder {
 		ORACLE.with(|v| {
 			*v.borrow_mut() = Box::new(Oracle::new());
 		});
+		ORACLE_PRICES.with(|v| {
+			*v.borrow_mut() = None;
+		});
+		VOLATILITY_FEE_PARAMS.with(|v| {
+			*v.borrow_mut() = VolatilityFeeParams {
+				max_fee: Fee::zero(),
+				amplification: FixedU128::zero(),
+			}
+		});
 
 		Self { initial_fee: None }
 	}
@@ -164,6 +182,21 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_volatility_fee_params(self, max_fee: Fee, amplification: FixedU128) -> Self {
+		VOLATILITY_FEE_PARAMS.with(|v| {
+			*v.borrow_mut() = VolatilityFeeParams { max_fee, amplification };
+		});
+
+		self
+	}
+
+	pub fn with_oracle_prices(self, short_price: Ratio, long_price: Ratio) -> Self {
+		ORACLE_PRICES.with(|v| {
+			*v.borrow_mut() = Some((short_price, long_price));
+		});
+		self
+	}
+
 	pub fn with_oracle(self, oracle: impl CustomOracle + 'static) -> Self {
 		ORACLE.with(|v| {
 			*v.borrow_mut() = Box::new(oracle);
@@ -214,6 +247,14 @@ impl VolumeProvider<AssetId, Balance> for OracleProvider {
 	}
 }
 
+pub struct PriceProvider;
+
+impl VolatilityProvider<AssetId> for PriceProvider {
+	fn asset_prices(_asset_id: AssetId) -> Option<(Ratio, Ratio)> {
+		ORACLE_PRICES.with(|v| *v.borrow())
+	}
+}
+
 #[derive(Default, Clone, Debug)]
 pub struct AssetVolume {
 	pub(crate) amount_in: Balance,
@@ -5,3 +5,4 @@ mod mock;
 mod oracle;
 mod oracle_fees;
 mod property;
+mod volatility;
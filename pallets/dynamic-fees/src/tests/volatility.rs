@@ -0,0 +1,119 @@
+use crate::tests::mock::*;
+use crate::tests::oracle::SingleValueOracle;
+use hydra_dx_math::types::Ratio;
+use sp_runtime::traits::{One, Zero};
+use sp_runtime::FixedU128;
+
+#[test]
+fn asset_fee_should_include_volatility_fee_when_prices_diverge() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(40),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_volatility_fee_params(Fee::from_percent(5), FixedU128::from_rational(1, 10))
+		.with_oracle_prices(Ratio::new(110, 100), Ratio::one())
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(3));
+			assert_eq!(DynamicFees::volatility_fee(HDX), Fee::from_percent(1));
+			// volatility fee is not accumulated in the asset fee
+			assert_eq!(DynamicFees::current_fees(HDX).unwrap().asset_fee, initial_fee);
+		});
+}
+
+#[test]
+fn volatility_fee_should_be_included_when_fee_is_retrieved_again_in_same_block() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_volatility_fee_params(Fee::from_percent(5), FixedU128::from_rational(1, 10))
+		.with_oracle_prices(Ratio::new(90, 100), Ratio::one())
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+			let fee_again = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(3));
+			assert_eq!(fee, fee_again);
+		});
+}
+
+#[test]
+fn volatility_fee_should_not_exceed_max_volatility_fee() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_volatility_fee_params(Fee::from_percent(5), FixedU128::from(10))
+		.with_oracle_prices(Ratio::new(2, 1), Ratio::one())
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(7));
+		});
+}
+
+#[test]
+fn asset_fee_with_volatility_fee_should_not_exceed_max_asset_fee() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_asset_fee_params(
+			Fee::from_percent(1),
+			Fee::from_percent(4),
+			FixedU128::zero(),
+			FixedU128::one(),
+		)
+		.with_volatility_fee_params(Fee::from_percent(5), FixedU128::from(10))
+		.with_oracle_prices(Ratio::new(2, 1), Ratio::one())
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+			let fee_again = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, Fee::from_percent(4));
+			assert_eq!(fee, fee_again);
+		});
+}
+
+#[test]
+fn volatility_fee_should_be_zero_when_prices_are_not_available() {
+	let initial_fee = Fee::from_percent(2);
+
+	ExtBuilder::default()
+		.with_oracle(SingleValueOracle::new(ONE, ONE, 50 * ONE))
+		.with_initial_fees(initial_fee, Fee::zero(), 0)
+		.with_volatility_fee_params(Fee::from_percent(5), FixedU128::from(10))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			let fee = retrieve_fee_entry(HDX);
+
+			assert_eq!(fee.0, initial_fee);
+			assert_eq!(DynamicFees::volatility_fee(HDX), Fee::zero());
+		});
+}
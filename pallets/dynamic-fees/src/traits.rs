@@ -1,3 +1,5 @@
+use hydra_dx_math::types::Ratio;
+
 pub trait Volume<Balance> {
 	fn amount_in(&self) -> Balance;
 	fn amount_out(&self) -> Balance;
@@ -10,3 +12,14 @@ pub trait VolumeProvider<AssetId, Balance> {
 
 	fn asset_liquidity(asset_id: AssetId) -> Option<Balance>;
 }
+
+pub trait VolatilityProvider<AssetId> {
+	/// Returns short and long term price of an asset as `(short, long)`.
+	fn asset_prices(asset_id: AssetId) -> Option<(Ratio, Ratio)>;
+}
+
+impl<AssetId> VolatilityProvider<AssetId> for () {
+	fn asset_prices(_asset_id: AssetId) -> Option<(Ratio, Ratio)> {
+		None
+	}
+}
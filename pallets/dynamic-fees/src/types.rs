@@ -3,7 +3,7 @@ use frame_support::pallet_prelude::*;
 use serde::{Deserialize, Serialize};
 use sp_runtime::FixedU128;
 
-use hydra_dx_math::dynamic_fees::types::FeeParams as MathFeeParams;
+use hydra_dx_math::dynamic_fees::types::{FeeParams as MathFeeParams, VolatilityFeeParams as MathVolatilityFeeParams};
 
 use scale_info::TypeInfo;
 
@@ -16,6 +16,13 @@ pub struct FeeParams<Fee> {
 	pub amplification: FixedU128,
 }
 
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VolatilityFeeParams<Fee> {
+	pub max_fee: Fee,
+	pub amplification: FixedU128,
+}
+
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct FeeEntry<Fee, Block> {
@@ -34,3 +41,12 @@ impl<Fee> From<FeeParams<Fee>> for MathFeeParams<Fee> {
 		}
 	}
 }
+
+impl<Fee> From<VolatilityFeeParams<Fee>> for MathVolatilityFeeParams<Fee> {
+	fn from(value: VolatilityFeeParams<Fee>) -> Self {
+		MathVolatilityFeeParams {
+			max_fee: value.max_fee,
+			amplification: value.amplification,
+		}
+	}
+}
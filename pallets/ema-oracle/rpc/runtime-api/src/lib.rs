@@ -0,0 +1,51 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the EMA oracle pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use hydradx_traits::{router::Trade, AggregatedEntry};
+use pallet_ema_oracle::{AssetId, Balance, OracleEntry, OraclePeriod, Price, Source};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query current and historical EMA oracle values.
+	pub trait EmaOracleApi<BlockNumber> where
+		BlockNumber: Codec,
+	{
+		/// Get the oracle entry of the given source, asset pair and period, updated to the parent block.
+		/// The entry is oriented in the order of the given assets.
+		fn get_entry(source: Source, asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Option<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+		/// Get the oracle entry of the given source, asset pair and period as it was at `block_number`.
+		/// Only blocks up to the parent block are supported. Returns `None` if there is not enough history.
+		fn get_entry_at(source: Source, asset_a: AssetId, asset_b: AssetId, period: OraclePeriod, block_number: BlockNumber) -> Option<AggregatedEntry<Balance, BlockNumber, Price>>;
+
+		/// Get the stored checkpoints of the given source, asset pair and period, oldest first.
+		/// Entries are oriented in the order of the given assets.
+		fn checkpoints(source: Source, asset_a: AssetId, asset_b: AssetId, period: OraclePeriod) -> Vec<OracleEntry<BlockNumber>>;
+
+		/// Get the source and ordered asset pair of all oracles in storage.
+		fn oracles() -> Vec<(Source, (AssetId, AssetId))>;
+
+		/// Get the periods tracked for every oracle, including registered custom periods.
+		fn periods() -> Vec<OraclePeriod>;
+
+		/// Get the oracle price of the route, as used by the `PriceOracle` implementation of the runtime.
+		fn route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<Price>;
+	}
+}
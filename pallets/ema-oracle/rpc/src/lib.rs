@@ -0,0 +1,234 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the EMA oracle pallet.
+//!
+//! Exposes oracle values updated to the parent block, so clients don't need to replicate the lazy
+//! fast-forwarding of the oracle entries in storage.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use hydradx_traits::{router::Trade, AggregatedEntry, Liquidity, Volume};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+use pallet_ema_oracle::{AssetId, Balance, OraclePeriod, Price, Source};
+pub use pallet_ema_oracle_rpc_runtime_api::EmaOracleApi as EmaOracleRuntimeApi;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+
+/// Price as a rational number of `n`umerator and `d`enominator.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+	pub n: NumberOrHex,
+	pub d: NumberOrHex,
+}
+
+/// Oracle values aggregated over the requested period.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OracleValue<BlockNumber> {
+	pub price: OraclePrice,
+	pub volume: Volume<NumberOrHex>,
+	pub liquidity: Liquidity<NumberOrHex>,
+	/// Number of blocks between the initialization of the oracle and the returned value.
+	pub oracle_age: BlockNumber,
+}
+
+#[rpc(client, server)]
+pub trait EmaOracleApi<BlockHash, BlockNumber> {
+	/// Get the oracle value of the asset pair updated to the parent block.
+	/// The value is oriented in the order of the given assets.
+	#[method(name = "emaOracle_getEntry")]
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OracleValue<BlockNumber>>>;
+
+	/// Get the oracle value of the asset pair as it was at `block_number`.
+	/// Returns `None` if the oracle history does not reach back far enough.
+	#[method(name = "emaOracle_getEntryAt")]
+	fn get_entry_at(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		block_number: BlockNumber,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OracleValue<BlockNumber>>>;
+
+	/// Get the source and ordered asset pair of all oracles.
+	#[method(name = "emaOracle_oracles")]
+	fn oracles(&self, at: Option<BlockHash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>>;
+
+	/// Get the tracked oracle periods.
+	#[method(name = "emaOracle_periods")]
+	fn periods(&self, at: Option<BlockHash>) -> RpcResult<Vec<OraclePeriod>>;
+
+	/// Get the oracle price of the route, as used by the runtime when pricing routes.
+	#[method(name = "emaOracle_routePrice")]
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<OraclePrice>>;
+}
+
+/// EMA oracle RPC methods.
+pub struct EmaOracle<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> EmaOracle<C, Block> {
+	/// Create new `EmaOracle` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+		}
+	}
+}
+
+impl<C, Block, BlockNumber> EmaOracleApiServer<<Block as BlockT>::Hash, BlockNumber> for EmaOracle<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: EmaOracleRuntimeApi<Block, BlockNumber>,
+	BlockNumber: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn get_entry(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OracleValue<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let entry = api
+			.get_entry(at, source, asset_a, asset_b, period)
+			.map_err(runtime_error)?;
+
+		Ok(entry.map(into_value))
+	}
+
+	fn get_entry_at(
+		&self,
+		source: Source,
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		block_number: BlockNumber,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OracleValue<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let entry = api
+			.get_entry_at(at, source, asset_a, asset_b, period, block_number)
+			.map_err(runtime_error)?;
+
+		Ok(entry.map(into_value))
+	}
+
+	fn oracles(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<(Source, (AssetId, AssetId))>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.oracles(at).map_err(runtime_error)
+	}
+
+	fn periods(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<OraclePeriod>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.periods(at).map_err(runtime_error)
+	}
+
+	fn route_price(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		period: OraclePeriod,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<OraclePrice>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		let price = api.route_price(at, route, period).map_err(runtime_error)?;
+
+		Ok(price.map(into_price))
+	}
+}
+
+fn into_price(price: Price) -> OraclePrice {
+	OraclePrice {
+		n: price.n.into(),
+		d: price.d.into(),
+	}
+}
+
+fn into_value<BlockNumber>(entry: AggregatedEntry<Balance, BlockNumber, Price>) -> OracleValue<BlockNumber> {
+	OracleValue {
+		price: into_price(entry.price),
+		volume: Volume {
+			a_in: entry.volume.a_in.into(),
+			b_out: entry.volume.b_out.into(),
+			a_out: entry.volume.a_out.into(),
+			b_in: entry.volume.b_in.into(),
+		},
+		liquidity: Liquidity {
+			a: entry.liquidity.a.into(),
+			b: entry.liquidity.b.into(),
+		},
+		oracle_age: entry.oracle_age,
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the oracle.",
+		Some(e.to_string()),
+	)
+}
@@ -63,6 +63,27 @@ benchmarks! {
 		assert!(!WhitelistedAssets::<T>::get().contains(&(SOURCE, (HDX, DOT))));
 	}
 
+	add_period {
+		for period in 2..MAX_CUSTOM_PERIODS + 1 {
+			assert_ok!(EmaOracle::<T>::add_period(RawOrigin::Root.into(), period));
+		}
+		let period = 432_000;
+	}: _(RawOrigin::Root, period)
+	verify {
+		assert!(CustomPeriods::<T>::get().contains(&period));
+	}
+
+	remove_period {
+		for period in 2..MAX_CUSTOM_PERIODS + 1 {
+			assert_ok!(EmaOracle::<T>::add_period(RawOrigin::Root.into(), period));
+		}
+		let period = 432_000;
+		assert_ok!(EmaOracle::<T>::add_period(RawOrigin::Root.into(), period));
+	}: _(RawOrigin::Root, period)
+	verify {
+		assert!(!CustomPeriods::<T>::get().contains(&period));
+	}
+
 	on_finalize_no_entry {
 		let block_num: u32 = 5;
 	}: { EmaOracle::<T>::on_finalize(block_num.into()); }
@@ -63,6 +63,23 @@
 //! and the price and liquidity to be constant) to the last block. Note: The most recent oracle
 //! values are always from the last block. This avoids e.g. sandwiching risks. If you want current
 //! prices you should use a spot price or similar.
+//!
+//! ### Checkpoints
+//!
+//! To answer queries about past oracle values, a checkpoint of all oracle entries of a
+//! `(source, asset_pair)` combination is recorded when the oracles are updated at least
+//! `CheckpointInterval` blocks after the previous checkpoint. Up to `MaxCheckpoints` checkpoints are
+//! kept in a ring buffer, the oldest one is overwritten when the buffer is full. The value at a past
+//! block is determined by fast-forwarding the most recent checkpoint before that block, the same way
+//! current values are determined from the last update. It is exposed via the
+//! `HistoricalAggregatedOracle` trait.
+//!
+//! ### Custom Periods
+//!
+//! Besides the `SupportedPeriods` configured in the runtime, `AuthorityOrigin` can register up to
+//! `MAX_CUSTOM_PERIODS` custom periods of a given number of blocks via `add_period`. Their
+//! smoothing factor is derived from the period length. Oracles of a removed custom period are not
+//! updated or returned anymore, their storage is cleaned up by `remove_oracle`.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -71,8 +88,8 @@ use frame_support::sp_runtime::traits::{BlockNumberProvider, One, Zero};
 use frame_support::traits::Contains;
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{
-	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, Liquidity, OnCreatePoolHandler,
-	OnLiquidityChangedHandler, OnTradeHandler, Volume,
+	AggregatedEntry, AggregatedOracle, AggregatedPriceOracle, HistoricalAggregatedOracle, Liquidity,
+	OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, Volume,
 };
 use sp_arithmetic::traits::Saturating;
 use sp_std::marker::PhantomData;
@@ -90,8 +107,11 @@ pub use weights::WeightInfo;
 
 mod benchmarking;
 
+/// The maximum number of custom periods that can be registered.
+pub const MAX_CUSTOM_PERIODS: u32 = 4;
+
 /// The maximum number of periods that could have corresponding oracles.
-pub const MAX_PERIODS: u32 = OraclePeriod::all_periods().len() as u32;
+pub const MAX_PERIODS: u32 = OraclePeriod::all_periods().len() as u32 + MAX_CUSTOM_PERIODS;
 
 const LOG_TARGET: &str = "runtime::ema-oracle";
 
@@ -126,7 +146,8 @@ pub mod pallet {
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
-		/// Origin that can enable oracle for assets that would be rejected by `OracleWhitelist` otherwise.
+		/// Origin that can enable oracle for assets that would be rejected by `OracleWhitelist` otherwise
+		/// and register custom periods.
 		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Provider for the current block number.
@@ -142,6 +163,16 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUniqueEntries: Get<u32>;
 
+		/// Minimum number of blocks between two checkpoints of an oracle.
+		/// Checkpoints are disabled if set to zero.
+		#[pallet::constant]
+		type CheckpointInterval: Get<BlockNumberFor<Self>>;
+
+		/// Number of checkpoints kept for each oracle.
+		/// Checkpoints are disabled if set to zero. Lowering the value requires a storage migration.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		type BenchmarkHelper: BenchmarkHelper<AssetId>;
 	}
@@ -151,6 +182,10 @@ pub mod pallet {
 		TooManyUniqueEntries,
 		OnTradeValueZero,
 		OracleNotFound,
+		InvalidPeriod,
+		PeriodAlreadyTracked,
+		TooManyPeriods,
+		PeriodNotFound,
 	}
 
 	#[pallet::event]
@@ -160,6 +195,10 @@ pub mod pallet {
 		AddedToWhitelist { source: Source, assets: (AssetId, AssetId) },
 		/// Oracle was removed from the whitelist.
 		RemovedFromWhitelist { source: Source, assets: (AssetId, AssetId) },
+		/// Custom period was registered.
+		PeriodAdded { period: OraclePeriod },
+		/// Custom period was removed.
+		PeriodRemoved { period: OraclePeriod },
 	}
 
 	/// Accumulator for oracle data in current block that will be recorded at the end of the block.
@@ -187,6 +226,37 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Ring buffer of historical oracle entries keyed by data source, involved asset ids, the period
+	/// length of the oracle and the slot in the buffer.
+	#[pallet::storage]
+	pub type Checkpoints<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, Source>,
+			NMapKey<Twox64Concat, (AssetId, AssetId)>,
+			NMapKey<Twox64Concat, OraclePeriod>,
+			NMapKey<Twox64Concat, u32>,
+		),
+		OracleEntry<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Position of the checkpoint ring buffer, shared by all periods of a data source and asset pair.
+	#[pallet::storage]
+	pub type CheckpointCursors<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		Source,
+		Twox64Concat,
+		(AssetId, AssetId),
+		CheckpointCursor<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// Custom periods tracked in addition to `SupportedPeriods`, as number of blocks.
+	#[pallet::storage]
+	pub type CustomPeriods<T: Config> = StorageValue<_, BoundedVec<u32, ConstU32<MAX_CUSTOM_PERIODS>>, ValueQuery>;
+
 	/// Assets that are whitelisted and tracked by the pallet.
 	#[pallet::storage]
 	pub type WhitelistedAssets<T: Config> =
@@ -264,7 +334,7 @@ pub mod pallet {
 		}
 
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_oracle())]
+		#[pallet::weight(Pallet::<T>::remove_oracle_entries_weight())]
 		pub fn remove_oracle(origin: OriginFor<T>, source: Source, assets: (AssetId, AssetId)) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
 
@@ -276,23 +346,93 @@ pub mod pallet {
 				Ok::<(), DispatchError>(())
 			})?;
 
-			// remove oracle from the storage
-			for period in T::SupportedPeriods::get().into_iter() {
-				let _ = Accumulator::<T>::mutate(|accumulator| {
-					accumulator.remove(&(source, assets));
-					Ok::<(), ()>(())
-				});
-				Oracles::<T>::remove((source, assets, period));
-			}
+			Self::remove_oracle_entries(source, assets);
 
 			Self::deposit_event(Event::RemovedFromWhitelist { source, assets });
 
 			Ok(())
 		}
+
+		/// Register a custom oracle period of `period` blocks.
+		///
+		/// Oracles for the period are tracked from the next update of each asset pair on.
+		/// The smoothing factor is derived from the length of the period.
+		///
+		/// Emits `PeriodAdded` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_period())]
+		pub fn add_period(origin: OriginFor<T>, period: u32) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(period > 1, Error::<T>::InvalidPeriod);
+			let oracle_period = OraclePeriod::Custom(period);
+			ensure!(
+				!T::SupportedPeriods::get().contains(&oracle_period),
+				Error::<T>::PeriodAlreadyTracked
+			);
+
+			CustomPeriods::<T>::try_mutate(|periods| -> DispatchResult {
+				ensure!(!periods.contains(&period), Error::<T>::PeriodAlreadyTracked);
+				periods.try_push(period).map_err(|_| Error::<T>::TooManyPeriods)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PeriodAdded { period: oracle_period });
+
+			Ok(())
+		}
+
+		/// Stop tracking a custom oracle period of `period` blocks.
+		///
+		/// Existing oracles of the period are not updated anymore and are no longer returned.
+		/// Their storage is cleaned up by `remove_oracle`.
+		///
+		/// Emits `PeriodRemoved` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_period())]
+		pub fn remove_period(origin: OriginFor<T>, period: u32) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			CustomPeriods::<T>::try_mutate(|periods| -> DispatchResult {
+				let index = periods
+					.iter()
+					.position(|p| *p == period)
+					.ok_or(Error::<T>::PeriodNotFound)?;
+				periods.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::PeriodRemoved {
+				period: OraclePeriod::Custom(period),
+			});
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Remove the oracles of an asset pair together with its accumulated entry and checkpoints.
+	/// Also removes the oracles of custom periods that are not tracked anymore.
+	pub fn remove_oracle_entries(source: Source, assets: (AssetId, AssetId)) {
+		let assets = ordered_pair(assets.0, assets.1);
+		let _ = Accumulator::<T>::mutate(|accumulator| {
+			accumulator.remove(&(source, assets));
+			Ok::<(), ()>(())
+		});
+		let _ = Oracles::<T>::clear_prefix((source, assets), u32::MAX, None);
+		let _ = Checkpoints::<T>::clear_prefix((source, assets), u32::MAX, None);
+		CheckpointCursors::<T>::remove(source, assets);
+	}
+
+	/// Weight of `remove_oracle_entries`. Checkpoints are bounded by `MAX_PERIODS * MaxCheckpoints`,
+	/// their removal together with the cursor is added on top of the benchmarked weight.
+	pub fn remove_oracle_entries_weight() -> Weight {
+		T::WeightInfo::remove_oracle().saturating_add(T::DbWeight::get().writes(u64::from(
+			MAX_PERIODS.saturating_mul(T::MaxCheckpoints::get()).saturating_add(1),
+		)))
+	}
+
 	/// Insert or update data in the accumulator from received entry. Aggregates volume and
 	/// takes the most recent data for the rest.
 	pub(crate) fn on_entry(
@@ -360,37 +500,91 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Return the periods tracked by the pallet: `SupportedPeriods` followed by the registered
+	/// custom periods.
+	pub fn tracked_periods() -> Vec<OraclePeriod> {
+		let mut periods = T::SupportedPeriods::get().into_inner();
+		periods.extend(
+			CustomPeriods::<T>::get()
+				.into_iter()
+				.map(OraclePeriod::Custom)
+				.filter(|p| !T::SupportedPeriods::get().contains(p)),
+		);
+		periods
+	}
+
 	/// Update oracles based on data accumulated during the block.
 	fn update_oracles_from_accumulator() {
+		let periods = Self::tracked_periods();
 		for ((src, assets), oracle_entry) in Accumulator::<T>::take().into_iter() {
+			let mut updated = Vec::with_capacity(MAX_PERIODS as usize);
 			// First we update the non-immediate oracles with the value of the `LastBlock` oracle.
-			for period in T::SupportedPeriods::get()
-				.into_iter()
-				.filter(|p| *p != OraclePeriod::LastBlock)
-			{
-				Self::update_oracle(src, assets, period, oracle_entry.clone());
+			for period in periods.iter().copied().filter(|p| *p != OraclePeriod::LastBlock) {
+				updated.push((period, Self::update_oracle(src, assets, period, oracle_entry.clone())));
 			}
 			// As we use (the old value of) the `LastBlock` entry to update the other oracles it
 			// gets updated last.
-			Self::update_oracle(src, assets, OraclePeriod::LastBlock, oracle_entry.clone());
+			updated.push((
+				OraclePeriod::LastBlock,
+				Self::update_oracle(src, assets, OraclePeriod::LastBlock, oracle_entry.clone()),
+			));
+
+			Self::record_checkpoint(src, assets, updated);
 		}
 	}
 
+	/// Store the updated oracle entries as a new checkpoint if at least `CheckpointInterval` blocks
+	/// passed since the last checkpoint.
+	fn record_checkpoint(
+		src: Source,
+		assets: (AssetId, AssetId),
+		entries: Vec<(OraclePeriod, OracleEntry<BlockNumberFor<T>>)>,
+	) {
+		let interval = T::CheckpointInterval::get();
+		let capacity = T::MaxCheckpoints::get();
+		if interval.is_zero() || capacity.is_zero() {
+			return;
+		}
+		let now = T::BlockNumberProvider::current_block_number();
+
+		CheckpointCursors::<T>::mutate(src, assets, |maybe_cursor| {
+			if let Some(cursor) = maybe_cursor.as_ref() {
+				if cursor.last_checkpoint.saturating_add(interval) > now {
+					return;
+				}
+			}
+			let cursor = maybe_cursor.get_or_insert_with(Default::default);
+			for (period, entry) in entries {
+				Checkpoints::<T>::insert((src, assets, period, cursor.next), entry);
+			}
+			cursor.advance(now, capacity);
+		});
+	}
+
 	/// Update the oracle of the given source, assets and period with `oracle_entry`.
+	/// Returns the updated entry.
 	fn update_oracle(
 		src: Source,
 		assets: (AssetId, AssetId),
 		period: OraclePeriod,
 		incoming_entry: OracleEntry<BlockNumberFor<T>>,
-	) {
+	) -> OracleEntry<BlockNumberFor<T>> {
 		Oracles::<T>::mutate((src, assets, period), |oracle| {
 			// initialize the oracle entry if it doesn't exist
 			if oracle.is_none() {
 				*oracle = Some((incoming_entry.clone(), T::BlockNumberProvider::current_block_number()));
-				return;
+				return incoming_entry;
 			}
 			if let Some((prev_entry, _)) = oracle.as_mut() {
 				let parent = T::BlockNumberProvider::current_block_number().saturating_sub(One::one());
+				// start over if the period was not tracked for a while
+				if parent > prev_entry.updated_at
+					&& period != OraclePeriod::LastBlock
+					&& Self::is_stale(src, assets, prev_entry)
+				{
+					*oracle = Some((incoming_entry.clone(), T::BlockNumberProvider::current_block_number()));
+					return incoming_entry;
+				}
 				// update the entry to the parent block if it hasn't been updated for a while
 				if parent > prev_entry.updated_at {
 					Self::last_block_oracle(src, assets, parent)
@@ -416,8 +610,19 @@ impl<T: Config> Pallet<T> {
 						);
 						debug_assert!(false, "Updating to new value should not fail.");
 					});
-			};
-		});
+				prev_entry.clone()
+			} else {
+				incoming_entry
+			}
+		})
+	}
+
+	/// Oracles of all tracked periods are updated together with the `LastBlock` oracle. An entry
+	/// older than the `LastBlock` oracle thus belongs to a period that was not tracked for some
+	/// time, e.g. a removed custom period.
+	fn is_stale(src: Source, assets: (AssetId, AssetId), entry: &OracleEntry<BlockNumberFor<T>>) -> bool {
+		Self::oracle((src, assets, OraclePeriod::LastBlock))
+			.is_some_and(|(last_block, _)| entry.updated_at < last_block.updated_at)
 	}
 
 	/// Return the updated oracle entry for the given source, assets and period.
@@ -438,6 +643,9 @@ impl<T: Config> Pallet<T> {
 		}
 
 		let (entry, init) = Self::oracle((src, assets, period))?;
+		if Self::is_stale(src, assets, &entry) {
+			return None;
+		}
 		if entry.updated_at < parent {
 			entry.calculate_current_from_outdated(period, &last_block)
 		} else {
@@ -445,6 +653,97 @@ impl<T: Config> Pallet<T> {
 		}
 		.map(|return_entry| (return_entry, init))
 	}
+
+	/// Return the oracle entry for the given source, assets and period as it was at `block`.
+	///
+	/// Uses the stored oracle if it was last updated at or before `block`, otherwise the most recent
+	/// checkpoint at or before `block`. The entry is then fast-forwarded to `block` the same way as
+	/// in `get_updated_entry`. Only blocks up to the parent block are supported.
+	fn get_entry_at_block(
+		src: Source,
+		assets: (AssetId, AssetId),
+		period: OraclePeriod,
+		block: BlockNumberFor<T>,
+	) -> Option<(OracleEntry<BlockNumberFor<T>>, BlockNumberFor<T>)> {
+		let parent = T::BlockNumberProvider::current_block_number().saturating_sub(One::one());
+		if block > parent {
+			return None;
+		}
+
+		let (current_entry, init) = Self::oracle((src, assets, period))?;
+		if block < init {
+			return None;
+		}
+		let (current_last_block, _) = Self::oracle((src, assets, OraclePeriod::LastBlock))?;
+
+		let (mut last_block, entry) = if current_last_block.updated_at <= block {
+			if current_entry.updated_at < current_last_block.updated_at {
+				return None;
+			}
+			(current_last_block, current_entry)
+		} else {
+			let slot = Self::find_checkpoint(src, assets, block)?;
+			(
+				Checkpoints::<T>::get((src, assets, OraclePeriod::LastBlock, slot))?,
+				Checkpoints::<T>::get((src, assets, period, slot))?,
+			)
+		};
+
+		if period == OraclePeriod::LastBlock || entry.updated_at == block {
+			let mut entry = entry;
+			if entry.updated_at != block {
+				entry.fast_forward_to(block);
+			}
+			return Some((entry, init));
+		}
+
+		last_block.fast_forward_to(block);
+		entry
+			.calculate_current_from_outdated(period, &last_block)
+			.map(|return_entry| (return_entry, init))
+	}
+
+	/// Return the slot of the most recent checkpoint recorded at or before `block`.
+	fn find_checkpoint(src: Source, assets: (AssetId, AssetId), block: BlockNumberFor<T>) -> Option<u32> {
+		let cursor = CheckpointCursors::<T>::get(src, assets)?;
+		let capacity = T::MaxCheckpoints::get();
+		if capacity.is_zero() {
+			return None;
+		}
+
+		// Checkpoints are ordered by block number, oldest first. Find the number of checkpoints
+		// recorded at or before `block`.
+		let (mut low, mut high) = (0u32, cursor.len);
+		while low < high {
+			let mid = low.saturating_add(high.saturating_sub(low) / 2);
+			let checkpoint = Checkpoints::<T>::get((src, assets, OraclePeriod::LastBlock, cursor.slot(mid, capacity)))?;
+			if checkpoint.updated_at <= block {
+				low = mid.saturating_add(1);
+			} else {
+				high = mid;
+			}
+		}
+
+		low.checked_sub(1).map(|index| cursor.slot(index, capacity))
+	}
+
+	/// Return the checkpoints of the given source, assets and period, oldest first.
+	pub fn checkpoints(
+		src: Source,
+		assets: (AssetId, AssetId),
+		period: OraclePeriod,
+	) -> Vec<OracleEntry<BlockNumberFor<T>>> {
+		let capacity = T::MaxCheckpoints::get();
+		let Some(cursor) = CheckpointCursors::<T>::get(src, assets) else {
+			return Vec::new();
+		};
+		if capacity.is_zero() {
+			return Vec::new();
+		}
+		(0..cursor.len)
+			.filter_map(|index| Checkpoints::<T>::get((src, assets, period, cursor.slot(index, capacity))))
+			.collect()
+	}
 }
 
 /// A callback handler for trading and liquidity activity that schedules oracle updates.
@@ -459,10 +758,28 @@ impl<T: Config> OnCreatePoolHandler<AssetId> for OnActivityHandler<T> {
 
 /// Calculate the weight contribution of one `on_trade`/`on_liquidity_changed` call towards
 /// `on_finalize`.
+///
+/// The benchmarks only cover the oracle updates of `SupportedPeriods`, so the storage access of
+/// the maximum number of custom periods (oracle update and checkpoint) and of the checkpoint of
+/// `SupportedPeriods` (cursor and entries) is added on top.
 pub(crate) fn fractional_on_finalize_weight<T: Config>(max_entries: u32) -> Weight {
+	let supported_periods = T::SupportedPeriods::get().len() as u64;
 	T::WeightInfo::on_finalize_multiple_tokens(max_entries)
 		.saturating_sub(T::WeightInfo::on_finalize_no_entry())
 		.saturating_div(max_entries.into())
+		.saturating_add(T::DbWeight::get().reads_writes(
+			u64::from(MAX_CUSTOM_PERIODS),
+			u64::from(MAX_CUSTOM_PERIODS.saturating_mul(2)),
+		))
+		.saturating_add(T::DbWeight::get().reads_writes(1, supported_periods.saturating_add(1)))
+}
+
+/// Calculate the weight of `get_entry_at`: the oracle reads of `get_entry`, the checkpoint cursor,
+/// the binary search over up to `MaxCheckpoints` checkpoints and the two checkpoints of the found
+/// slot.
+pub(crate) fn get_entry_at_weight<T: Config>() -> Weight {
+	let search_reads = u64::from(u32::BITS.saturating_sub(T::MaxCheckpoints::get().leading_zeros()));
+	T::WeightInfo::get_entry().saturating_add(T::DbWeight::get().reads(search_reads.saturating_add(3)))
 }
 
 impl<T: Config> OnTradeHandler<AssetId, Balance, Price> for OnActivityHandler<T> {
@@ -636,6 +953,39 @@ impl<T: Config> AggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price> for
 	}
 }
 
+impl<T: Config> HistoricalAggregatedOracle<AssetId, Balance, BlockNumberFor<T>, Price> for Pallet<T> {
+	type Error = OracleError;
+
+	/// Returns the entry corresponding to the given assets and period as it was at `block_number`.
+	/// The entry is determined from the most recent checkpoint at or before `block_number` and is
+	/// adjusted to the asset order given as parameters, same as in `get_entry`.
+	fn get_entry_at(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		block_number: BlockNumberFor<T>,
+	) -> Result<AggregatedEntry<Balance, BlockNumberFor<T>, Price>, OracleError> {
+		if asset_a == asset_b {
+			return Err(OracleError::SameAsset);
+		};
+		Self::get_entry_at_block(source, ordered_pair(asset_a, asset_b), period, block_number)
+			.ok_or(OracleError::NotPresent)
+			.map(|(entry, initialized)| {
+				let entry = if (asset_a, asset_b) != ordered_pair(asset_a, asset_b) {
+					entry.inverted()
+				} else {
+					entry
+				};
+				entry.into_aggregated(initialized)
+			})
+	}
+
+	fn get_entry_at_weight() -> Weight {
+		get_entry_at_weight::<T>()
+	}
+}
+
 impl<T: Config> AggregatedPriceOracle<AssetId, BlockNumberFor<T>, Price> for Pallet<T> {
 	type Error = OracleError;
 
@@ -0,0 +1,210 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use mock::{EmaOracle, RuntimeOrigin, Test, DOT, HDX};
+
+use frame_support::assert_ok;
+use pretty_assertions::assert_eq;
+
+fn checkpoint_blocks(period: OraclePeriod) -> Vec<BlockNumber> {
+	EmaOracle::checkpoints(SOURCE, ordered_pair(HDX, DOT), period)
+		.into_iter()
+		.map(|entry| entry.updated_at)
+		.collect()
+}
+
+#[test]
+fn first_update_should_record_checkpoint() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, (2_000, 1_000));
+
+		assert_eq!(
+			CheckpointCursors::<Test>::get(SOURCE, ordered_pair(HDX, DOT)),
+			Some(CheckpointCursor {
+				next: 1,
+				len: 1,
+				last_checkpoint: 5,
+			})
+		);
+		for period in supported_periods() {
+			assert_eq!(
+				Checkpoints::<Test>::get((SOURCE, ordered_pair(HDX, DOT), period, 0)),
+				get_oracle_entry(HDX, DOT, period),
+			);
+		}
+	});
+}
+
+#[test]
+fn checkpoint_should_not_be_recorded_before_interval_passed() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(5, (2_000, 1_000));
+		trade_and_finalize(14, (2_000, 1_000));
+		assert_eq!(checkpoint_blocks(LastBlock), vec![5]);
+
+		trade_and_finalize(15, (2_000, 1_000));
+		assert_eq!(checkpoint_blocks(LastBlock), vec![5, 15]);
+	});
+}
+
+#[test]
+fn oldest_checkpoint_should_be_overwritten_when_buffer_is_full() {
+	new_test_ext().execute_with(|| {
+		for block in [1, 11, 21, 31, 41, 51, 61] {
+			trade_and_finalize(block, (2_000, 1_000));
+		}
+
+		let max_checkpoints = <<Test as Config>::MaxCheckpoints as Get<u32>>::get();
+		assert_eq!(max_checkpoints, 5);
+		for period in supported_periods() {
+			assert_eq!(checkpoint_blocks(period), vec![21, 31, 41, 51, 61]);
+		}
+	});
+}
+
+#[test]
+fn get_entry_at_should_return_value_from_checkpoint() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(1, (2_000, 1_000));
+		let first_last_block = get_oracle_entry(HDX, DOT, LastBlock).unwrap();
+		let first_ten_minutes = get_oracle_entry(HDX, DOT, TenMinutes).unwrap();
+		trade_and_finalize(20, (3_000, 1_000));
+		System::set_block_number(30);
+
+		let last_block_at_10 = OracleEntry {
+			volume: Volume::default(),
+			updated_at: 10,
+			..first_last_block
+		};
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 10),
+			Ok(last_block_at_10.clone().into_aggregated(1))
+		);
+
+		let expected = first_ten_minutes
+			.calculate_current_from_outdated(TenMinutes, &last_block_at_10)
+			.unwrap();
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, TenMinutes, SOURCE, 10),
+			Ok(expected.into_aggregated(1))
+		);
+	});
+}
+
+#[test]
+fn get_entry_at_should_search_checkpoints_when_oracle_was_updated_after_block() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(1, (2_000, 1_000));
+		trade_and_finalize(11, (3_000, 1_000));
+		trade_and_finalize(21, (4_000, 1_000));
+		trade_and_finalize(31, (5_000, 1_000));
+		System::set_block_number(40);
+
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 15).map(|e| e.price),
+			Ok(Price::new(3_000, 1_000))
+		);
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 21).map(|e| e.price),
+			Ok(Price::new(4_000, 1_000))
+		);
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 35).map(|e| e.price),
+			Ok(Price::new(5_000, 1_000))
+		);
+	});
+}
+
+#[test]
+fn get_entry_at_parent_block_should_equal_get_entry() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(1, (2_000, 1_000));
+		trade_and_finalize(11, (3_000, 1_000));
+		System::set_block_number(30);
+
+		for period in supported_periods() {
+			assert_eq!(
+				EmaOracle::get_entry_at(HDX, DOT, period, SOURCE, 29),
+				EmaOracle::get_entry(HDX, DOT, period, SOURCE),
+			);
+		}
+	});
+}
+
+#[test]
+fn get_entry_at_should_invert_entry_for_reversed_assets() {
+	new_test_ext().execute_with(|| {
+		trade_and_finalize(1, (2_000, 1_000));
+		System::set_block_number(30);
+
+		assert_eq!(
+			EmaOracle::get_entry_at(DOT, HDX, LastBlock, SOURCE, 10).map(|e| e.price),
+			Ok(Price::new(1_000, 2_000))
+		);
+	});
+}
+
+#[test]
+fn get_entry_at_should_fail_when_history_is_not_available() {
+	new_test_ext().execute_with(|| {
+		for block in [5, 15, 25, 35, 45, 55, 65] {
+			trade_and_finalize(block, (2_000, 1_000));
+		}
+		System::set_block_number(70);
+
+		// checkpoint at block 5 and 15 were overwritten
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 20),
+			Err(OracleError::NotPresent)
+		);
+		// before the oracle was initialized
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 4),
+			Err(OracleError::NotPresent)
+		);
+		// current block is not finalized yet
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 70),
+			Err(OracleError::NotPresent)
+		);
+		assert_eq!(
+			EmaOracle::get_entry_at(HDX, HDX, LastBlock, SOURCE, 30),
+			Err(OracleError::SameAsset)
+		);
+		assert_ok!(EmaOracle::get_entry_at(HDX, DOT, LastBlock, SOURCE, 25));
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_checkpoints() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+		trade_and_finalize(1, (2_000, 1_000));
+		trade_and_finalize(11, (2_000, 1_000));
+
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		assert_eq!(CheckpointCursors::<Test>::get(SOURCE, ordered_pair(HDX, DOT)), None);
+		for period in supported_periods() {
+			assert!(checkpoint_blocks(period).is_empty());
+			assert_eq!(
+				Checkpoints::<Test>::iter_prefix_values((SOURCE, ordered_pair(HDX, DOT), period)).count(),
+				0
+			);
+		}
+	});
+}
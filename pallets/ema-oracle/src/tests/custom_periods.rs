@@ -0,0 +1,216 @@
+// This file is part of pallet-ema-oracle.
+
+// Copyright (C) 2022-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::*;
+pub use mock::{expect_events, EmaOracle, RuntimeOrigin, Test, DOT, HDX};
+
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::DispatchError::BadOrigin;
+
+const ALICE: u64 = 1;
+const THIRTY_MINUTES: u32 = 300;
+
+#[test]
+fn add_period_should_register_custom_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_eq!(CustomPeriods::<Test>::get().into_inner(), vec![THIRTY_MINUTES]);
+		assert!(EmaOracle::tracked_periods().contains(&Custom(THIRTY_MINUTES)));
+		expect_events(vec![Event::PeriodAdded {
+			period: Custom(THIRTY_MINUTES),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_origin_is_not_authority() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::signed(ALICE), THIRTY_MINUTES),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_period_is_too_short() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), 0),
+			Error::<Test>::InvalidPeriod
+		);
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), 1),
+			Error::<Test>::InvalidPeriod
+		);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_period_is_already_tracked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES),
+			Error::<Test>::PeriodAlreadyTracked
+		);
+	});
+}
+
+#[test]
+fn add_period_should_fail_when_storage_is_full() {
+	new_test_ext().execute_with(|| {
+		for period in 2..MAX_CUSTOM_PERIODS + 2 {
+			assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), period));
+		}
+
+		assert_noop!(
+			EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES),
+			Error::<Test>::TooManyPeriods
+		);
+	});
+}
+
+#[test]
+fn remove_period_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert!(CustomPeriods::<Test>::get().is_empty());
+		assert!(!EmaOracle::tracked_periods().contains(&Custom(THIRTY_MINUTES)));
+		expect_events(vec![Event::PeriodRemoved {
+			period: Custom(THIRTY_MINUTES),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_period_should_fail_when_period_is_not_registered() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES),
+			Error::<Test>::PeriodNotFound
+		);
+		assert_noop!(
+			EmaOracle::remove_period(RuntimeOrigin::signed(ALICE), THIRTY_MINUTES),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn custom_period_oracle_should_be_updated_with_derived_smoothing() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		trade_and_finalize(5, (2_000, 1_000));
+		let first_entry = get_oracle_entry(HDX, DOT, Custom(THIRTY_MINUTES)).expect("oracle should be initialized");
+		let last_block = get_oracle_entry(HDX, DOT, LastBlock).unwrap();
+		assert_eq!(first_entry, last_block);
+
+		trade_and_finalize(6, (3_000, 1_000));
+		let incoming = OracleEntry {
+			price: Price::new(3_000, 1_000),
+			volume: Volume::from_a_in_b_out(1_000, 500),
+			liquidity: Liquidity::new(3_000, 1_000),
+			updated_at: 6,
+		};
+		let expected = first_entry
+			.calculate_new_by_integrating_incoming(Custom(THIRTY_MINUTES), &incoming)
+			.unwrap();
+		assert_eq!(
+			get_oracle_entry(HDX, DOT, Custom(THIRTY_MINUTES)),
+			Some(expected.clone())
+		);
+
+		System::set_block_number(7);
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Custom(THIRTY_MINUTES), SOURCE),
+			Ok(expected.into_aggregated(5))
+		);
+	});
+}
+
+#[test]
+fn removed_period_should_not_be_updated_nor_returned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_and_finalize(5, (2_000, 1_000));
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		trade_and_finalize(6, (3_000, 1_000));
+
+		assert_eq!(
+			get_oracle_entry(HDX, DOT, Custom(THIRTY_MINUTES)).map(|e| e.updated_at),
+			Some(5)
+		);
+		System::set_block_number(7);
+		assert_eq!(
+			EmaOracle::get_entry(HDX, DOT, Custom(THIRTY_MINUTES), SOURCE),
+			Err(OracleError::NotPresent)
+		);
+	});
+}
+
+#[test]
+fn re_added_period_should_initialize_new_oracle() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_and_finalize(5, (2_000, 1_000));
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_and_finalize(6, (3_000, 1_000));
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		trade_and_finalize(10, (4_000, 1_000));
+
+		let (entry, initialized) =
+			Oracles::<Test>::get((SOURCE, ordered_pair(HDX, DOT), Custom(THIRTY_MINUTES))).unwrap();
+		assert_eq!(initialized, 10);
+		assert_eq!(
+			entry,
+			OracleEntry {
+				price: Price::new(4_000, 1_000),
+				volume: Volume::from_a_in_b_out(1_000, 500),
+				liquidity: Liquidity::new(4_000, 1_000),
+				updated_at: 10,
+			}
+		);
+	});
+}
+
+#[test]
+fn remove_oracle_should_remove_custom_period_oracles() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(EmaOracle::add_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+		assert_ok!(EmaOracle::add_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+		trade_and_finalize(5, (2_000, 1_000));
+		assert_ok!(EmaOracle::remove_period(RuntimeOrigin::root(), THIRTY_MINUTES));
+
+		assert_ok!(EmaOracle::remove_oracle(RuntimeOrigin::root(), SOURCE, (HDX, DOT)));
+
+		assert_eq!(get_oracle_entry(HDX, DOT, Custom(THIRTY_MINUTES)), None);
+		for period in supported_periods() {
+			assert_eq!(get_oracle_entry(HDX, DOT, period), None);
+		}
+	});
+}
@@ -18,7 +18,7 @@
 use crate as ema_oracle;
 use crate::Config;
 use ema_oracle::OracleEntry;
-use frame_support::pallet_prelude::ConstU32;
+use frame_support::pallet_prelude::{ConstU32, ConstU64};
 use frame_support::parameter_types;
 use frame_support::sp_runtime::{
 	bounded_vec,
@@ -146,6 +146,8 @@ impl Config for Test {
 	type SupportedPeriods = SupportedPeriods;
 	type OracleWhitelist = OracleWhitelist;
 	type MaxUniqueEntries = ConstU32<45>;
+	type CheckpointInterval = ConstU64<10>;
+	type MaxCheckpoints = ConstU32<5>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ();
 	type WeightInfo = ();
@@ -16,6 +16,8 @@
 // limitations under the License.
 
 mod add_and_remove_oracle;
+mod checkpoints;
+mod custom_periods;
 mod invariants;
 mod mock;
 
@@ -75,6 +77,23 @@ fn get_oracle_entry(a: AssetId, b: AssetId, period: OraclePeriod) -> Option<Orac
 	Oracles::<Test>::get((SOURCE, ordered_pair(a, b), period)).map(|(e, _)| e)
 }
 
+/// Trade HDX/DOT at `block` and finalize it.
+fn trade_and_finalize(block: BlockNumber, liquidity: (Balance, Balance)) {
+	System::set_block_number(block);
+	EmaOracle::on_initialize(block);
+	assert_ok!(OnActivityHandler::<Test>::on_trade(
+		SOURCE,
+		HDX,
+		DOT,
+		1_000,
+		500,
+		liquidity.0,
+		liquidity.1,
+		Price::new(liquidity.0, liquidity.1),
+	));
+	EmaOracle::on_finalize(block);
+}
+
 #[test]
 fn genesis_config_works() {
 	ExtBuilder::default()
@@ -897,4 +916,10 @@ fn check_period_smoothing_factors() {
 	let week = smoothing_from_period(7 * days);
 	println!("Week: {} (bits: {})", week, week.to_bits());
 	assert_eq!(into_smoothing(Week), week);
+
+	assert_eq!(into_smoothing(Custom(10 * minutes as u32)), ten_minutes);
+	assert_eq!(
+		into_smoothing(Custom(30 * minutes as u32)),
+		smoothing_from_period(30 * minutes)
+	);
 }
@@ -17,7 +17,9 @@
 
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::RuntimeDebug;
-use hydra_dx_math::ema::{calculate_new_by_integrating_incoming, update_outdated_to_current, EmaPrice};
+use hydra_dx_math::ema::{
+	calculate_new_by_integrating_incoming, smoothing_from_period, update_outdated_to_current, EmaPrice,
+};
 use hydra_dx_math::types::Fraction;
 use hydradx_traits::{AggregatedEntry, Liquidity, Volume};
 use scale_info::TypeInfo;
@@ -204,8 +206,38 @@ where
 	}
 }
 
+/// Position of the checkpoint ring buffer of an oracle.
+///
+/// Checkpoints are stored in slots `0..capacity`. Once the buffer is full, the oldest checkpoint
+/// gets overwritten.
+#[derive(RuntimeDebug, Encode, Decode, Clone, Copy, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
+pub struct CheckpointCursor<BlockNumber> {
+	/// Slot the next checkpoint is written to.
+	pub next: u32,
+	/// Number of checkpoints stored.
+	pub len: u32,
+	/// Block number of the most recent checkpoint.
+	pub last_checkpoint: BlockNumber,
+}
+
+impl<BlockNumber> CheckpointCursor<BlockNumber> {
+	/// Return the slot of the checkpoint at `index`, where index `0` is the oldest checkpoint.
+	pub fn slot(&self, index: u32, capacity: u32) -> u32 {
+		let oldest = self.next.saturating_add(capacity).saturating_sub(self.len) % capacity;
+		oldest.saturating_add(index) % capacity
+	}
+
+	/// Advance the cursor after a checkpoint has been written to `self.next`.
+	pub fn advance(&mut self, checkpoint: BlockNumber, capacity: u32) {
+		self.next = self.next.saturating_add(1) % capacity;
+		self.len = self.len.saturating_add(1).min(capacity);
+		self.last_checkpoint = checkpoint;
+	}
+}
+
 /// Convert a given `period` into the smoothing factor used in the weighted average.
 /// See [`check_period_smoothing_factors`] for how the values are generated.
+/// The smoothing factor of custom periods is derived from their length in blocks.
 pub fn into_smoothing(period: OraclePeriod) -> Fraction {
 	match period {
 		OraclePeriod::LastBlock => Fraction::from_bits(170141183460469231731687303715884105728),
@@ -214,6 +246,7 @@ pub fn into_smoothing(period: OraclePeriod) -> Fraction {
 		OraclePeriod::Hour => Fraction::from_bits(566193622164623067326746434994622648),
 		OraclePeriod::Day => Fraction::from_bits(23629079016800115510268356880200556),
 		OraclePeriod::Week => Fraction::from_bits(3375783642235081630771268215908257),
+		OraclePeriod::Custom(blocks) => smoothing_from_period(blocks.into()),
 	}
 }
 
@@ -57,6 +57,8 @@ pub trait WeightInfo {
 	fn on_trade_multiple_tokens(b: u32) -> Weight;
 	fn on_liquidity_changed_multiple_tokens(b: u32) -> Weight;
 	fn get_entry() -> Weight;
+	fn add_period() -> Weight;
+	fn remove_period() -> Weight;
 }
 
 /// Weights for `pallet_ema_oracle` using the HydraDX node and recommended hardware.
@@ -89,13 +91,15 @@ impl WeightInfo for () {
 	}
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:0)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:0)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
 	fn on_finalize_no_entry() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `208`
 		//  Estimated: `7406`
-		// Minimum execution time: 2_187_000 picoseconds.
-		Weight::from_parts(2_267_000, 7406)
-			.saturating_add(RocksDbWeight::get().reads(1_u64))
+		// Minimum execution time: 2_612_000 picoseconds.
+		Weight::from_parts(2_718_000, 7406)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
 	/// Storage: `EmaOracle::Accumulator` (r:1 w:1)
 	/// Proof: `EmaOracle::Accumulator` (`max_values`: Some(1), `max_size`: Some(5921), added: 6416, mode: `MaxEncodedLen`)
@@ -158,4 +162,26 @@ impl WeightInfo for () {
 		Weight::from_parts(17_680_000, 6294)
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	fn add_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `148`
+		//  Estimated: `1502`
+		// Minimum execution time: 11_023_000 picoseconds.
+		Weight::from_parts(11_315_000, 1502)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `EmaOracle::CustomPeriods` (r:1 w:1)
+	/// Proof: `EmaOracle::CustomPeriods` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	fn remove_period() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `164`
+		//  Estimated: `1502`
+		// Minimum execution time: 10_871_000 picoseconds.
+		Weight::from_parts(11_102_000, 1502)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -45,6 +45,10 @@
 //! This pallet is also used to control which contracts are allowed to manage balances and tokens.
 //! `ApprovedContract` storage is used by the currencies precompile to determine whenever contract is allowed to transfer or not.
 //!
+//! ### Allowances
+//! ERC20 allowances granted through the currencies precompile are stored per asset, owner and spender
+//! in `Allowances` storage. Approved contracts are allowed to transfer any amount regardless of the allowance.
+//!
 //! ### Dispatchable Functions
 //!
 //! * `bind_evm_address` - Binds a Substrate address to EVM address.
@@ -107,6 +111,9 @@ pub mod pallet {
 		/// Origin that can whitelist addresses for smart contract deployment.
 		type ControllerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Asset id type.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
 		/// Weight information for extrinsic in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -124,6 +131,19 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type ApprovedContract<T: Config> = StorageMap<_, Blake2_128Concat, EvmAddress, ()>;
 
+	/// Amount of asset the spender is allowed to transfer from the owner's balance.
+	#[pallet::storage]
+	pub(super) type Allowances<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::AssetId>,
+			NMapKey<Blake2_128Concat, EvmAddress>,
+			NMapKey<Blake2_128Concat, EvmAddress>,
+		),
+		Balance,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -150,6 +170,8 @@ pub mod pallet {
 		BoundAddressCannotBeUsed,
 		/// Address not whitelisted
 		AddressNotWhitelisted,
+		/// Spender's allowance is lower than the transferred amount
+		InsufficientAllowance,
 	}
 
 	#[pallet::hooks]
@@ -319,6 +341,51 @@ impl<T: Config> Pallet<T> {
 	fn _is_evm_account(account_id: &[u8; 32]) -> bool {
 		&account_id[0..4] == b"ETH\0" && account_id[24..32] == [0u8; 8]
 	}
+
+	/// Returns amount of `asset_id` the `spender` is allowed to transfer from `owner`'s balance.
+	/// Approved contracts are allowed to transfer any amount.
+	pub fn allowance(asset_id: T::AssetId, owner: EvmAddress, spender: EvmAddress) -> Balance {
+		if ApprovedContract::<T>::contains_key(spender) {
+			Balance::MAX
+		} else {
+			Allowances::<T>::get((asset_id, owner, spender))
+		}
+	}
+
+	/// Sets amount of `asset_id` the `spender` is allowed to transfer from `owner`'s balance.
+	/// `Balance::MAX` is treated as an unlimited allowance.
+	pub fn approve(asset_id: T::AssetId, owner: EvmAddress, spender: EvmAddress, amount: Balance) {
+		if amount == 0 {
+			Allowances::<T>::remove((asset_id, owner, spender));
+		} else {
+			Allowances::<T>::insert((asset_id, owner, spender), amount);
+		}
+	}
+
+	/// Decreases `spender`'s allowance by `amount`.
+	/// Unlimited allowances and allowances of approved contracts are not decreased.
+	pub fn spend_allowance(
+		asset_id: T::AssetId,
+		owner: EvmAddress,
+		spender: EvmAddress,
+		amount: Balance,
+	) -> DispatchResult {
+		if ApprovedContract::<T>::contains_key(spender) {
+			return Ok(());
+		}
+
+		Allowances::<T>::try_mutate_exists((asset_id, owner, spender), |maybe_allowance| -> DispatchResult {
+			let allowance = maybe_allowance.unwrap_or_default();
+			if allowance == Balance::MAX {
+				return Ok(());
+			}
+
+			let remaining = allowance.checked_sub(amount).ok_or(Error::<T>::InsufficientAllowance)?;
+			*maybe_allowance = if remaining == 0 { None } else { Some(remaining) };
+
+			Ok(())
+		})
+	}
 }
 
 impl<T: Config> InspectEvmAccounts<T::AccountId> for Pallet<T>
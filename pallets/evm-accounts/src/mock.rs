@@ -24,6 +24,7 @@ pub const ONE: Balance = 1_000_000_000_000;
 pub const INITIAL_BALANCE: Balance = 1_000_000_000_000 * ONE;
 
 pub const ALICE: AccountId = AccountId::new([1; 32]);
+pub const BOB: AccountId = AccountId::new([2; 32]);
 
 pub const HDX: AssetId = 0;
 
@@ -61,6 +62,7 @@ impl Config for Test {
 	type FeeMultiplier = sp_core::ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
 	type WeightInfo = ();
 }
 
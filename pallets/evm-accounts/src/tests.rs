@@ -199,3 +199,100 @@ fn renounce_contract_deployer_should_remove_address_from_the_storage() {
 		assert_ok!(EVMAccounts::renounce_contract_deployer(RuntimeOrigin::signed(ALICE)));
 	});
 }
+
+#[test]
+fn approve_should_store_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = EVMAccounts::evm_address(&BOB);
+
+		// Act
+		EVMAccounts::approve(HDX, owner, spender, 100);
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(HDX, owner, spender), 100);
+		assert_eq!(EVMAccounts::allowance(HDX, spender, owner), 0);
+		assert_eq!(EVMAccounts::allowance(1, owner, spender), 0);
+
+		// Act
+		EVMAccounts::approve(HDX, owner, spender, 0);
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(HDX, owner, spender), 0);
+		assert!(!Allowances::<Test>::contains_key((HDX, owner, spender)));
+	});
+}
+
+#[test]
+fn spend_allowance_should_decrease_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = EVMAccounts::evm_address(&BOB);
+		EVMAccounts::approve(HDX, owner, spender, 100);
+
+		// Act
+		assert_ok!(EVMAccounts::spend_allowance(HDX, owner, spender, 40));
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(HDX, owner, spender), 60);
+
+		// Act
+		assert_ok!(EVMAccounts::spend_allowance(HDX, owner, spender, 60));
+
+		// Assert
+		assert!(!Allowances::<Test>::contains_key((HDX, owner, spender)));
+	});
+}
+
+#[test]
+fn spend_allowance_should_fail_when_allowance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = EVMAccounts::evm_address(&BOB);
+		EVMAccounts::approve(HDX, owner, spender, 100);
+
+		// Act & Assert
+		assert_noop!(
+			EVMAccounts::spend_allowance(HDX, owner, spender, 101),
+			Error::<Test>::InsufficientAllowance
+		);
+		assert_noop!(
+			EVMAccounts::spend_allowance(HDX, spender, owner, 1),
+			Error::<Test>::InsufficientAllowance
+		);
+	});
+}
+
+#[test]
+fn spend_allowance_should_not_decrease_unlimited_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let spender = EVMAccounts::evm_address(&BOB);
+		EVMAccounts::approve(HDX, owner, spender, Balance::MAX);
+
+		// Act
+		assert_ok!(EVMAccounts::spend_allowance(HDX, owner, spender, 1_000));
+
+		// Assert
+		assert_eq!(EVMAccounts::allowance(HDX, owner, spender), Balance::MAX);
+	});
+}
+
+#[test]
+fn approved_contract_should_have_unlimited_allowance() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let owner = EVMAccounts::evm_address(&ALICE);
+		let contract = EVMAccounts::evm_address(&BOB);
+		assert_ok!(EVMAccounts::approve_contract(RuntimeOrigin::root(), contract));
+
+		// Act & Assert
+		assert_eq!(EVMAccounts::allowance(HDX, owner, contract), Balance::MAX);
+		assert_ok!(EVMAccounts::spend_allowance(HDX, owner, contract, 1_000));
+		assert!(!Allowances::<Test>::contains_key((HDX, owner, contract)));
+	});
+}
@@ -90,15 +90,23 @@ benchmarks! {
 	}
 
 	remove_liquidity {
+		let a in 0..T::MaxAllowlistSize::get();
+
 		let caller = funded_account::<T>("caller", 0);
 		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
 
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		for i in 0..a {
+			let participant: T::AccountId = account("participant", i, SEED);
+			LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), participant)?;
+		}
+
 	}: _(RawOrigin::Signed(caller.clone()), pool_id.clone())
 	verify {
 		assert!(!PoolData::<T>::contains_key(&pool_id));
+		assert!(Allowlist::<T>::iter_prefix(&pool_id).next().is_none());
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_A_ID, &caller), 1000000000000000);
 		assert_eq!(T::MultiCurrency::free_balance(ASSET_B_ID, &caller), 1000000000000000);
 	}
@@ -116,6 +124,14 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		// worst case - account limits and allowlist are checked and the trade is recorded
+		LBP::<T>::set_sale_limits(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleLimits {
+			max_bought_per_account: Some(ASSET_B_AMOUNT),
+			min_trade_interval: Some(BlockNumberFor::<T>::from(1u32)),
+			allowlist_only: true,
+		})?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), caller.clone())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
@@ -141,6 +157,14 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		// worst case - account limits and allowlist are checked and the trade is recorded
+		LBP::<T>::set_sale_limits(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleLimits {
+			max_bought_per_account: Some(ASSET_B_AMOUNT),
+			min_trade_interval: Some(BlockNumberFor::<T>::from(1u32)),
+			allowlist_only: true,
+		})?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), caller.clone())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
@@ -172,6 +196,14 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		// worst case - account limits and allowlist are checked and the trade is recorded
+		LBP::<T>::set_sale_limits(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleLimits {
+			max_bought_per_account: Some(ASSET_B_AMOUNT),
+			min_trade_interval: Some(BlockNumberFor::<T>::from(1u32)),
+			allowlist_only: true,
+		})?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), caller.clone())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
@@ -210,6 +242,14 @@ benchmarks! {
 		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, fee_collector, 0)?;
 		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
 
+		// worst case - account limits and allowlist are checked and the trade is recorded
+		LBP::<T>::set_sale_limits(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), SaleLimits {
+			max_bought_per_account: Some(ASSET_B_AMOUNT),
+			min_trade_interval: Some(BlockNumberFor::<T>::from(1u32)),
+			allowlist_only: true,
+		})?;
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), caller.clone())?;
+
 		let start = BlockNumberFor::<T>::from(1u32);
 		let end = BlockNumberFor::<T>::from(11u32);
 
@@ -281,6 +321,70 @@ benchmarks! {
 	}: {
 		assert!(<LBP::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>>::calculate_spot_price_with_fee(PoolType::LBP, asset_in, asset_out).is_ok());
 	}
+
+	set_sale_limits {
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+		let sale_limits = SaleLimits {
+			max_bought_per_account: Some(ASSET_B_AMOUNT / 10),
+			min_trade_interval: Some(BlockNumberFor::<T>::from(10_u32)),
+			allowlist_only: true,
+		};
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), sale_limits)
+	verify {
+		assert_eq!(LBP::<T>::pool_data(pool_id).unwrap().sale_limits, sale_limits);
+	}
+
+	add_to_allowlist {
+		let caller = funded_account::<T>("caller", 0);
+		let participant: T::AccountId = account("participant", 0, SEED);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), participant.clone())
+	verify {
+		assert!(Allowlist::<T>::contains_key(pool_id, participant));
+	}
+
+	remove_from_allowlist {
+		let caller = funded_account::<T>("caller", 0);
+		let participant: T::AccountId = account("participant", 0, SEED);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		LBP::<T>::create_pool(RawOrigin::Root.into(), caller.clone(), ASSET_A_ID, ASSET_A_AMOUNT, ASSET_B_ID, ASSET_B_AMOUNT, INITIAL_WEIGHT, FINAL_WEIGHT, WeightCurveType::Linear, DEFAULT_FEE, caller.clone(), 0)?;
+		ensure!(PoolData::<T>::contains_key(&pool_id), "Pool does not exist.");
+
+		LBP::<T>::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), participant.clone())?;
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone(), participant.clone())
+	verify {
+		assert!(!Allowlist::<T>::contains_key(pool_id, participant));
+	}
+
+	clear_participants {
+		let p in 1..T::MaxParticipantsRemoved::get();
+
+		let caller = funded_account::<T>("caller", 0);
+		let pool_id = LBP::<T>::pair_account_from_assets(ASSET_A_ID, ASSET_B_ID);
+
+		for i in 0..p {
+			let participant: T::AccountId = account("participant", i, SEED);
+			Participants::<T>::insert(&pool_id, participant, Participant {
+				bought: ASSET_B_AMOUNT / 10,
+				last_trade: BlockNumberFor::<T>::from(1u32),
+			});
+		}
+
+	}: _(RawOrigin::Signed(caller), pool_id.clone())
+	verify {
+		assert!(Participants::<T>::iter_prefix(&pool_id).next().is_none());
+	}
 }
 
 #[cfg(test)]
@@ -300,6 +404,10 @@ mod tests {
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_sale_limits());
+			assert_ok!(Pallet::<Test>::test_benchmark_add_to_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_remove_from_allowlist());
+			assert_ok!(Pallet::<Test>::test_benchmark_clear_participants());
 		});
 	}
 }
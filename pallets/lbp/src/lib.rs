@@ -25,7 +25,7 @@ pub use crate::types::{Amount, AssetId, AssetPair, Balance};
 use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::sp_runtime::{
 	traits::{AtLeast32BitUnsigned, BlockNumberProvider, Saturating, Zero},
-	DispatchError, RuntimeDebug,
+	DispatchError, PerThing, Permill, RuntimeDebug, SaturatedConversion,
 };
 use frame_support::{
 	dispatch,
@@ -61,6 +61,7 @@ pub mod weights;
 #[cfg(test)]
 mod invariants;
 
+pub mod migration;
 mod provider;
 mod trade_execution;
 pub mod types;
@@ -75,8 +76,20 @@ type PoolId<T> = <T as frame_system::Config>::AccountId;
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Default, RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum WeightCurveType {
+	/// Weight changes linearly from the initial weight to the final weight.
 	#[default]
 	Linear,
+	/// Weight follows exponential decay towards the final weight, most of the change happens early in the sale.
+	/// Higher `rate` makes the curve steeper.
+	Exponential { rate: u32 },
+	/// Weight changes in `steps` equal jumps and stays constant in between.
+	Step { steps: u32 },
+	/// Weight changes linearly to `breakpoint_weight` at `breakpoint` of the sale duration
+	/// and then linearly to the final weight.
+	PiecewiseLinear {
+		breakpoint: Permill,
+		breakpoint_weight: LBPWeight,
+	},
 }
 
 /// Max weight corresponds to 100%
@@ -85,6 +98,12 @@ pub const MAX_WEIGHT: LBPWeight = 100_000_000;
 /// Max sale duration is 14 days, assuming 6 sec blocks
 pub const MAX_SALE_DURATION: u32 = (60 * 60 * 24 / 6) * 14;
 
+/// Max rate of the exponential weight curve
+pub const MAX_EXPONENTIAL_RATE: u32 = 10;
+
+/// Max number of steps of the step weight curve
+pub const MAX_WEIGHT_CURVE_STEPS: u32 = 100;
+
 /// Lock Identifier for the collected fees
 pub const COLLECTOR_LOCK_ID: LockIdentifier = *b"lbpcllct";
 
@@ -120,6 +139,50 @@ pub struct Pool<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> {
 
 	/// repayment target of the accumulated asset in fee collectors account, when this target is reached fee drops from 20% to fee
 	pub repay_target: Balance,
+
+	/// per-account limits of the sale
+	pub sale_limits: SaleLimits<BlockNumber>,
+}
+
+/// Limits applied to every account trading in a pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct SaleLimits<BlockNumber> {
+	/// max amount of the sold asset a single account can buy from the pool, unlimited if `None`
+	pub max_bought_per_account: Option<Balance>,
+
+	/// min number of blocks between two trades of a single account, unlimited if `None`
+	pub min_trade_interval: Option<BlockNumber>,
+
+	/// only accounts added to the allowlist by the pool owner can trade if set
+	pub allowlist_only: bool,
+}
+
+impl<BlockNumber> SaleLimits<BlockNumber> {
+	/// returns true if the amount bought or the time of trades of an account is limited
+	pub fn is_account_limited(&self) -> bool {
+		self.max_bought_per_account.is_some() || self.min_trade_interval.is_some()
+	}
+}
+
+impl<BlockNumber> Default for SaleLimits<BlockNumber> {
+	fn default() -> Self {
+		SaleLimits {
+			max_bought_per_account: None,
+			min_trade_interval: None,
+			allowlist_only: false,
+		}
+	}
+}
+
+/// Trading history of an account in a pool with limits.
+#[derive(RuntimeDebug, Encode, Decode, Copy, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Participant<BlockNumber> {
+	/// total amount of the sold asset bought by the account
+	pub bought: Balance,
+
+	/// block of the last trade of the account
+	pub last_trade: BlockNumber,
 }
 
 impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockNumber> {
@@ -145,6 +208,7 @@ impl<AccountId, BlockNumber: AtLeast32BitUnsigned + Copy> Pool<AccountId, BlockN
 			fee,
 			fee_collector,
 			repay_target,
+			sale_limits: SaleLimits::default(),
 		}
 	}
 }
@@ -163,14 +227,37 @@ pub trait LBPWeightCalculation<BlockNumber: AtLeast32BitUnsigned> {
 pub struct LBPWeightFunction;
 impl<BlockNumber: AtLeast32BitUnsigned> LBPWeightCalculation<BlockNumber> for LBPWeightFunction {
 	fn calculate_weight(
-		_weight_curve: WeightCurveType,
+		weight_curve: WeightCurveType,
 		start: BlockNumber,
 		end: BlockNumber,
 		initial_weight: LBPWeight,
 		final_weight: LBPWeight,
 		at: BlockNumber,
 	) -> Option<LBPWeight> {
-		hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at).ok()
+		match weight_curve {
+			WeightCurveType::Linear => {
+				hydra_dx_math::lbp::calculate_linear_weights(start, end, initial_weight, final_weight, at)
+			}
+			WeightCurveType::Exponential { rate } => {
+				hydra_dx_math::lbp::calculate_exponential_weights(start, end, initial_weight, final_weight, rate, at)
+			}
+			WeightCurveType::Step { steps } => {
+				hydra_dx_math::lbp::calculate_step_weights(start, end, initial_weight, final_weight, steps, at)
+			}
+			WeightCurveType::PiecewiseLinear {
+				breakpoint,
+				breakpoint_weight,
+			} => hydra_dx_math::lbp::calculate_piecewise_linear_weights(
+				start,
+				end,
+				initial_weight,
+				final_weight,
+				breakpoint,
+				breakpoint_weight,
+				at,
+			),
+		}
+		.ok()
 	}
 }
 
@@ -180,7 +267,11 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::OriginFor;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -224,6 +315,14 @@ pub mod pallet {
 
 		/// The block number provider
 		type BlockNumberProvider: BlockNumberProvider<BlockNumber = BlockNumberFor<Self>>;
+
+		/// Max number of accounts in the allowlist of a pool
+		#[pallet::constant]
+		type MaxAllowlistSize: Get<u32>;
+
+		/// Max number of participant records of a removed pool cleared in single transaction
+		#[pallet::constant]
+		type MaxParticipantsRemoved: Get<u32>;
 	}
 
 	#[pallet::hooks]
@@ -306,6 +405,30 @@ pub mod pallet {
 
 		/// Not more than one fee collector per asset id
 		FeeCollectorWithAssetAlreadyUsed,
+
+		/// Weight curve parameters are out of range
+		InvalidWeightCurve,
+
+		/// Sale limits are set to invalid values
+		InvalidSaleLimits,
+
+		/// Account is not allowed to trade in the pool
+		NotAllowlisted,
+
+		/// Amount bought by the account exceeds the pool limit
+		MaxBoughtPerAccountExceeded,
+
+		/// Account has traded in the pool too recently
+		TradeIntervalNotElapsed,
+
+		/// Allowlist of the pool is full
+		MaxAllowlistSizeReached,
+
+		/// Participant records of a removed pool with the same assets have not been cleared yet
+		ParticipantsNotCleared,
+
+		/// There are no participant records to clear
+		NothingToClear,
 	}
 
 	#[pallet::event]
@@ -362,6 +485,16 @@ pub mod pallet {
 			fee_asset: AssetId,
 			fee_amount: BalanceOf<T>,
 		},
+
+		/// Account was added to or removed from the pool allowlist.
+		AllowlistUpdated {
+			pool: PoolId<T>,
+			who: T::AccountId,
+			allowed: bool,
+		},
+
+		/// Participant records of a removed pool were cleared.
+		ParticipantsCleared { pool: PoolId<T>, count: u32 },
 	}
 
 	/// Details of a pool.
@@ -376,6 +509,28 @@ pub mod pallet {
 	pub type FeeCollectorWithAsset<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, AssetId, bool, ValueQuery>;
 
+	/// Accounts allowed to trade in pools which are limited to allowlist only.
+	#[pallet::storage]
+	pub type Allowlist<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, PoolId<T>, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+	/// Number of accounts in the allowlist of a pool.
+	#[pallet::storage]
+	pub type AllowlistSize<T: Config> = StorageMap<_, Blake2_128Concat, PoolId<T>, u32, ValueQuery>;
+
+	/// Trading history of accounts in pools with per-account limits.
+	#[pallet::storage]
+	#[pallet::getter(fn participant)]
+	pub type Participants<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		PoolId<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		Participant<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::extra_constants]
 	impl<T: Config> Pallet<T> {
 		pub fn repay_fee() -> (u32, u32) {
@@ -405,8 +560,8 @@ pub mod pallet {
 		/// this should be higher than final weight
 		/// - `final_weight`: Final weight of the asset_a. 1_000_000 corresponding to 1% and 100_000_000 to 100%
 		/// this should be lower than initial weight
-		/// - `weight_curve`: The weight function used to update the LBP weights. Currently,
-		/// there is only one weight function implemented, the linear function.
+		/// - `weight_curve`: The weight function used to update the LBP weights. See `WeightCurveType`
+		/// for the available curves.
 		/// - `fee`: The trading fee charged on every trade distributed to `fee_collector`.
 		/// - `fee_collector`: The account to which trading fees will be transferred.
 		/// - `repay_target`: The amount of tokens to repay to separate fee_collector account. Until this amount is
@@ -481,6 +636,11 @@ pub mod pallet {
 
 			let pool_id = Self::get_pair_id(asset_pair);
 
+			ensure!(
+				<Participants<T>>::iter_key_prefix(&pool_id).next().is_none(),
+				Error::<T>::ParticipantsNotCleared
+			);
+
 			<PoolData<T>>::insert(&pool_id, &pool_data);
 			<FeeCollectorWithAsset<T>>::insert(fee_collector, asset_a, true);
 
@@ -669,8 +829,8 @@ pub mod pallet {
 		///
 		/// Emits 'LiquidityRemoved' when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity())]
-		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity(T::MaxAllowlistSize::get()))]
+		pub fn remove_liquidity(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
@@ -693,6 +853,9 @@ pub mod pallet {
 
 			<FeeCollectorWithAsset<T>>::remove(pool_data.fee_collector, pool_data.assets.0);
 			<PoolData<T>>::remove(&pool_id);
+			// allowlist is bounded by `MaxAllowlistSize`, participant records are removed by `clear_participants`
+			let allowlist_size = <AllowlistSize<T>>::take(&pool_id);
+			let _ = <Allowlist<T>>::clear_prefix(&pool_id, T::MaxAllowlistSize::get(), None);
 
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: pool_id,
@@ -702,7 +865,7 @@ pub mod pallet {
 				amount_b,
 			});
 
-			Ok(())
+			Ok(Some(<T as Config>::WeightInfo::remove_liquidity(allowlist_size)).into())
 		}
 
 		/// Trade `asset_in` for `asset_out`.
@@ -764,6 +927,107 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set per-account limits of a sale.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// The limits can be updated only if the sale has not already started.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool to be updated.
+		/// - `sale_limits`: Max amount of the sold asset an account can buy, min number of blocks between
+		/// trades of an account and whether only allowlisted accounts can trade.
+		///
+		/// Emits `PoolUpdated` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sale_limits())]
+		pub fn set_sale_limits(
+			origin: OriginFor<T>,
+			pool_id: PoolId<T>,
+			sale_limits: SaleLimits<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			<PoolData<T>>::try_mutate_exists(pool_id.clone(), |maybe_pool| -> DispatchResult {
+				let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(who == pool.owner, Error::<T>::NotOwner);
+
+				ensure!(!Self::has_pool_started(pool), Error::<T>::SaleStarted);
+
+				pool.sale_limits = sale_limits;
+
+				Self::validate_pool_data(pool)?;
+
+				Self::deposit_event(Event::PoolUpdated {
+					pool: pool_id,
+					data: (*pool).clone(),
+				});
+				Ok(())
+			})
+		}
+
+		/// Allow an account to trade in a pool limited to allowlisted accounts.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// At most `MaxAllowlistSize` accounts can be allowlisted in a pool.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `who`: The account to be added to the allowlist.
+		///
+		/// Emits `AllowlistUpdated` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_to_allowlist())]
+		pub fn add_to_allowlist(origin: OriginFor<T>, pool_id: PoolId<T>, who: T::AccountId) -> DispatchResult {
+			Self::update_allowlist(origin, pool_id, who, true)
+		}
+
+		/// Remove an account from the allowlist of a pool.
+		///
+		/// The dispatch origin for this call must be signed by the pool owner.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the pool.
+		/// - `who`: The account to be removed from the allowlist.
+		///
+		/// Emits `AllowlistUpdated` event when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_from_allowlist())]
+		pub fn remove_from_allowlist(origin: OriginFor<T>, pool_id: PoolId<T>, who: T::AccountId) -> DispatchResult {
+			Self::update_allowlist(origin, pool_id, who, false)
+		}
+
+		/// Clear trading history of accounts in a removed pool.
+		///
+		/// Can be called by any signed origin. At most `MaxParticipantsRemoved` records are removed
+		/// in single call, so it needs to be repeated until all records of the pool are cleared.
+		/// A new pool with the same assets can't be created until then.
+		/// Only the records actually removed are charged for.
+		///
+		/// Parameters:
+		/// - `pool_id`: The identifier of the removed pool.
+		///
+		/// Emits `ParticipantsCleared` event when successful.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_participants(T::MaxParticipantsRemoved::get()))]
+		pub fn clear_participants(origin: OriginFor<T>, pool_id: PoolId<T>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			ensure!(!<PoolData<T>>::contains_key(&pool_id), Error::<T>::PoolAlreadyExists);
+
+			let count = <Participants<T>>::drain_prefix(&pool_id)
+				.take(T::MaxParticipantsRemoved::get() as usize)
+				.count() as u32;
+
+			ensure!(count > 0, Error::<T>::NothingToClear);
+
+			Self::deposit_event(Event::ParticipantsCleared { pool: pool_id, count });
+
+			Ok(Some(<T as Config>::WeightInfo::clear_participants(count)).into())
+		}
 	}
 }
 
@@ -787,6 +1051,80 @@ impl<T: Config> Pallet<T> {
 		Ok((weight_a, weight_b))
 	}
 
+	fn update_allowlist(
+		origin: T::RuntimeOrigin,
+		pool_id: PoolId<T>,
+		who: T::AccountId,
+		allowed: bool,
+	) -> DispatchResult {
+		let owner = ensure_signed(origin)?;
+
+		let pool_data = <PoolData<T>>::try_get(&pool_id).map_err(|_| Error::<T>::PoolNotFound)?;
+
+		ensure!(owner == pool_data.owner, Error::<T>::NotOwner);
+
+		if allowed {
+			if !<Allowlist<T>>::contains_key(&pool_id, &who) {
+				<AllowlistSize<T>>::try_mutate(&pool_id, |size| -> DispatchResult {
+					ensure!(*size < T::MaxAllowlistSize::get(), Error::<T>::MaxAllowlistSizeReached);
+					*size = size.saturating_add(1);
+					Ok(())
+				})?;
+				<Allowlist<T>>::insert(&pool_id, &who, ());
+			}
+		} else if <Allowlist<T>>::take(&pool_id, &who).is_some() {
+			<AllowlistSize<T>>::mutate(&pool_id, |size| *size = size.saturating_sub(1));
+		}
+
+		Self::deposit_event(Event::AllowlistUpdated {
+			pool: pool_id,
+			who,
+			allowed,
+		});
+
+		Ok(())
+	}
+
+	/// Ensures that `who` can trade in the pool and that buying `bought` amount of the sold asset
+	/// doesn't exceed the per-account limits.
+	fn ensure_sale_limits(
+		pool_id: &PoolId<T>,
+		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
+		who: &T::AccountId,
+		bought: Balance,
+	) -> DispatchResult {
+		let limits = pool_data.sale_limits;
+
+		if limits.allowlist_only {
+			ensure!(<Allowlist<T>>::contains_key(pool_id, who), Error::<T>::NotAllowlisted);
+		}
+
+		if !limits.is_account_limited() {
+			return Ok(());
+		}
+
+		let participant = <Participants<T>>::get(pool_id, who);
+
+		if let Some(max_bought) = limits.max_bought_per_account {
+			let total_bought = participant
+				.map(|p| p.bought)
+				.unwrap_or_default()
+				.checked_add(bought)
+				.ok_or(Error::<T>::Overflow)?;
+			ensure!(total_bought <= max_bought, Error::<T>::MaxBoughtPerAccountExceeded);
+		}
+
+		if let (Some(interval), Some(participant)) = (limits.min_trade_interval, participant) {
+			let now = T::BlockNumberProvider::current_block_number();
+			ensure!(
+				now >= participant.last_trade.saturating_add(interval),
+				Error::<T>::TradeIntervalNotElapsed
+			);
+		}
+
+		Ok(())
+	}
+
 	fn validate_pool_data(pool_data: &Pool<T::AccountId, BlockNumberFor<T>>) -> DispatchResult {
 		let now = T::BlockNumberProvider::current_block_number();
 
@@ -824,6 +1162,38 @@ impl<T: Config> Pallet<T> {
 			Error::<T>::InvalidWeight
 		);
 
+		let duration = match (pool_data.start, pool_data.end) {
+			(Some(start), Some(end)) => Some(end.saturating_sub(start).saturated_into::<u32>()),
+			_ => None,
+		};
+
+		let is_weight_curve_valid = match pool_data.weight_curve {
+			WeightCurveType::Linear => true,
+			WeightCurveType::Exponential { rate } => (1..=MAX_EXPONENTIAL_RATE).contains(&rate),
+			// every step has to last at least one block
+			WeightCurveType::Step { steps } => {
+				(1..=MAX_WEIGHT_CURVE_STEPS).contains(&steps) && duration.map_or(true, |d| steps <= d)
+			}
+			// breakpoint weight has the same limits as initial and final weights
+			// and the breakpoint can't fall on the start or the end of the sale
+			WeightCurveType::PiecewiseLinear {
+				breakpoint,
+				breakpoint_weight,
+			} => {
+				(1..Permill::ACCURACY).contains(&breakpoint.deconstruct())
+					&& breakpoint_weight < MAX_WEIGHT
+					&& breakpoint_weight >= MAX_WEIGHT / 50
+					&& duration.map_or(true, |d| !breakpoint.mul_floor(d).is_zero())
+			}
+		};
+		ensure!(is_weight_curve_valid, Error::<T>::InvalidWeightCurve);
+
+		ensure!(
+			pool_data.sale_limits.max_bought_per_account != Some(0)
+				&& pool_data.sale_limits.min_trade_interval != Some(Zero::zero()),
+			Error::<T>::InvalidSaleLimits
+		);
+
 		ensure!(!pool_data.fee.1.is_zero(), Error::<T>::FeeAmountInvalid);
 
 		Ok(())
@@ -835,6 +1205,8 @@ impl<T: Config> Pallet<T> {
 		pool_data: &Pool<T::AccountId, BlockNumberFor<T>>,
 	) -> Result<(LBPWeight, LBPWeight), Error<T>> {
 		match Self::calculate_weights(pool_data, now) {
+			// zero weight of either asset breaks the price calculation
+			Ok(weights) if weights.0.is_zero() || weights.1.is_zero() => Err(Error::<T>::InvalidWeight),
 			Ok(weights) => {
 				if asset_in == pool_data.assets.0 {
 					Ok((weights.0, weights.1))
@@ -909,6 +1281,28 @@ impl<T: Config> Pallet<T> {
 		let collected_fee_total = Self::collected_fees(&pool) + fee_amount;
 		T::MultiCurrency::set_lock(COLLECTOR_LOCK_ID, fee_asset, &pool.fee_collector, collected_fee_total)?;
 
+		if pool.sale_limits.is_account_limited() {
+			let bought = if transfer.assets.asset_out == pool.assets.1 {
+				transfer.amount_b
+			} else {
+				0
+			};
+
+			<Participants<T>>::try_mutate(&pool_account, &transfer.origin, |maybe_participant| -> DispatchResult {
+				let total_bought = maybe_participant
+					.map(|p| p.bought)
+					.unwrap_or_default()
+					.checked_add(bought)
+					.ok_or(Error::<T>::Overflow)?;
+
+				*maybe_participant = Some(Participant {
+					bought: total_bought,
+					last_trade: T::BlockNumberProvider::current_block_number(),
+				});
+				Ok(())
+			})?;
+		}
+
 		Ok(())
 	}
 
@@ -1047,6 +1441,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(min_bought <= amount_out, Error::<T>::TradingLimitReached);
 
+			Self::ensure_sale_limits(&pool_id, &pool_data, who, amount_out)?;
+
 			let amount_without_fee = amount.checked_sub(fee).ok_or(Error::<T>::Overflow)?;
 
 			Ok(AMMTransfer {
@@ -1087,6 +1483,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(min_bought <= amount_out_without_fee, Error::<T>::TradingLimitReached);
 
+			Self::ensure_sale_limits(&pool_id, &pool_data, who, 0)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
@@ -1178,6 +1576,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
+			Self::ensure_sale_limits(&pool_id, &pool_data, who, 0)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
@@ -1221,6 +1621,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 
 			ensure!(max_sold >= calculated_in, Error::<T>::TradingLimitReached);
 
+			Self::ensure_sale_limits(&pool_id, &pool_data, who, amount)?;
+
 			Ok(AMMTransfer {
 				origin: who.clone(),
 				assets,
@@ -0,0 +1,165 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::marker::PhantomData;
+
+/// The log target.
+const TARGET: &str = "runtime::lbp::migration::v1";
+
+pub mod v0 {
+	use super::*;
+
+	#[derive(RuntimeDebug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+	pub struct Pool<AccountId, BlockNumber> {
+		pub owner: AccountId,
+		pub start: Option<BlockNumber>,
+		pub end: Option<BlockNumber>,
+		pub assets: (AssetId, AssetId),
+		pub initial_weight: LBPWeight,
+		pub final_weight: LBPWeight,
+		pub weight_curve: WeightCurveType,
+		pub fee: (u32, u32),
+		pub fee_collector: AccountId,
+		pub repay_target: Balance,
+	}
+}
+
+pub mod v1 {
+	use super::*;
+
+	/// Adds default (unlimited) sale limits to all existing pools.
+	pub struct Migration<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 0, "Storage version too high.");
+
+			let pools_count = PoolData::<T>::iter_keys().count() as u64;
+
+			log::info!(target: TARGET, "LBP migration: PRE checks successful!");
+
+			Ok(pools_count.encode())
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			if StorageVersion::get::<Pallet<T>>() != 0 {
+				log::info!(target: TARGET, "LBP migration to v1 already applied, skipping");
+				return T::DbWeight::get().reads(1);
+			}
+
+			log::info!(target: TARGET, "Running migration to v1 for LBP");
+
+			let mut translated: u64 = 0;
+
+			PoolData::<T>::translate::<v0::Pool<T::AccountId, BlockNumberFor<T>>, _>(|_, old| {
+				translated.saturating_inc();
+
+				Some(Pool {
+					owner: old.owner,
+					start: old.start,
+					end: old.end,
+					assets: old.assets,
+					initial_weight: old.initial_weight,
+					final_weight: old.final_weight,
+					weight_curve: old.weight_curve,
+					fee: old.fee,
+					fee_collector: old.fee_collector,
+					repay_target: old.repay_target,
+					sale_limits: SaleLimits::default(),
+				})
+			});
+
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: TARGET, "Migrated {:?} pools", translated);
+
+			T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+			assert_eq!(StorageVersion::get::<Pallet<T>>(), 1, "Unexpected storage version.");
+
+			let previous_count = <u64 as codec::Decode>::decode(&mut state.as_slice()).unwrap();
+
+			assert_eq!(
+				PoolData::<T>::iter_values().count() as u64,
+				previous_count,
+				"Number of pools doesn't match the number prior migration!"
+			);
+
+			log::info!(target: TARGET, "LBP migration: POST checks successful!");
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::mock::{set_block_number, ExtBuilder, Test, ALICE, BSX, CHARLIE, KUSD, KUSD_BSX_POOL_ID};
+	use frame_support::storage::unhashed;
+
+	#[test]
+	fn migration_should_add_default_sale_limits() {
+		ExtBuilder::default().build().execute_with(|| {
+			set_block_number(1);
+			StorageVersion::new(0).put::<Pallet<Test>>();
+
+			let old_pool = v0::Pool {
+				owner: ALICE,
+				start: Some(10),
+				end: Some(20),
+				assets: (KUSD, BSX),
+				initial_weight: 20_000_000,
+				final_weight: 90_000_000,
+				weight_curve: WeightCurveType::Linear,
+				fee: (2, 1_000),
+				fee_collector: CHARLIE,
+				repay_target: 0,
+			};
+			unhashed::put(&PoolData::<Test>::hashed_key_for(KUSD_BSX_POOL_ID), &old_pool);
+
+			v1::Migration::<Test>::on_runtime_upgrade();
+
+			assert_eq!(StorageVersion::get::<Pallet<Test>>(), 1);
+			assert_eq!(
+				PoolData::<Test>::get(KUSD_BSX_POOL_ID),
+				Some(Pool {
+					owner: ALICE,
+					start: Some(10),
+					end: Some(20),
+					assets: (KUSD, BSX),
+					initial_weight: 20_000_000,
+					final_weight: 90_000_000,
+					weight_curve: WeightCurveType::Linear,
+					fee: (2, 1_000),
+					fee_collector: CHARLIE,
+					repay_target: 0,
+					sale_limits: SaleLimits::default(),
+				})
+			);
+		});
+	}
+}
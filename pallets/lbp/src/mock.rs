@@ -54,6 +54,11 @@ pub const SAMPLE_POOL_DATA: Pool<AccountId, BlockNumber> = Pool {
 	fee: DEFAULT_FEE,
 	fee_collector: CHARLIE,
 	repay_target: 0,
+	sale_limits: SaleLimits {
+		max_bought_per_account: None,
+		min_trade_interval: None,
+		allowlist_only: false,
+	},
 };
 
 pub const SAMPLE_AMM_TRANSFER: AMMTransfer<AccountId, AssetId, AssetPair, Balance> = AMMTransfer {
@@ -159,6 +164,8 @@ parameter_types! {
 	pub const MinPoolLiquidity: Balance = 1_000;
 	pub const MaxInRatio: u128 = 3;
 	pub const MaxOutRatio: u128 = 3;
+	pub const MaxAllowlistSize: u32 = 3;
+	pub const MaxParticipantsRemoved: u32 = 2;
 }
 
 pub struct MultiLockedBalance();
@@ -188,6 +195,8 @@ impl Config for Test {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = System;
+	type MaxAllowlistSize = MaxAllowlistSize;
+	type MaxParticipantsRemoved = MaxParticipantsRemoved;
 }
 
 pub struct ExtBuilder {
@@ -81,6 +81,7 @@ pub fn predefined_test_ext() -> sp_io::TestExternalities {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 
 		assert_eq!(<PoolData<Test>>::get(KUSD_BSX_POOL_ID).unwrap(), pool_data2);
@@ -212,6 +213,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -227,6 +229,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 		assert_ok!(LBPPallet::validate_pool_data(&pool_data));
 
@@ -241,6 +244,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -258,6 +262,7 @@ fn validate_pool_data_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 		assert_noop!(
 			LBPPallet::validate_pool_data(&pool_data),
@@ -280,6 +285,7 @@ fn max_sale_duration_ckeck() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		}));
 		assert_noop!(
 			LBPPallet::validate_pool_data(&Pool {
@@ -293,6 +299,7 @@ fn max_sale_duration_ckeck() {
 				fee: DEFAULT_FEE,
 				fee_collector: CHARLIE,
 				repay_target: 0,
+				sale_limits: SaleLimits::default(),
 			}),
 			Error::<Test>::MaxSaleDurationExceeded
 		);
@@ -313,6 +320,7 @@ fn calculate_weights_should_work() {
 			fee: DEFAULT_FEE,
 			fee_collector: CHARLIE,
 			repay_target: 0,
+			sale_limits: SaleLimits::default(),
 		};
 		assert_eq!(LBPPallet::calculate_weights(&pool_data, 170), Ok((38333333, 61666667)));
 
@@ -358,6 +366,149 @@ fn calculate_weights_should_work() {
 	});
 }
 
+#[test]
+fn calculate_weights_should_work_with_non_linear_curves() {
+	new_test_ext().execute_with(|| {
+		let mut pool_data = Pool {
+			owner: ALICE,
+			start: Some(100),
+			end: Some(200),
+			assets: (KUSD, BSX),
+			initial_weight: 50_000_000,
+			final_weight: 30_000_000,
+			weight_curve: WeightCurveType::Exponential { rate: 1 },
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+			sale_limits: SaleLimits::default(),
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 100),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((37_550_813, 62_449_187))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((30_000_000, 70_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::Step { steps: 4 };
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 124),
+			Ok((50_000_000, 50_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 170),
+			Ok((40_000_000, 60_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 200),
+			Ok((30_000_000, 70_000_000))
+		);
+
+		pool_data.weight_curve = WeightCurveType::PiecewiseLinear {
+			breakpoint: Permill::from_percent(50),
+			breakpoint_weight: 34_000_000,
+		};
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 125),
+			Ok((42_000_000, 58_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 150),
+			Ok((34_000_000, 66_000_000))
+		);
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 175),
+			Ok((32_000_000, 68_000_000))
+		);
+
+		// out of bound
+		assert_eq!(
+			LBPPallet::calculate_weights(&pool_data, 210),
+			Err(Error::<Test>::WeightCalculationError.into())
+		);
+	});
+}
+
+#[test]
+fn validate_pool_data_should_validate_weight_curve() {
+	new_test_ext().execute_with(|| {
+		let pool_data = |weight_curve| Pool {
+			owner: ALICE,
+			start: SALE_START,
+			end: SALE_END,
+			assets: (KUSD, BSX),
+			initial_weight: 20_000_000,
+			final_weight: 90_000_000,
+			weight_curve,
+			fee: DEFAULT_FEE,
+			fee_collector: CHARLIE,
+			repay_target: 0,
+			sale_limits: SaleLimits::default(),
+		};
+
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data(
+			WeightCurveType::Exponential {
+				rate: MAX_EXPONENTIAL_RATE
+			}
+		)));
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data(WeightCurveType::Step {
+			steps: 30
+		})));
+		assert_ok!(LBPPallet::validate_pool_data(&pool_data(
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(80),
+				breakpoint_weight: 30_000_000,
+			}
+		)));
+
+		let invalid_curves = vec![
+			WeightCurveType::Exponential { rate: 0 },
+			WeightCurveType::Exponential {
+				rate: MAX_EXPONENTIAL_RATE + 1,
+			},
+			WeightCurveType::Step { steps: 0 },
+			WeightCurveType::Step {
+				steps: MAX_WEIGHT_CURVE_STEPS + 1,
+			},
+			// sale is only 30 blocks long
+			WeightCurveType::Step { steps: 31 },
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_parts(0),
+				breakpoint_weight: 30_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(100),
+				breakpoint_weight: 30_000_000,
+			},
+			// breakpoint rounds down to the sale start
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(1),
+				breakpoint_weight: 30_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(50),
+				breakpoint_weight: 1_000_000,
+			},
+			WeightCurveType::PiecewiseLinear {
+				breakpoint: Permill::from_percent(50),
+				breakpoint_weight: MAX_WEIGHT,
+			},
+		];
+
+		for weight_curve in invalid_curves {
+			assert_noop!(
+				LBPPallet::validate_pool_data(&pool_data(weight_curve)),
+				Error::<Test>::InvalidWeightCurve
+			);
+		}
+	});
+}
+
 #[test]
 fn create_pool_should_work() {
 	new_test_ext().execute_with(|| {
@@ -411,6 +562,29 @@ fn create_pool_should_work() {
 	});
 }
 
+#[test]
+fn create_pool_with_invalid_weight_curve_should_not_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000u32,
+				90_000_000u32,
+				WeightCurveType::Exponential { rate: 0 },
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			),
+			Error::<Test>::InvalidWeightCurve
+		);
+	});
+}
+
 #[test]
 fn create_pool_from_basic_origin_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -929,6 +1103,56 @@ fn update_pool_with_invalid_data_should_not_work() {
 	});
 }
 
+#[test]
+fn update_pool_data_should_validate_weight_curve() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::create_pool(
+			Origin::root(),
+			ALICE,
+			KUSD,
+			1_000_000_000,
+			BSX,
+			2_000_000_000,
+			20_000_000u32,
+			90_000_000u32,
+			WeightCurveType::Step { steps: 20 },
+			DEFAULT_FEE,
+			CHARLIE,
+			0,
+		));
+
+		// every step has to last at least one block
+		assert_noop!(
+			LBPPallet::update_pool_data(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				None,
+				Some(10),
+				Some(20),
+				None,
+				None,
+				None,
+				None,
+				None,
+			),
+			Error::<Test>::InvalidWeightCurve
+		);
+
+		assert_ok!(LBPPallet::update_pool_data(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			None,
+			Some(10),
+			Some(30),
+			None,
+			None,
+			None,
+			None,
+			None,
+		));
+	});
+}
+
 #[test]
 fn update_pool_data_without_changes_should_not_work() {
 	predefined_test_ext().execute_with(|| {
@@ -3863,6 +4087,467 @@ fn simulate_lbp_event_with_repayment() {
 		assert_eq!(Currency::free_balance(sold_asset, &fee_collector), 0);
 	});
 }
+
+#[test]
+fn set_sale_limits_should_work() {
+	predefined_test_ext().execute_with(|| {
+		let sale_limits = SaleLimits {
+			max_bought_per_account: Some(1_000_000),
+			min_trade_interval: Some(5),
+			allowlist_only: true,
+		};
+
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			sale_limits
+		));
+
+		let pool_data = LBPPallet::pool_data(KUSD_BSX_POOL_ID).unwrap();
+		assert_eq!(pool_data.sale_limits, sale_limits);
+
+		expect_events(vec![Event::PoolUpdated {
+			pool: KUSD_BSX_POOL_ID,
+			data: pool_data,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_sale_limits_should_not_work_when_not_owner() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_limits(
+				Origin::signed(BOB),
+				KUSD_BSX_POOL_ID,
+				SaleLimits {
+					max_bought_per_account: Some(1_000_000),
+					..Default::default()
+				}
+			),
+			Error::<Test>::NotOwner
+		);
+	});
+}
+
+#[test]
+fn set_sale_limits_should_not_work_when_sale_started() {
+	predefined_test_ext().execute_with(|| {
+		set_block_number(11);
+
+		assert_noop!(
+			LBPPallet::set_sale_limits(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleLimits {
+					max_bought_per_account: Some(1_000_000),
+					..Default::default()
+				}
+			),
+			Error::<Test>::SaleStarted
+		);
+	});
+}
+
+#[test]
+fn set_sale_limits_should_not_work_with_zero_limits() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::set_sale_limits(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleLimits {
+					max_bought_per_account: Some(0),
+					..Default::default()
+				}
+			),
+			Error::<Test>::InvalidSaleLimits
+		);
+
+		assert_noop!(
+			LBPPallet::set_sale_limits(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				SaleLimits {
+					min_trade_interval: Some(0),
+					..Default::default()
+				}
+			),
+			Error::<Test>::InvalidSaleLimits
+		);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_max_bought_per_account_exceeded() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				max_bought_per_account: Some(1_500_000),
+				..Default::default()
+			}
+		));
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 2_000_000_000));
+
+		//Assert
+		assert_eq!(
+			LBPPallet::participant(KUSD_BSX_POOL_ID, BOB),
+			Some(Participant {
+				bought: 1_000_000,
+				last_trade: 11,
+			})
+		);
+
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 500_001, 2_000_000_000),
+			Error::<Test>::MaxBoughtPerAccountExceeded
+		);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::MaxBoughtPerAccountExceeded
+		);
+
+		// the limit is tracked per account
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			1_000_000,
+			2_000_000_000
+		));
+
+		assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 500_000, 2_000_000_000));
+		assert_eq!(LBPPallet::participant(KUSD_BSX_POOL_ID, BOB).unwrap().bought, 1_500_000);
+	});
+}
+
+#[test]
+fn selling_distributed_asset_should_not_count_towards_max_bought_per_account() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				max_bought_per_account: Some(1_000_000),
+				..Default::default()
+			}
+		));
+
+		set_block_number(11);
+
+		//Act
+		assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 2_000_000_000));
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), BSX, KUSD, 500_000, 1));
+
+		//Assert
+		assert_eq!(LBPPallet::participant(KUSD_BSX_POOL_ID, BOB).unwrap().bought, 1_000_000);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_min_trade_interval_not_elapsed() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				min_trade_interval: Some(5),
+				..Default::default()
+			}
+		));
+
+		set_block_number(11);
+
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1));
+
+		//Act & Assert
+		set_block_number(15);
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::TradeIntervalNotElapsed
+		);
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), KUSD, BSX, 1_000_000, 2_000_000_000),
+			Error::<Test>::TradeIntervalNotElapsed
+		);
+
+		set_block_number(16);
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1));
+		assert_eq!(LBPPallet::participant(KUSD_BSX_POOL_ID, BOB).unwrap().last_trade, 16);
+	});
+}
+
+#[test]
+fn trade_should_not_work_when_account_not_allowlisted() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				allowlist_only: true,
+				..Default::default()
+			}
+		));
+
+		set_block_number(11);
+
+		//Act & Assert
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::NotAllowlisted
+		);
+		assert_noop!(
+			LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 2_000_000_000),
+			Error::<Test>::NotAllowlisted
+		);
+
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert_ok!(LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1));
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert_noop!(
+			LBPPallet::sell(Origin::signed(BOB), KUSD, BSX, 10_000_000, 1),
+			Error::<Test>::NotAllowlisted
+		);
+	});
+}
+
+#[test]
+fn update_allowlist_should_work() {
+	predefined_test_ext().execute_with(|| {
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert!(<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert!(!<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+
+		expect_events(vec![
+			Event::AllowlistUpdated {
+				pool: KUSD_BSX_POOL_ID,
+				who: BOB,
+				allowed: true,
+			}
+			.into(),
+			Event::AllowlistUpdated {
+				pool: KUSD_BSX_POOL_ID,
+				who: BOB,
+				allowed: false,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn update_allowlist_should_not_work_when_not_owner() {
+	predefined_test_ext().execute_with(|| {
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			LBPPallet::remove_from_allowlist(Origin::signed(BOB), KUSD_BSX_POOL_ID, BOB),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(ALICE), HDX_BSX_POOL_ID, BOB),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_to_allowlist_should_not_work_when_allowlist_is_full() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		for who in [ALICE, BOB, CHARLIE] {
+			assert_ok!(LBPPallet::add_to_allowlist(
+				Origin::signed(ALICE),
+				KUSD_BSX_POOL_ID,
+				who
+			));
+		}
+		// adding an allowlisted account again doesn't increase the allowlist size
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert_eq!(<AllowlistSize<Test>>::get(KUSD_BSX_POOL_ID), 3);
+
+		//Act & Assert
+		assert_noop!(
+			LBPPallet::add_to_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, 4),
+			Error::<Test>::MaxAllowlistSizeReached
+		);
+
+		assert_ok!(LBPPallet::remove_from_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+		assert_eq!(<AllowlistSize<Test>>::get(KUSD_BSX_POOL_ID), 2);
+		assert_ok!(LBPPallet::add_to_allowlist(Origin::signed(ALICE), KUSD_BSX_POOL_ID, 4));
+	});
+}
+
+#[test]
+fn remove_liquidity_should_clear_allowlist() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				max_bought_per_account: Some(10_000_000),
+				allowlist_only: true,
+				..Default::default()
+			}
+		));
+		assert_ok!(LBPPallet::add_to_allowlist(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			BOB
+		));
+
+		set_block_number(11);
+		assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 2_000_000_000));
+
+		set_block_number(41);
+
+		//Act
+		let result = LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID);
+
+		//Assert
+		assert_eq!(
+			result.unwrap().actual_weight,
+			Some(<() as crate::weights::WeightInfo>::remove_liquidity(1))
+		);
+		assert!(!<Allowlist<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+		assert_eq!(<AllowlistSize<Test>>::get(KUSD_BSX_POOL_ID), 0);
+		// participant records are cleared by `clear_participants`
+		assert!(<Participants<Test>>::contains_key(KUSD_BSX_POOL_ID, BOB));
+	});
+}
+
+#[test]
+fn clear_participants_should_work_in_batches() {
+	predefined_test_ext().execute_with(|| {
+		//Arrange
+		assert_ok!(LBPPallet::set_sale_limits(
+			Origin::signed(ALICE),
+			KUSD_BSX_POOL_ID,
+			SaleLimits {
+				max_bought_per_account: Some(10_000_000),
+				..Default::default()
+			}
+		));
+
+		set_block_number(11);
+		assert_ok!(LBPPallet::buy(Origin::signed(BOB), BSX, KUSD, 1_000_000, 2_000_000_000));
+		assert_ok!(LBPPallet::buy(
+			Origin::signed(ALICE),
+			BSX,
+			KUSD,
+			1_000_000,
+			2_000_000_000
+		));
+		<Participants<Test>>::insert(
+			KUSD_BSX_POOL_ID,
+			CHARLIE,
+			Participant {
+				bought: 1_000_000,
+				last_trade: 11,
+			},
+		);
+
+		assert_noop!(
+			LBPPallet::clear_participants(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID),
+			Error::<Test>::PoolAlreadyExists
+		);
+
+		set_block_number(41);
+		assert_ok!(LBPPallet::remove_liquidity(Origin::signed(ALICE), KUSD_BSX_POOL_ID));
+
+		let create_pool = || {
+			LBPPallet::create_pool(
+				Origin::root(),
+				ALICE,
+				KUSD,
+				1_000_000_000,
+				BSX,
+				2_000_000_000,
+				20_000_000u32,
+				90_000_000u32,
+				WeightCurveType::Linear,
+				DEFAULT_FEE,
+				CHARLIE,
+				0,
+			)
+		};
+		assert_noop!(create_pool(), Error::<Test>::ParticipantsNotCleared);
+
+		//Act
+		let first_batch = LBPPallet::clear_participants(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID);
+		let second_batch = LBPPallet::clear_participants(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID);
+
+		//Assert
+		assert_eq!(
+			first_batch.unwrap().actual_weight,
+			Some(<() as crate::weights::WeightInfo>::clear_participants(2))
+		);
+		assert_eq!(
+			second_batch.unwrap().actual_weight,
+			Some(<() as crate::weights::WeightInfo>::clear_participants(1))
+		);
+		expect_events(vec![
+			Event::ParticipantsCleared {
+				pool: KUSD_BSX_POOL_ID,
+				count: 2,
+			}
+			.into(),
+			Event::ParticipantsCleared {
+				pool: KUSD_BSX_POOL_ID,
+				count: 1,
+			}
+			.into(),
+		]);
+
+		assert_noop!(
+			LBPPallet::clear_participants(Origin::signed(CHARLIE), KUSD_BSX_POOL_ID),
+			Error::<Test>::NothingToClear
+		);
+		assert_ok!(create_pool());
+	});
+}
+
 mod spot_price_calculation {
 	use super::*;
 	use crate::mock::RuntimeOrigin;
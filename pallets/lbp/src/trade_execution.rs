@@ -1,5 +1,5 @@
 use crate::*;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{take_pools, ExecutorError, PoolType, PoolsProvider, TradeExecution};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
@@ -196,3 +196,12 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(spot_price_with_fee)
 	}
 }
+
+impl<T: Config> PoolsProvider<AssetId> for Pallet<T> {
+	fn pools(max_assets: u32) -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		take_pools(
+			<PoolData<T>>::iter_values().map(|pool| (PoolType::LBP, vec![pool.assets.0, pool.assets.1])),
+			max_assets,
+		)
+	}
+}
@@ -53,13 +53,17 @@ pub trait WeightInfo {
 	fn create_pool() -> Weight;
 	fn update_pool_data() -> Weight;
 	fn add_liquidity() -> Weight;
-	fn remove_liquidity() -> Weight;
+	fn remove_liquidity(a: u32, ) -> Weight;
 	fn sell() -> Weight;
 	fn buy() -> Weight;
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn calculate_buy() -> Weight;
 	fn calculate_spot_price_with_fee() -> Weight;
+	fn set_sale_limits() -> Weight;
+	fn add_to_allowlist() -> Weight;
+	fn remove_from_allowlist() -> Weight;
+	fn clear_participants(p: u32, ) -> Weight;
 }
 
 /// Weights for pallet_lbp using the hydraDX node and recommended hardware.
@@ -149,7 +153,11 @@ impl WeightInfo for () {
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
-	fn remove_liquidity() -> Weight {
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	/// Storage: `LBP::Allowlist` (r:0 w:1000)
+	/// The range of component `a` is `[0, 1000]`.
+	/// Removal of the pool as benchmarked before allowlists were introduced, plus removal of `a` allowlisted accounts.
+	fn remove_liquidity(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1895`
 		//  Estimated: `11322`
@@ -157,6 +165,8 @@ impl WeightInfo for () {
 		Weight::from_parts(136_175_000, 11322)
 			.saturating_add(RocksDbWeight::get().reads(14_u64))
 			.saturating_add(RocksDbWeight::get().writes(8_u64))
+			.saturating_add(RocksDbWeight::get().reads_writes(1_u64, 1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
 	}
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -300,4 +310,32 @@ impl WeightInfo for () {
 		Weight::from_parts(25_559_000, 6156)
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 	}
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Same storage access as `update_pool_data`, which is an upper bound of the call.
+	fn set_sale_limits() -> Weight {
+		Self::update_pool_data()
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Allowlist` (r:1 w:1)
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	fn add_to_allowlist() -> Weight {
+		Self::update_pool_data()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Allowlist` (r:1 w:1)
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	fn remove_from_allowlist() -> Weight {
+		Self::update_pool_data()
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Participants` (r:p w:p)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 500]`.
+	fn clear_participants(p: u32, ) -> Weight {
+		RocksDbWeight::get().reads(1_u64)
+			.saturating_add(RocksDbWeight::get().reads_writes((1_u64).saturating_mul(p.into()), (1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(p.into()))
+	}
 }
@@ -241,6 +241,9 @@ impl pallet_route_executor::Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = Omnipool;
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRoutePoolAssets = frame_support::traits::ConstU32<100>;
 	type WeightInfo = ();
 }
 
@@ -464,6 +467,7 @@ impl pallet_evm_accounts::Config for Test {
 	type FeeMultiplier = ConstU32<10>;
 	type EvmNonceProvider = EvmNonceProviderMock;
 	type ControllerOrigin = EnsureRoot<AccountId>;
+	type AssetId = AssetId;
 	type WeightInfo = ();
 }
 
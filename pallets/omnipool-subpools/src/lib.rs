@@ -0,0 +1,470 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Omnipool subpools pallet
+//!
+//! ## Overview
+//!
+//! A subpool is a Stableswap pool whose share asset is held by Omnipool as a single asset.
+//! It allows correlated assets (eg. stablecoins) to share their liquidity in Omnipool.
+//!
+//! ### Creating a subpool
+//!
+//! `create_subpool` migrates two existing Omnipool assets into a new Stableswap pool.
+//! Reserves of both assets are moved from Omnipool to the pool account, and share tokens matching
+//! the hub reserve of both assets are minted into Omnipool, which lists the share asset instead of the migrated assets.
+//!
+//! More Omnipool assets can be migrated into an existing subpool by `migrate_asset_to_subpool`.
+//!
+//! ### Positions
+//!
+//! Omnipool positions of a migrated asset are not converted during the migration.
+//! Owner of such position converts it into a position of the subpool share asset by calling `migrate_position`.
+//!
+//! ### Trading
+//!
+//! Trades are executed by the router, through the Stableswap pool of a migrated asset and Omnipool.
+//! `trade_route` returns the route between any two assets, regardless of whether they have been migrated to a subpool or not.
+//!
+//! ### Routes
+//!
+//! Omnipool does not trade a migrated asset anymore, so the routes trading it in Omnipool fail.
+//! Anyone can migrate such route with `migrate_route`, which replaces the Omnipool trades of migrated assets
+//! by the trades through their subpools and stores the new route in the router.
+//!
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments)]
+
+use frame_support::pallet_prelude::{DispatchResult, Weight};
+use frame_support::{ensure, require_transactional, transactional};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::OriginFor;
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, AssetPair, PoolType, RouteProvider, RouterT, Trade};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::traits::OmnipoolHooks;
+use pallet_omnipool::types::{Position, Tradability};
+use pallet_route_executor::MAX_NUMBER_OF_TRADES;
+use sp_runtime::{ArithmeticError, Permill};
+use sp_std::vec;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+pub mod types;
+pub mod weights;
+
+use crate::types::{AssetDetail, Balance};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+type CurrencyOf<T> = <T as pallet_omnipool::Config>::Currency;
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool::Config
+		+ pallet_stableswap::Config<AssetId = <Self as pallet_omnipool::Config>::AssetId>
+	{
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Router used to store the migrated routes.
+		type RouteExecutor: RouterT<
+			OriginFor<Self>,
+			AssetIdOf<Self>,
+			Balance,
+			Trade<AssetIdOf<Self>>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Provider of the current routes.
+		type RouteProvider: RouteProvider<AssetIdOf<Self>>;
+
+		/// Weight information of the router, used to weigh the migration of a route.
+		type RouterWeightInfo: AmmTradeWeights<Trade<AssetIdOf<Self>>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Existing subpools, identified by their share asset.
+	#[pallet::storage]
+	#[pallet::getter(fn subpools)]
+	pub type Subpools<T: Config> = StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (), OptionQuery>;
+
+	/// Omnipool assets migrated to a subpool - (subpool id, asset details at the moment of migration).
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_assets)]
+	pub type MigratedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (AssetIdOf<T>, AssetDetail), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A subpool was created from two Omnipool assets.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: (AssetIdOf<T>, AssetIdOf<T>),
+		},
+		/// An Omnipool asset was migrated to a subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+		},
+		/// A position of a migrated asset was converted into a position of the subpool share asset.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+		},
+		/// A route was migrated to the subpools of its assets.
+		RouteMigrated {
+			asset_pair: AssetPair<AssetIdOf<T>>,
+			route: Vec<Trade<AssetIdOf<T>>>,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Subpool does not exist.
+		SubpoolNotFound,
+		/// Assets of a subpool must be different.
+		SameAssets,
+		/// Position asset has not been migrated to a subpool.
+		AssetNotMigrated,
+		/// Route does not trade any asset migrated to a subpool in Omnipool.
+		RouteNotAffected,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a subpool from two existing Omnipool assets.
+		///
+		/// Stableswap pool is created with `share_asset` as pool id. Reserves of both assets are moved
+		/// from Omnipool to the pool account and `share_asset` is added to Omnipool instead of the migrated assets.
+		///
+		/// Positions of migrated assets can be converted by their owners with `migrate_position`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be Stableswap's `AuthorityOrigin`
+		/// - `share_asset`: Preregistered share asset identifier
+		/// - `asset_a`: Omnipool asset to migrate
+		/// - `asset_b`: Omnipool asset to migrate
+		/// - `share_asset_weight_cap`: weight cap of the share asset in Omnipool
+		/// - `amplification`: Stableswap pool amplification
+		/// - `fee`: Stableswap pool fee
+		///
+		/// Emits `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool()
+			.saturating_add(Pallet::<T>::remove_omnipool_asset_weight().saturating_mul(2)))]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			asset_a: AssetIdOf<T>,
+			asset_b: AssetIdOf<T>,
+			share_asset_weight_cap: Permill,
+			amplification: u16,
+			fee: Permill,
+		) -> DispatchResult {
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+
+			let asset_state_a = OmnipoolPallet::<T>::load_asset_state(asset_a)?;
+			let asset_state_b = OmnipoolPallet::<T>::load_asset_state(asset_b)?;
+
+			StableswapPallet::<T>::create_pool(origin, share_asset, vec![asset_a, asset_b], amplification, fee)?;
+
+			let subpool_state = hydra_dx_math::omnipool_subpools::create_subpool_initial_state(
+				&(&asset_state_a).into(),
+				&(&asset_state_b).into(),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			for (asset_id, asset_state) in [(asset_a, asset_state_a), (asset_b, asset_state_b)] {
+				let (details, _) = hydra_dx_math::omnipool_subpools::calculate_asset_migration_details(
+					&(&asset_state).into(),
+					None,
+					Balance::default(),
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+				Self::move_omnipool_reserve_to_subpool(share_asset, asset_id, asset_state.reserve)?;
+				MigratedAssets::<T>::insert(asset_id, (share_asset, AssetDetail::from(details)));
+			}
+
+			CurrencyOf::<T>::deposit(
+				share_asset,
+				&OmnipoolPallet::<T>::protocol_account(),
+				subpool_state.reserve,
+			)?;
+			OmnipoolPallet::<T>::add_asset(
+				share_asset,
+				(subpool_state, share_asset_weight_cap, Tradability::default()).into(),
+			)?;
+
+			Subpools::<T>::insert(share_asset, ());
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id: share_asset,
+				assets: (asset_a, asset_b),
+			});
+
+			Ok(())
+		}
+
+		/// Migrate an Omnipool asset to an existing subpool.
+		///
+		/// Reserve of the asset is moved from Omnipool to the pool account. Subpool shares are minted into Omnipool
+		/// and the state of the share asset in Omnipool is updated accordingly.
+		///
+		/// Parameters:
+		/// - `origin`: Must be Stableswap's `AuthorityOrigin`
+		/// - `pool_id`: subpool id
+		/// - `asset_id`: Omnipool asset to migrate
+		///
+		/// Emits `AssetMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_asset_to_subpool()
+			.saturating_add(Pallet::<T>::remove_omnipool_asset_weight()))]
+		#[transactional]
+		pub fn migrate_asset_to_subpool(
+			origin: OriginFor<T>,
+			pool_id: AssetIdOf<T>,
+			asset_id: AssetIdOf<T>,
+		) -> DispatchResult {
+			<T as pallet_stableswap::Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Subpools::<T>::contains_key(pool_id), Error::<T>::SubpoolNotFound);
+
+			let asset_state = OmnipoolPallet::<T>::load_asset_state(asset_id)?;
+			let subpool_state = OmnipoolPallet::<T>::load_asset_state(pool_id)?;
+			let share_issuance = CurrencyOf::<T>::total_issuance(pool_id);
+
+			let (details, delta) = hydra_dx_math::omnipool_subpools::calculate_asset_migration_details(
+				&(&asset_state).into(),
+				Some(&(&subpool_state).into()),
+				share_issuance,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			let delta = delta.ok_or(ArithmeticError::Overflow)?;
+
+			StableswapPallet::<T>::add_pool_asset(pool_id, asset_id)?;
+			Self::move_omnipool_reserve_to_subpool(pool_id, asset_id, asset_state.reserve)?;
+
+			CurrencyOf::<T>::deposit(pool_id, &OmnipoolPallet::<T>::protocol_account(), *delta.delta_reserve)?;
+			OmnipoolPallet::<T>::update_asset_state(pool_id, delta)?;
+
+			MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+
+			Self::deposit_event(Event::AssetMigrated { asset_id, pool_id });
+
+			Ok(())
+		}
+
+		/// Convert an Omnipool position of a migrated asset into a position of the subpool share asset.
+		///
+		/// Parameters:
+		/// - `origin`: position owner
+		/// - `position_id`: Omnipool position id
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		#[transactional]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let position = OmnipoolPallet::<T>::load_position(position_id, who.clone())?;
+			let (pool_id, details) = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted = hydra_dx_math::omnipool_subpools::convert_position(
+				(&position).into(),
+				MigrationDetails::from(&details),
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			let new_position = Position::<Balance, AssetIdOf<T>> {
+				asset_id: pool_id,
+				amount: converted.amount,
+				shares: converted.shares,
+				price: converted.price,
+			};
+			OmnipoolPallet::<T>::set_position(position_id, &new_position)?;
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				owner: who,
+				asset_id: position.asset_id,
+				pool_id,
+			});
+
+			Ok(())
+		}
+
+		/// Migrate the route of an asset pair to the subpools of its assets.
+		///
+		/// Every Omnipool trade of the current route (on-chain or default) which trades an asset migrated to a subpool
+		/// is replaced by the trades through the subpool of the asset. The new route is set by the router's `set_route`,
+		/// so it is validated and replaces the current route, which is no longer executable.
+		///
+		/// Can be called by anyone.
+		///
+		/// Parameters:
+		/// - `origin`: signed origin
+		/// - `asset_pair`: asset pair of the route
+		///
+		/// Emits `RouteMigrated` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_route()
+			.saturating_add(T::RouterWeightInfo::set_route_weight(&Pallet::<T>::worst_case_route(*asset_pair))))]
+		#[transactional]
+		pub fn migrate_route(origin: OriginFor<T>, asset_pair: AssetPair<AssetIdOf<T>>) -> DispatchResult {
+			ensure_signed(origin.clone())?;
+
+			let route = T::RouteProvider::get_route(asset_pair);
+			let new_route = Self::migrated_route(&route);
+			ensure!(new_route != route, Error::<T>::RouteNotAffected);
+
+			T::RouteExecutor::set_route(origin, asset_pair, new_route.clone()).map_err(|e| e.error)?;
+
+			Self::deposit_event(Event::RouteMigrated {
+				asset_pair,
+				route: new_route,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Move reserve of a migrated asset from Omnipool to the subpool account and remove the asset from Omnipool.
+	#[require_transactional]
+	fn move_omnipool_reserve_to_subpool(
+		pool_id: AssetIdOf<T>,
+		asset_id: AssetIdOf<T>,
+		reserve: Balance,
+	) -> DispatchResult {
+		CurrencyOf::<T>::transfer(
+			asset_id,
+			&OmnipoolPallet::<T>::protocol_account(),
+			&StableswapPallet::<T>::pool_account(pool_id),
+			reserve,
+		)?;
+		OmnipoolPallet::<T>::remove_asset(asset_id)
+	}
+
+	/// Weight of the Omnipool hooks notified about the removal of a migrated asset.
+	pub fn remove_omnipool_asset_weight() -> Weight {
+		<T as pallet_omnipool::Config>::OmnipoolHooks::on_asset_removed_weight()
+	}
+
+	/// Build the route of a trade between two assets.
+	///
+	/// An asset migrated to a subpool is traded in its subpool first, and the share asset continues in Omnipool.
+	pub fn trade_route(asset_in: AssetIdOf<T>, asset_out: AssetIdOf<T>) -> Vec<Trade<AssetIdOf<T>>> {
+		let subpool_in = Self::subpool_of(asset_in);
+		let subpool_out = Self::subpool_of(asset_out);
+
+		if let (Some(pool_in), Some(pool_out)) = (subpool_in, subpool_out) {
+			if pool_in == pool_out {
+				return vec![Trade {
+					pool: PoolType::Stableswap(pool_in),
+					asset_in,
+					asset_out,
+				}];
+			}
+		}
+
+		let omnipool_asset_in = subpool_in.unwrap_or(asset_in);
+		let omnipool_asset_out = subpool_out.unwrap_or(asset_out);
+
+		let mut route = Vec::new();
+		if let Some(pool_id) = subpool_in {
+			route.push(Trade {
+				pool: PoolType::Stableswap(pool_id),
+				asset_in,
+				asset_out: pool_id,
+			});
+		}
+		if omnipool_asset_in != omnipool_asset_out {
+			route.push(Trade {
+				pool: PoolType::Omnipool,
+				asset_in: omnipool_asset_in,
+				asset_out: omnipool_asset_out,
+			});
+		}
+		if let Some(pool_id) = subpool_out {
+			route.push(Trade {
+				pool: PoolType::Stableswap(pool_id),
+				asset_in: pool_id,
+				asset_out,
+			});
+		}
+		route
+	}
+
+	/// Returns subpool id of a migrated asset.
+	pub fn subpool_of(asset_id: AssetIdOf<T>) -> Option<AssetIdOf<T>> {
+		MigratedAssets::<T>::get(asset_id).map(|(pool_id, _)| pool_id)
+	}
+
+	/// Replace every Omnipool trade of a migrated asset in the route by the trades through its subpool.
+	pub fn migrated_route(route: &[Trade<AssetIdOf<T>>]) -> Vec<Trade<AssetIdOf<T>>> {
+		let mut new_route = Vec::with_capacity(route.len());
+		for trade in route {
+			let is_migrated = Self::subpool_of(trade.asset_in).is_some() || Self::subpool_of(trade.asset_out).is_some();
+			if trade.pool == PoolType::Omnipool && is_migrated {
+				new_route.extend(Self::trade_route(trade.asset_in, trade.asset_out));
+			} else {
+				new_route.push(*trade);
+			}
+		}
+		new_route
+	}
+
+	/// Route used to weigh the migration of a route.
+	///
+	/// The router does not store routes longer than `MAX_NUMBER_OF_TRADES`, and Stableswap is the most expensive pool.
+	pub fn worst_case_route(asset_pair: AssetPair<AssetIdOf<T>>) -> Vec<Trade<AssetIdOf<T>>> {
+		vec![
+			Trade {
+				pool: PoolType::Stableswap(asset_pair.asset_in),
+				asset_in: asset_pair.asset_in,
+				asset_out: asset_pair.asset_out,
+			};
+			MAX_NUMBER_OF_TRADES as usize
+		]
+	}
+}
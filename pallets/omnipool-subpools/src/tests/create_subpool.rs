@@ -0,0 +1,188 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128};
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.add_endowed_accounts((LP1, USDC, 5000 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+}
+
+#[test]
+fn create_subpool_should_work_when_assets_are_in_omnipool() {
+	ext().execute_with(|| {
+		let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+		let usdc_state = Omnipool::load_asset_state(USDC).unwrap();
+
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			USDT,
+			USDC,
+			Permill::from_percent(50),
+			100u16,
+			Permill::from_percent(0),
+		));
+
+		// Migrated assets are no longer listed in Omnipool
+		assert!(Omnipool::load_asset_state(USDT).is_err());
+		assert!(Omnipool::load_asset_state(USDC).is_err());
+
+		// Reserves are held by the subpool account
+		let pool_account = Stableswap::pool_account(SHARE_ASSET);
+		assert_eq!(Tokens::free_balance(USDT, &pool_account), usdt_state.reserve);
+		assert_eq!(Tokens::free_balance(USDC, &pool_account), usdc_state.reserve);
+		assert_eq!(Tokens::free_balance(USDT, &Omnipool::protocol_account()), 0);
+		assert_eq!(Tokens::free_balance(USDC, &Omnipool::protocol_account()), 0);
+
+		// Share asset is listed in Omnipool with the hub reserve of both assets
+		let share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert_eq!(share_state.hub_reserve, usdt_state.hub_reserve + usdc_state.hub_reserve);
+		assert_eq!(share_state.shares, share_state.reserve);
+		assert_eq!(share_state.cap, FixedU128::from(Permill::from_percent(50)).into_inner());
+		assert_eq!(
+			Tokens::free_balance(SHARE_ASSET, &Omnipool::protocol_account()),
+			share_state.reserve
+		);
+		assert_eq!(Tokens::total_issuance(SHARE_ASSET), share_state.reserve);
+
+		assert!(OmnipoolSubpools::subpools(SHARE_ASSET).is_some());
+		assert_eq!(OmnipoolSubpools::subpool_of(USDT), Some(SHARE_ASSET));
+		assert_eq!(OmnipoolSubpools::subpool_of(USDC), Some(SHARE_ASSET));
+
+		let (_, usdt_detail) = OmnipoolSubpools::migrated_assets(USDT).unwrap();
+		assert_eq!(usdt_detail.shares, usdt_state.shares);
+		assert_eq!(usdt_detail.hub_reserve, usdt_state.hub_reserve);
+
+		let pool = pallet_stableswap::Pools::<Test>::get(SHARE_ASSET).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![USDT, USDC]);
+
+		expect_events(vec![Event::SubpoolCreated {
+			pool_id: SHARE_ASSET,
+			assets: (USDT, USDC),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_subpool_should_notify_omnipool_hooks_when_assets_are_removed_from_omnipool() {
+	ext().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::create_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			USDT,
+			USDC,
+			Permill::from_percent(100),
+			100u16,
+			Permill::from_percent(0),
+		));
+
+		assert_eq!(removed_assets(), vec![USDT, USDC]);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_origin_is_not_authority() {
+	ext().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::signed(ALICE),
+				SHARE_ASSET,
+				USDT,
+				USDC,
+				Permill::from_percent(50),
+				100u16,
+				Permill::from_percent(0),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_assets_are_same() {
+	ext().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				USDT,
+				USDT,
+				Permill::from_percent(50),
+				100u16,
+				Permill::from_percent(0),
+			),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					USDT,
+					USDC,
+					Permill::from_percent(50),
+					100u16,
+					Permill::from_percent(0),
+				),
+				pallet_omnipool::Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_share_asset_is_not_registered() {
+	ext().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				OTHER_SHARE_ASSET,
+				USDT,
+				USDC,
+				Permill::from_percent(50),
+				100u16,
+				Permill::from_percent(0),
+			),
+			pallet_stableswap::Error::<Test>::ShareAssetNotRegistered
+		);
+	});
+}
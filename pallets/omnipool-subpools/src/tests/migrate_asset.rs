@@ -0,0 +1,125 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128};
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(USDX)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.add_endowed_accounts((LP1, USDC, 5000 * ONE))
+		.add_endowed_accounts((LP1, USDX, 5000 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDX, FixedU128::from_float(0.65), LP1, 1000 * ONE)
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_work_when_subpool_exists() {
+	ext().with_subpool().build().execute_with(|| {
+		let usdx_state = Omnipool::load_asset_state(USDX).unwrap();
+		let share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		let share_issuance = Tokens::total_issuance(SHARE_ASSET);
+
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			USDX
+		));
+
+		assert!(Omnipool::load_asset_state(USDX).is_err());
+		assert_eq!(
+			Tokens::free_balance(USDX, &Stableswap::pool_account(SHARE_ASSET)),
+			usdx_state.reserve
+		);
+
+		let minted_shares = usdx_state.hub_reserve * share_issuance / share_state.hub_reserve;
+		let updated_share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+		assert_eq!(
+			updated_share_state.hub_reserve,
+			share_state.hub_reserve + usdx_state.hub_reserve
+		);
+		assert_eq!(updated_share_state.reserve, share_state.reserve + minted_shares);
+		assert_eq!(Tokens::total_issuance(SHARE_ASSET), share_issuance + minted_shares);
+
+		let pool = pallet_stableswap::Pools::<Test>::get(SHARE_ASSET).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![USDT, USDC, USDX]);
+
+		let (pool_id, detail) = OmnipoolSubpools::migrated_assets(USDX).unwrap();
+		assert_eq!(pool_id, SHARE_ASSET);
+		assert_eq!(detail.shares, usdx_state.shares);
+		assert_eq!(detail.hub_reserve, usdx_state.hub_reserve);
+		assert_eq!(detail.share_tokens, minted_shares);
+
+		expect_events(vec![Event::AssetMigrated {
+			asset_id: USDX,
+			pool_id: SHARE_ASSET,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_notify_omnipool_hooks_when_asset_is_removed_from_omnipool() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_asset_to_subpool(
+			RuntimeOrigin::root(),
+			SHARE_ASSET,
+			USDX
+		));
+
+		assert_eq!(removed_assets(), vec![USDT, USDC, USDX]);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_origin_is_not_authority() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::signed(ALICE), SHARE_ASSET, USDX),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_subpool_does_not_exist() {
+	ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, USDX),
+			Error::<Test>::SubpoolNotFound
+		);
+	});
+}
+
+#[test]
+fn migrate_asset_to_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_asset_to_subpool(RuntimeOrigin::root(), SHARE_ASSET, USDT),
+			pallet_omnipool::Error::<Test>::AssetNotFound
+		);
+	});
+}
@@ -0,0 +1,106 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+const USDT_POSITION_ID: u32 = 2;
+const USDC_POSITION_ID: u32 = 3;
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.add_endowed_accounts((LP2, USDC, 5000 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+}
+
+#[test]
+fn migrate_position_should_convert_position_to_share_asset_when_asset_is_migrated() {
+	ext().build().execute_with(|| {
+		let position = Omnipool::load_position(USDT_POSITION_ID, LP1).unwrap();
+		let usdt_state = Omnipool::load_asset_state(USDT).unwrap();
+
+		create_subpool();
+
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION_ID
+		));
+
+		let migrated_position = Omnipool::load_position(USDT_POSITION_ID, LP1).unwrap();
+		assert_eq!(migrated_position.asset_id, SHARE_ASSET);
+		assert_eq!(
+			migrated_position.shares,
+			position.shares * usdt_state.hub_reserve / usdt_state.shares
+		);
+		assert_eq!(
+			migrated_position.amount,
+			position.amount * usdt_state.hub_reserve / usdt_state.shares
+		);
+		assert_eq!(position_owner(USDT_POSITION_ID), Some(LP1));
+
+		expect_events(vec![Event::PositionMigrated {
+			position_id: USDT_POSITION_ID,
+			owner: LP1,
+			asset_id: USDT,
+			pool_id: SHARE_ASSET,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_caller_is_not_position_owner() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), USDC_POSITION_ID),
+			pallet_omnipool::Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_is_not_migrated() {
+	ext().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), USDT_POSITION_ID),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
+
+#[test]
+fn migrate_position_should_fail_when_position_is_already_migrated() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_ok!(OmnipoolSubpools::migrate_position(
+			RuntimeOrigin::signed(LP1),
+			USDT_POSITION_ID
+		));
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP1), USDT_POSITION_ID),
+			Error::<Test>::AssetNotMigrated
+		);
+	});
+}
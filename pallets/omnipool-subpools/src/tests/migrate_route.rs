@@ -0,0 +1,151 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::*;
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.add_endowed_accounts((LP1, USDC, 5000 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+}
+
+#[test]
+fn migrate_route_should_replace_omnipool_trade_when_asset_is_migrated() {
+	ext().with_subpool().build().execute_with(|| {
+		let asset_pair = AssetPair::new(DAI, USDT);
+
+		assert_ok!(OmnipoolSubpools::migrate_route(
+			RuntimeOrigin::signed(ALICE),
+			asset_pair
+		));
+
+		let expected_route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: SHARE_ASSET,
+			},
+			Trade {
+				pool: PoolType::Stableswap(SHARE_ASSET),
+				asset_in: SHARE_ASSET,
+				asset_out: USDT,
+			},
+		];
+		assert_eq!(Router::get_route(asset_pair), expected_route);
+
+		expect_events(vec![Event::RouteMigrated {
+			asset_pair,
+			route: expected_route,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn migrate_route_should_replace_omnipool_trade_with_stableswap_trade_when_both_assets_are_in_same_subpool() {
+	ext().with_subpool().build().execute_with(|| {
+		let asset_pair = AssetPair::new(USDT, USDC);
+
+		assert_ok!(OmnipoolSubpools::migrate_route(
+			RuntimeOrigin::signed(ALICE),
+			asset_pair
+		));
+
+		assert_eq!(
+			Router::get_route(asset_pair),
+			vec![Trade {
+				pool: PoolType::Stableswap(SHARE_ASSET),
+				asset_in: USDT,
+				asset_out: USDC,
+			}]
+		);
+	});
+}
+
+#[test]
+fn migrate_route_should_fail_when_route_does_not_contain_migrated_asset() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_noop!(
+			OmnipoolSubpools::migrate_route(RuntimeOrigin::signed(ALICE), AssetPair::new(DAI, HDX)),
+			Error::<Test>::RouteNotAffected
+		);
+	});
+}
+
+#[test]
+fn migrate_route_should_fail_when_route_is_already_migrated() {
+	ext().with_subpool().build().execute_with(|| {
+		let asset_pair = AssetPair::new(DAI, USDT);
+		assert_ok!(OmnipoolSubpools::migrate_route(
+			RuntimeOrigin::signed(ALICE),
+			asset_pair
+		));
+
+		assert_noop!(
+			OmnipoolSubpools::migrate_route(RuntimeOrigin::signed(ALICE), asset_pair),
+			Error::<Test>::RouteNotAffected
+		);
+	});
+}
+
+#[test]
+fn migrated_route_should_keep_trades_not_containing_migrated_assets() {
+	ext().with_subpool().build().execute_with(|| {
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: DAI,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: USDC,
+			},
+		];
+
+		assert_eq!(
+			OmnipoolSubpools::migrated_route(&route),
+			vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: HDX,
+					asset_out: DAI,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: SHARE_ASSET,
+				},
+				Trade {
+					pool: PoolType::Stableswap(SHARE_ASSET),
+					asset_in: SHARE_ASSET,
+					asset_out: USDC,
+				},
+			]
+		);
+	});
+}
@@ -0,0 +1,607 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Omnipool subpools pallet.
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_omnipool_subpools;
+
+use core::ops::RangeInclusive;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::traits::{ConstU128, Everything};
+use frame_support::weights::Weight;
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::router::RefundEdCalculator;
+use hydradx_traits::{registry::Inspect as InspectRegistry, AccountIdFor, AssetKind, OraclePeriod, PriceOracle};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_omnipool::traits::{AssetInfo, ExternalPriceProvider, OmnipoolHooks};
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, FixedU128,
+};
+use std::num::NonZeroU16;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 3;
+pub const USDC: AssetId = 4;
+pub const USDX: AssetId = 5;
+
+pub const SHARE_ASSET: AssetId = 100;
+pub const OTHER_SHARE_ASSET: AssetId = 101;
+
+pub const LP1: AccountId = 1;
+pub const LP2: AccountId = 2;
+pub const ALICE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
+
+thread_local! {
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static REMOVED_ASSETS: RefCell<Vec<AssetId>> = RefCell::new(Vec::default());
+}
+
+construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Omnipool: pallet_omnipool,
+		Stableswap: pallet_stableswap,
+		Router: pallet_route_executor,
+		OmnipoolSubpools: pallet_omnipool_subpools,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeTask = RuntimeTask;
+	type Nonce = u64;
+	type Block = Block;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+	type SingleBlockMigrations = ();
+	type MultiBlockMigrator = ();
+	type PreInherents = ();
+	type PostInherents = ();
+	type PostTransactions = ();
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |currency_id: AssetId| -> Balance {
+		if *currency_id == LRNA{
+			400_000_000
+		}else{
+			1
+		}
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Everything;
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const PositionCollectionId: u32 = 1000;
+	pub const MinAddedLiquidity: Balance = 1000;
+	pub const MinTradeAmount: Balance = 1000;
+	pub const MaxInRatio: Balance = 1;
+	pub const MaxOutRatio: Balance = 1;
+	pub const MinWithdrawFee: Permill = Permill::from_percent(0);
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u32;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = MinTradeAmount;
+	type MinimumPoolLiquidity = MinAddedLiquidity;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CollectionId = u32;
+	type OmnipoolHooks = RemovedAssetsHooks;
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type Fee = FeeProvider;
+}
+
+parameter_types! {
+	pub const MinimumLiquidity: Balance = 1000;
+	pub const MinimumTradingLimit: Balance = 1000;
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type MinPoolLiquidity = MinimumLiquidity;
+	type AmplificationRange = AmplificationRange;
+	type MinTradingLimit = MinimumTradingLimit;
+	type WeightInfo = ();
+	type BlockNumberProvider = System;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type VolumeProvider = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+parameter_types! {
+	pub DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
+	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+}
+
+pub struct MockedEdCalculator;
+
+impl RefundEdCalculator<Balance> for MockedEdCalculator {
+	fn calculate() -> Balance {
+		ONE
+	}
+}
+
+pub struct PriceProviderMock;
+
+impl PriceOracle<AssetId> for PriceProviderMock {
+	type Price = EmaPrice;
+
+	fn price(_route: &[Trade<AssetId>], _period: OraclePeriod) -> Option<EmaPrice> {
+		Some(EmaPrice::one())
+	}
+}
+
+impl pallet_route_executor::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Balance = Balance;
+	type NativeAssetId = HDXAssetId;
+	type Currency = Tokens;
+	type InspectRegistry = DummyRegistry;
+	type AMM = (Omnipool, Stableswap);
+	type EdToRefundCalculator = MockedEdCalculator;
+	type OraclePriceProvider = PriceProviderMock;
+	type OraclePeriod = RouteValidationOraclePeriod;
+	type DefaultRoutePoolType = DefaultRoutePoolType;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = (Omnipool, Stableswap);
+	type MaxRouteCandidates = ConstU32<10>;
+	type MaxRoutePoolAssets = ConstU32<100>;
+	type WeightInfo = ();
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteExecutor = Router;
+	type RouteProvider = Router;
+	type RouterWeightInfo = ();
+	type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+	with_subpool: bool,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		REMOVED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			],
+			registered_assets: vec![],
+			pool_tokens: vec![],
+			with_subpool: false,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn add_endowed_accounts(mut self, account: (AccountId, AssetId, Balance)) -> Self {
+		self.endowed_accounts.push(account);
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset: AssetId) -> Self {
+		self.registered_assets.push(asset);
+		self
+	}
+
+	pub fn with_token(
+		mut self,
+		asset_id: AssetId,
+		price: FixedU128,
+		position_owner: AccountId,
+		amount: Balance,
+	) -> Self {
+		self.pool_tokens.push((asset_id, price, position_owner, amount));
+		self
+	}
+
+	/// Create the `SHARE_ASSET` subpool from `USDT` and `USDC`, which must be added as tokens.
+	pub fn with_subpool(mut self) -> Self {
+		self.with_subpool = true;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(HDX, 12);
+			v.borrow_mut().insert(LRNA, 12);
+			v.borrow_mut().insert(DAI, 12);
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, 12);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts.clone(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				HDX,
+				FixedU128::from_float(0.5),
+				Permill::from_percent(100),
+				Omnipool::protocol_account(),
+			));
+			assert_ok!(Omnipool::add_token(
+				RuntimeOrigin::root(),
+				DAI,
+				FixedU128::from_float(0.65),
+				Permill::from_percent(100),
+				Omnipool::protocol_account(),
+			));
+
+			for (asset_id, price, owner, amount) in self.pool_tokens {
+				assert_ok!(Tokens::transfer(
+					RuntimeOrigin::signed(owner),
+					Omnipool::protocol_account(),
+					asset_id,
+					amount
+				));
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					asset_id,
+					price,
+					Permill::from_percent(100),
+					owner
+				));
+			}
+
+			if self.with_subpool {
+				create_subpool();
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|o| (*o).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl InspectRegistry for DummyRegistry {
+	type AssetId = AssetId;
+	type Location = u8;
+
+	fn is_sufficient(_id: Self::AssetId) -> bool {
+		true
+	}
+
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn asset_type(_id: Self::AssetId) -> Option<AssetKind> {
+		unimplemented!()
+	}
+
+	fn is_banned(_id: Self::AssetId) -> bool {
+		unimplemented!()
+	}
+
+	fn asset_name(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_id: Self::AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn existential_deposit(id: Self::AssetId) -> Option<u128> {
+		Some(ExistentialDeposits::get(&id))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_stableswap::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+
+	fn remove_account(_account: &AccountId) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
+pub struct WithdrawFeePriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		Ok(EmaPrice::new(asset_state.hub_reserve, asset_state.reserve))
+	}
+
+	fn get_price_weight() -> Weight {
+		todo!()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(Permill::zero(), Permill::zero())
+	}
+}
+
+pub struct RemovedAssetsHooks;
+
+impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for RemovedAssetsHooks {
+	type Error = DispatchError;
+
+	fn on_liquidity_changed(_: RuntimeOrigin, _: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
+	fn on_trade(
+		_: RuntimeOrigin,
+		_: AssetInfo<AssetId, Balance>,
+		_: AssetInfo<AssetId, Balance>,
+	) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
+	fn on_hub_asset_trade(_: RuntimeOrigin, _: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error> {
+		REMOVED_ASSETS.with(|v| v.borrow_mut().push(asset_id));
+		Ok(Weight::zero())
+	}
+
+	fn on_liquidity_changed_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_trade_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_asset_removed_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn on_trade_fee(_: AccountId, _: AccountId, _: AssetId, _: Balance) -> Result<Balance, Self::Error> {
+		Ok(0)
+	}
+}
+
+pub(crate) fn removed_assets() -> Vec<AssetId> {
+	REMOVED_ASSETS.with(|v| v.borrow().clone())
+}
+
+pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
+	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
+}
+
+pub(crate) fn create_subpool() {
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		SHARE_ASSET,
+		USDT,
+		USDC,
+		Permill::from_percent(100),
+		100u16,
+		Permill::from_percent(0),
+	));
+}
+
+pub(crate) fn position_owner(position_id: u32) -> Option<AccountId> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
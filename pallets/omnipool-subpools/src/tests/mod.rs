@@ -0,0 +1,6 @@
+mod create_subpool;
+mod migrate_asset;
+mod migrate_position;
+mod migrate_route;
+pub(crate) mod mock;
+mod trades;
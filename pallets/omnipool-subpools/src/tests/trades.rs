@@ -0,0 +1,170 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::mock::*;
+use crate::*;
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn ext() -> ExtBuilder {
+	ExtBuilder::default()
+		.with_registered_asset(USDT)
+		.with_registered_asset(USDC)
+		.with_registered_asset(SHARE_ASSET)
+		.add_endowed_accounts((LP1, USDT, 5000 * ONE))
+		.add_endowed_accounts((LP1, USDC, 5000 * ONE))
+		.add_endowed_accounts((ALICE, USDT, 100 * ONE))
+		.add_endowed_accounts((ALICE, DAI, 100 * ONE))
+		.with_token(USDT, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+}
+
+#[test]
+fn trade_route_should_contain_single_stableswap_trade_when_assets_are_in_same_subpool() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_eq!(
+			OmnipoolSubpools::trade_route(USDT, USDC),
+			vec![Trade {
+				pool: PoolType::Stableswap(SHARE_ASSET),
+				asset_in: USDT,
+				asset_out: USDC,
+			}]
+		);
+	});
+}
+
+#[test]
+fn trade_route_should_go_through_omnipool_when_assets_are_in_different_pools() {
+	ext().with_subpool().build().execute_with(|| {
+		assert_eq!(
+			OmnipoolSubpools::trade_route(USDT, DAI),
+			vec![
+				Trade {
+					pool: PoolType::Stableswap(SHARE_ASSET),
+					asset_in: USDT,
+					asset_out: SHARE_ASSET,
+				},
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: SHARE_ASSET,
+					asset_out: DAI,
+				}
+			]
+		);
+		assert_eq!(
+			OmnipoolSubpools::trade_route(DAI, USDC),
+			vec![
+				Trade {
+					pool: PoolType::Omnipool,
+					asset_in: DAI,
+					asset_out: SHARE_ASSET,
+				},
+				Trade {
+					pool: PoolType::Stableswap(SHARE_ASSET),
+					asset_in: SHARE_ASSET,
+					asset_out: USDC,
+				}
+			]
+		);
+		assert_eq!(
+			OmnipoolSubpools::trade_route(DAI, HDX),
+			vec![Trade {
+				pool: PoolType::Omnipool,
+				asset_in: DAI,
+				asset_out: HDX,
+			}]
+		);
+	});
+}
+
+fn migrate_route(asset_in: AssetId, asset_out: AssetId) {
+	assert_ok!(OmnipoolSubpools::migrate_route(
+		RuntimeOrigin::signed(ALICE),
+		AssetPair::new(asset_in, asset_out),
+	));
+}
+
+#[test]
+fn router_sell_should_work_when_selling_subpool_asset_for_omnipool_asset() {
+	ext().with_subpool().build().execute_with(|| {
+		migrate_route(USDT, DAI);
+
+		let share_state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE),
+			USDT,
+			DAI,
+			10 * ONE,
+			ONE,
+			vec![]
+		));
+
+		assert_eq!(Tokens::free_balance(USDT, &ALICE), 90 * ONE);
+		assert!(Tokens::free_balance(DAI, &ALICE) > 100 * ONE);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &ALICE), 0);
+		assert!(Omnipool::load_asset_state(SHARE_ASSET).unwrap().reserve > share_state.reserve);
+	});
+}
+
+#[test]
+fn router_sell_should_work_when_selling_omnipool_asset_for_subpool_asset() {
+	ext().with_subpool().build().execute_with(|| {
+		migrate_route(DAI, USDC);
+
+		assert_ok!(Router::sell(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			USDC,
+			10 * ONE,
+			ONE,
+			vec![]
+		));
+
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 90 * ONE);
+		assert!(Tokens::free_balance(USDC, &ALICE) > 0);
+		assert_eq!(Tokens::free_balance(SHARE_ASSET, &ALICE), 0);
+	});
+}
+
+#[test]
+fn router_buy_should_work_when_buying_subpool_asset_for_omnipool_asset() {
+	ext().with_subpool().build().execute_with(|| {
+		migrate_route(DAI, USDC);
+
+		assert_ok!(Router::buy(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			USDC,
+			5 * ONE,
+			50 * ONE,
+			vec![]
+		));
+
+		assert_eq!(Tokens::free_balance(USDC, &ALICE), 5 * ONE);
+		assert!(Tokens::free_balance(DAI, &ALICE) < 100 * ONE);
+	});
+}
+
+#[test]
+fn router_sell_should_fail_when_route_of_migrated_asset_is_not_migrated() {
+	ext().with_subpool().build().execute_with(|| {
+		assert!(Router::sell(RuntimeOrigin::signed(ALICE), USDT, DAI, 10 * ONE, ONE, vec![]).is_err());
+	});
+}
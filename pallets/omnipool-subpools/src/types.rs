@@ -0,0 +1,43 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use scale_info::TypeInfo;
+
+pub use pallet_omnipool::types::Balance;
+
+/// Details of an Omnipool asset at the moment of its migration to a subpool.
+///
+/// Used to convert positions of the migrated asset into positions of the subpool share asset.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct AssetDetail {
+	/// Price of the asset in subpool shares - ( numerator, denominator )
+	pub price: (Balance, Balance),
+	/// Quantity of LP shares of the asset in Omnipool
+	pub shares: Balance,
+	/// Quantity of Hub Asset matching the asset
+	pub hub_reserve: Balance,
+	/// Quantity of subpool shares minted for the asset reserve
+	pub share_tokens: Balance,
+}
+
+impl From<MigrationDetails> for AssetDetail {
+	fn from(details: MigrationDetails) -> Self {
+		Self {
+			price: details.price,
+			shares: details.shares,
+			hub_reserve: details.hub_reserve,
+			share_tokens: details.share_tokens,
+		}
+	}
+}
+
+impl From<&AssetDetail> for MigrationDetails {
+	fn from(detail: &AssetDetail) -> Self {
+		Self {
+			price: detail.price,
+			shares: detail.shares,
+			hub_reserve: detail.hub_reserve,
+			share_tokens: detail.share_tokens,
+		}
+	}
+}
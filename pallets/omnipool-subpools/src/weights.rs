@@ -0,0 +1,82 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_omnipool_subpools`
+//!
+//! The weight of `migrate_route` does not include setting the route in the router, which is weighed by the router.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_omnipool_subpools.
+pub trait WeightInfo {
+	fn create_subpool() -> Weight;
+	fn migrate_asset_to_subpool() -> Weight;
+	fn migrate_position() -> Weight;
+	fn migrate_route() -> Weight;
+}
+
+/// Weights for pallet_omnipool_subpools using the hydraDX node and recommended hardware.
+impl WeightInfo for () {
+	/// Storage: `Omnipool::Assets` (r:3 w:3)
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Storage: `AssetRegistry::Assets` (r:4 w:0)
+	/// Storage: `Tokens::Accounts` (r:5 w:5)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Storage: `Duster::AccountBlacklist` (r:0 w:1)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:0 w:2)
+	/// Storage: `OmnipoolSubpools::Subpools` (r:0 w:1)
+	fn create_subpool() -> Weight {
+		Weight::from_parts(120_000_000, 16590)
+			.saturating_add(RocksDbWeight::get().reads(14_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `OmnipoolSubpools::Subpools` (r:1 w:0)
+	/// Storage: `Omnipool::Assets` (r:2 w:2)
+	/// Storage: `Stableswap::Pools` (r:1 w:1)
+	/// Storage: `AssetRegistry::Assets` (r:1 w:0)
+	/// Storage: `Tokens::Accounts` (r:4 w:4)
+	/// Storage: `Tokens::TotalIssuance` (r:1 w:1)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:0 w:1)
+	fn migrate_asset_to_subpool() -> Weight {
+		Weight::from_parts(90_000_000, 14000)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
+	/// Storage: `Uniques::Asset` (r:1 w:0)
+	/// Storage: `Omnipool::Positions` (r:1 w:1)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:1 w:0)
+	fn migrate_position() -> Weight {
+		Weight::from_parts(40_000_000, 3655)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Router::Routes` (r:1 w:0)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:2 w:0)
+	fn migrate_route() -> Weight {
+		Weight::from_parts(25_000_000, 5080)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+	}
+}
@@ -0,0 +1,53 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_omnipool::types::{AssetReserveState, PositionValue, TradeQuote};
+use sp_runtime::{DispatchError, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query state of Omnipool and quote its trades.
+	pub trait OmnipoolApi<AssetId, Balance, PositionItemId> where
+		AssetId: Codec,
+		Balance: Codec,
+		PositionItemId: Codec,
+	{
+		/// Get state of all assets in Omnipool, including asset reserve.
+		fn assets() -> Vec<(AssetId, AssetReserveState<Balance>)>;
+
+		/// Get state of an asset in Omnipool, including asset reserve.
+		fn asset_state(asset_id: AssetId) -> Option<AssetReserveState<Balance>>;
+
+		/// Get spot price of `asset_a` denominated in `asset_b`, without fees.
+		fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128>;
+
+		/// Get current asset fee and protocol fee of an asset.
+		fn fees(asset_id: AssetId) -> (Permill, Permill);
+
+		/// Calculate amount of `asset_out` received when selling `amount_in` of `asset_in`, with current fees applied.
+		fn calculate_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Calculate amount of `asset_in` required to buy `amount_out` of `asset_out`, with current fees applied.
+		fn calculate_buy(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Calculate amounts received when all shares of a position are removed, including withdrawal fee.
+		fn position_value(position_id: PositionItemId) -> Result<PositionValue<AssetId, Balance>, DispatchError>;
+	}
+}
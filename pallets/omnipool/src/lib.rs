@@ -49,6 +49,7 @@
 //! - on_liquidity_changed - called when liquidity is added or removed from the pool
 //! - on_trade - called when trade is executed
 //! - on_trade_fee - called after successful trade with fee amount that can be taken out of the pool if needed.
+//! - on_asset_removed - called when an asset is removed from the pool
 //!
 //! This is currently used to update on-chain oracle and in the circuit breaker.
 //!
@@ -115,8 +116,13 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
+use crate::traits::ExternalPriceProvider;
 use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, Position, PositionValue, SimpleImbalance, Tradability, TradeQuote,
+};
+use frame_support::traits::DefensiveOption;
+use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
@@ -131,7 +137,6 @@ pub mod pallet {
 	use crate::types::{Position, Price, Tradability};
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::DefensiveOption;
 	use frame_system::pallet_prelude::*;
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
@@ -723,17 +728,7 @@ pub mod pallet {
 				)
 				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 			}
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
-
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
+			let withdrawal_fee = Self::calculate_withdrawal_fee(asset_id, &asset_state)?;
 
 			let current_imbalance = <HubAssetImbalance<T>>::get();
 			let current_hub_asset_liquidity =
@@ -1521,7 +1516,7 @@ pub mod pallet {
 		///
 		/// Emits `TokenRemoved` event when successful.
 		#[pallet::call_index(12)]
-		#[pallet::weight(<T as Config>::WeightInfo::remove_token())]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_token().saturating_add(T::OmnipoolHooks::on_asset_removed_weight()))]
 		#[transactional]
 		pub fn remove_token(origin: OriginFor<T>, asset_id: T::AssetId, beneficiary: T::AccountId) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
@@ -1549,7 +1544,7 @@ pub mod pallet {
 
 			T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), asset_state.hub_reserve)?;
 			T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, asset_state.reserve)?;
-			<Assets<T>>::remove(asset_id);
+			Self::remove_asset(asset_id)?;
 			Self::deposit_event(Event::TokenRemoved {
 				asset_id,
 				amount: asset_state.reserve,
@@ -1960,10 +1955,12 @@ impl<T: Config> Pallet<T> {
 		T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
 	}
 
-	/// Remove asset from list of Omnipool assets.
+	/// Remove asset from list of Omnipool assets and notify hooks about the removal.
+	/// Reserves of the asset are not moved, hub asset reserve of the asset must be accounted for by the caller.
 	/// No events emitted.
 	pub fn remove_asset(asset_id: T::AssetId) -> DispatchResult {
 		<Assets<T>>::remove(asset_id);
+		T::OmnipoolHooks::on_asset_removed(asset_id)?;
 		Ok(())
 	}
 
@@ -2016,6 +2013,140 @@ impl<T: Config> Pallet<T> {
 		Assets::<T>::contains_key(asset)
 	}
 
+	/// Load states of all assets in Omnipool.
+	pub fn load_all_asset_states() -> Vec<(T::AssetId, AssetReserveState<Balance>)> {
+		let account = Self::protocol_account();
+		<Assets<T>>::iter()
+			.map(|(asset_id, state)| {
+				let reserve = T::Currency::free_balance(asset_id, &account);
+				(asset_id, (state, reserve).into())
+			})
+			.collect()
+	}
+
+	/// Calculate amount of `asset_out` received when selling `amount_in` of `asset_in`.
+	///
+	/// Current asset fee of `asset_out` and protocol fee of `asset_in` are applied and returned together with the amounts.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		let amount_out = <Self as TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>>::calculate_sell(
+			PoolType::Omnipool,
+			asset_in,
+			asset_out,
+			amount_in,
+		)
+		.map_err(Self::executor_error)?;
+
+		let (asset_fee, protocol_fee) = Self::trade_fees(asset_in, asset_out);
+
+		Ok(TradeQuote {
+			amount_in,
+			amount_out,
+			asset_fee,
+			protocol_fee,
+		})
+	}
+
+	/// Calculate amount of `asset_in` required to buy `amount_out` of `asset_out`.
+	///
+	/// Current asset fee of `asset_out` and protocol fee of `asset_in` are applied and returned together with the amounts.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		let amount_in = <Self as TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>>::calculate_buy(
+			PoolType::Omnipool,
+			asset_in,
+			asset_out,
+			amount_out,
+		)
+		.map_err(Self::executor_error)?;
+
+		let (asset_fee, protocol_fee) = Self::trade_fees(asset_in, asset_out);
+
+		Ok(TradeQuote {
+			amount_in,
+			amount_out,
+			asset_fee,
+			protocol_fee,
+		})
+	}
+
+	/// Calculate amounts LP would receive if all shares of given position were removed, including withdrawal fee.
+	pub fn calculate_position_value(
+		position_id: T::PositionItemId,
+	) -> Result<PositionValue<T::AssetId, Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+
+		let withdrawal_fee = Self::calculate_withdrawal_fee(position.asset_id, &asset_state)?;
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			position.shares,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(PositionValue {
+			asset_id: position.asset_id,
+			shares: position.shares,
+			amount: *state_changes.asset.delta_reserve,
+			hub_amount: state_changes.lp_hub_amount,
+			withdrawal_fee,
+		})
+	}
+
+	/// Calculate dynamic withdrawal fee of an asset using spot price and external price oracle.
+	fn calculate_withdrawal_fee(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+	) -> Result<FixedU128, DispatchError> {
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+
+		Ok(hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		))
+	}
+
+	/// Returns asset fee of `asset_out` and protocol fee of `asset_in`. Protocol fee is not applied when hub asset is sold.
+	fn trade_fees(asset_in: T::AssetId, asset_out: T::AssetId) -> (Permill, Permill) {
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+		let protocol_fee = if asset_in == T::HubAssetId::get() {
+			Permill::zero()
+		} else {
+			T::Fee::get(&asset_in).1
+		};
+		(asset_fee, protocol_fee)
+	}
+
+	fn executor_error(error: ExecutorError<DispatchError>) -> DispatchError {
+		match error {
+			ExecutorError::NotSupported => Error::<T>::NotAllowed.into(),
+			ExecutorError::Error(dispatch_error) => dispatch_error,
+		}
+	}
+
 	/// Calls `on_trade_fee` hook and ensures that no more than the fee amount is transferred.
 	fn process_trade_fee(trader: &T::AccountId, asset: T::AssetId, amount: Balance) -> DispatchResult {
 		let account = Self::protocol_account();
@@ -1,13 +1,15 @@
 use crate::types::Balance;
-use crate::{Config, Error, HubAssetImbalance, Pallet};
+use crate::{Assets, Config, Error, HubAssetImbalance, Pallet};
 use frame_system::pallet_prelude::OriginFor;
 use hydra_dx_math::omnipool::types::I129;
 
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolType, PoolsProvider, TradeExecution};
 use orml_traits::{GetByKey, MultiCurrency};
 use sp_runtime::traits::Get;
 use sp_runtime::DispatchError::Corruption;
 use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128};
+use sp_std::vec;
+use sp_std::vec::Vec;
 
 // dev note: The code is calculate sell and buy is copied from the corresponding functions.
 // This is not ideal and should be refactored to avoid code duplication.
@@ -208,3 +210,14 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		Ok(spot_price)
 	}
 }
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn pools(max_assets: u32) -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		let assets: Vec<T::AssetId> = Assets::<T>::iter_keys().take(max_assets as usize).collect();
+		if assets.is_empty() {
+			return vec![];
+		}
+
+		vec![(PoolType::Omnipool, assets)]
+	}
+}
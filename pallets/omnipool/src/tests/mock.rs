@@ -708,6 +708,10 @@ impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for MockHooks {
 		Ok(Weight::zero())
 	}
 
+	fn on_asset_removed(_asset_id: AssetId) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		Weight::zero()
 	}
@@ -716,6 +720,10 @@ impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for MockHooks {
 		Weight::zero()
 	}
 
+	fn on_asset_removed_weight() -> Weight {
+		Weight::zero()
+	}
+
 	fn on_trade_fee(
 		fee_account: AccountId,
 		_trader: AccountId,
@@ -15,6 +15,7 @@ mod barrier;
 mod imbalance;
 pub(crate) mod mock;
 mod positions;
+mod quote;
 mod refund;
 mod remove_liquidity_with_limit;
 mod remove_token;
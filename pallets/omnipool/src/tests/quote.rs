@@ -0,0 +1,185 @@
+use super::*;
+use crate::types::{PositionValue, TradeQuote};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn load_all_asset_states_should_return_state_of_all_assets() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let mut states = Omnipool::load_all_asset_states();
+			states.sort_by_key(|(asset_id, _)| *asset_id);
+
+			assert_eq!(
+				states,
+				vec![
+					(HDX, Omnipool::load_asset_state(HDX).unwrap()),
+					(DAI, Omnipool::load_asset_state(DAI).unwrap()),
+					(100, Omnipool::load_asset_state(100).unwrap()),
+				]
+			);
+		});
+}
+
+#[test]
+fn quote_sell_should_return_same_amount_as_sell_when_fees_are_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP2, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_rational(2u32, 1000u32))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let quote = Omnipool::quote_sell(100, 200, 50 * ONE).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, 50 * ONE, 0));
+
+			assert_eq!(
+				quote,
+				TradeQuote {
+					amount_in: 50 * ONE,
+					amount_out: Tokens::free_balance(200, &LP1),
+					asset_fee: Permill::from_percent(1),
+					protocol_fee: Permill::from_rational(2u32, 1000u32),
+				}
+			);
+		});
+}
+
+#[test]
+fn quote_buy_should_return_same_amount_as_buy_when_fees_are_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP2, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_rational(2u32, 1000u32))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let quote = Omnipool::quote_buy(100, 200, 50 * ONE).unwrap();
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP1),
+				200,
+				100,
+				50 * ONE,
+				Balance::MAX
+			));
+
+			assert_eq!(
+				quote,
+				TradeQuote {
+					amount_in: 1000 * ONE - Tokens::free_balance(100, &LP1),
+					amount_out: 50 * ONE,
+					asset_fee: Permill::from_percent(1),
+					protocol_fee: Permill::from_rational(2u32, 1000u32),
+				}
+			);
+		});
+}
+
+#[test]
+fn quote_sell_should_not_apply_protocol_fee_when_selling_hub_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+		])
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_rational(2u32, 1000u32))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let quote = Omnipool::quote_sell(LRNA, 100, 50 * ONE).unwrap();
+
+			assert_eq!(quote.protocol_fee, Permill::zero());
+			assert_eq!(quote.asset_fee, Permill::from_percent(1));
+		});
+}
+
+#[test]
+fn calculate_position_value_should_return_amounts_received_when_all_shares_are_removed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, 1_000, 5000 * ONE),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.build()
+		.execute_with(|| {
+			let position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+
+			// Move the price so LP receives also some hub asset
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), 1_000, HDX, 100 * ONE, 0));
+
+			let value = Omnipool::calculate_position_value(position_id).unwrap();
+
+			let asset_balance = Tokens::free_balance(1_000, &LP1);
+			let hub_balance = Tokens::free_balance(LRNA, &LP1);
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				400 * ONE
+			));
+
+			assert!(value.withdrawal_fee >= FixedU128::from(Permill::from_percent(1)));
+			assert_eq!(
+				value,
+				PositionValue {
+					asset_id: 1_000,
+					shares: 400 * ONE,
+					amount: Tokens::free_balance(1_000, &LP1) - asset_balance,
+					hub_amount: Tokens::free_balance(LRNA, &LP1) - hub_balance,
+					withdrawal_fee: value.withdrawal_fee,
+				}
+			);
+		});
+}
+
+#[test]
+fn calculate_position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				Omnipool::calculate_position_value(1234),
+				Err(Error::<Test>::PositionNotFound.into())
+			);
+		});
+}
@@ -55,8 +55,12 @@ where
 
 	fn on_hub_asset_trade(origin: Origin, asset: AssetInfo<AssetId, Balance>) -> Result<Weight, Self::Error>;
 
+	/// Called when an asset is removed from Omnipool.
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error>;
+
 	fn on_liquidity_changed_weight() -> Weight;
 	fn on_trade_weight() -> Weight;
+	fn on_asset_removed_weight() -> Weight;
 
 	/// Returns used amount
 	fn on_trade_fee(
@@ -90,6 +94,10 @@ where
 		Ok(Weight::zero())
 	}
 
+	fn on_asset_removed(_: AssetId) -> Result<Weight, Self::Error> {
+		Ok(Weight::zero())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		Weight::zero()
 	}
@@ -98,6 +106,10 @@ where
 		Weight::zero()
 	}
 
+	fn on_asset_removed_weight() -> Weight {
+		Weight::zero()
+	}
+
 	fn on_trade_fee(
 		_fee_account: AccountId,
 		_trader: AccountId,
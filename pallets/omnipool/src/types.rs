@@ -214,7 +214,7 @@ impl<Balance: CheckedAdd + CheckedSub + PartialOrd + Copy> Sub<Balance> for Simp
 }
 
 /// Asset state representation including asset pool reserve.
-#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
 pub struct AssetReserveState<Balance> {
 	/// Quantity of asset in omnipool
 	pub reserve: Balance,
@@ -319,3 +319,31 @@ where
 		})
 	}
 }
+
+/// Result of a trade calculation including fees which were applied.
+#[derive(Clone, Copy, Default, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct TradeQuote<Balance> {
+	/// Amount of asset sold
+	pub amount_in: Balance,
+	/// Amount of asset bought
+	pub amount_out: Balance,
+	/// Asset fee of asset bought
+	pub asset_fee: Permill,
+	/// Protocol fee of asset sold
+	pub protocol_fee: Permill,
+}
+
+/// Amounts received by LP if all shares of a position were removed.
+#[derive(Clone, Copy, Default, Debug, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct PositionValue<AssetId, Balance> {
+	/// Asset of the position
+	pub asset_id: AssetId,
+	/// Shares of the position
+	pub shares: Balance,
+	/// Amount of asset received
+	pub amount: Balance,
+	/// Amount of hub asset received
+	pub hub_amount: Balance,
+	/// Withdrawal fee applied
+	pub withdrawal_fee: FixedU128,
+}
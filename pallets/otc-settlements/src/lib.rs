@@ -474,6 +474,14 @@ impl<T: Config> Pallet<T> {
 
 				let mut list = vec![];
 				for (otc_id, otc) in <pallet_otc::Orders<T>>::iter() {
+					// expired orders can't be filled and are going to be removed by the OTC pallet
+					if pallet_otc::Pallet::<T>::is_expired_at(otc_id, block_number) {
+						log::debug!(
+							target: "offchain_worker::sort_otcs",
+							"expired, skipping OTC: {:?}", otc_id);
+						continue;
+					}
+
 					let otc_price = Self::otc_price(&otc).ok();
 
 					let route = T::Router::get_route(AssetPair {
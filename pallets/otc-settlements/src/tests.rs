@@ -174,6 +174,7 @@ fn otcs_list_storage_should_be_sorted_on_new_block() {
 			1_000_000_000,
 			2_000_000_000,
 			true,
+			None,
 		));
 
 		OtcSettlements::sort_otcs(block_num);
@@ -187,6 +188,46 @@ fn otcs_list_storage_should_be_sorted_on_new_block() {
 	});
 }
 
+#[test]
+fn expired_otcs_should_not_be_in_sorted_list() {
+	let (mut ext, _) = ExtBuilder::default().build();
+	ext.execute_with(|| {
+		let expiring_otc_id = OTC::next_order_id();
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+			Some(10),
+		));
+		let otc_id = OTC::next_order_id();
+		assert_ok!(OTC::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100_000 * ONE,
+			205_000 * ONE,
+			true,
+			None,
+		));
+
+		frame_system::Pallet::<Test>::set_block_number(10);
+		let block_num = frame_system::Pallet::<Test>::block_number();
+
+		OtcSettlements::sort_otcs(block_num);
+
+		let sorted_list_of_otcs = StorageValueRef::persistent(OFFCHAIN_WORKER_DATA)
+			.get::<Vec<SortedOtcsStorageType>>()
+			.unwrap_or_default()
+			.unwrap_or_default();
+
+		assert!(sorted_list_of_otcs.contains(&otc_id));
+		assert!(!sorted_list_of_otcs.contains(&expiring_otc_id));
+	});
+}
+
 #[test]
 fn profit_should_be_transferred_to_treasury_when_zero_initial_pallet_balance() {
 	let (mut ext, _) = ExtBuilder::default().build();
@@ -198,6 +239,7 @@ fn profit_should_be_transferred_to_treasury_when_zero_initial_pallet_balance() {
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 
 		let pallet_acc = OtcSettlements::account_id();
@@ -228,6 +270,7 @@ fn profit_should_be_transferred_to_treasury_when_nonzero_initial_pallet_balance(
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 
 		let pallet_acc = OtcSettlements::account_id();
@@ -260,6 +303,7 @@ fn existing_arb_opportunity_should_trigger_trade_when_correct_amount_can_be_foun
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -311,6 +355,7 @@ fn existing_arb_opportunity_should_trigger_trade_when_partially_fillable_otc_can
 			100 * ONE,
 			205 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -366,7 +411,8 @@ fn existing_arb_opportunity_should_trigger_trade_when_otc_is_not_partially_filla
 			DAI, // otc asset_out
 			100_000 * ONE,
 			300_000 * ONE,
-			false, // not partially fillable
+			false, // not partially fillable,
+			None
 		));
 
 		// get otc price
@@ -421,6 +467,7 @@ fn existing_arb_opportunity_of_insufficient_asset_should_trigger_trade() {
 			200_000 * ONE,
 			105_000 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -472,6 +519,7 @@ fn multiple_arb_opportunities_should_trigger_trades() {
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 		assert_ok!(OTC::place_order(
 			RuntimeOrigin::signed(ALICE),
@@ -480,6 +528,7 @@ fn multiple_arb_opportunities_should_trigger_trades() {
 			100_000 * ONE,
 			102_000 * ONE,
 			true,
+			None,
 		));
 
 		<OtcSettlements as Hooks<BlockNumberFor<Test>>>::offchain_worker(System::block_number());
@@ -510,6 +559,7 @@ fn trade_should_be_triggered_when_arb_opportunity_appears() {
 			100_001 * ONE,
 			200_000 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -581,6 +631,7 @@ fn trade_should_not_be_triggered_when_there_is_no_arb_opportunity() {
 			100_001 * ONE,
 			200_000 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -628,6 +679,7 @@ fn trade_should_be_triggered_when_optimal_amount_not_found_but_arb_can_be_reduce
 			1_000_000 * ONE,
 			8_000_000_001 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -673,6 +725,7 @@ fn trade_should_not_be_triggered_when_amount_not_found() {
 			1_000 * ONE,
 			800_000_000_000_000_001 * ONE,
 			true,
+			None,
 		));
 
 		// get otc price
@@ -710,6 +763,7 @@ fn test_offchain_worker_unsigned_transaction_submission() {
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 
 		let otc_id = 0;
@@ -748,6 +802,7 @@ fn test_offchain_worker_signed_transaction_submission() {
 			100_000 * ONE,
 			205_000 * ONE,
 			true,
+			None,
 		));
 
 		let otc_id = 0;
@@ -774,6 +829,7 @@ fn place_orders() {
 		1_000_000_000,
 		2_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -783,6 +839,7 @@ fn place_orders() {
 		1_000_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -792,6 +849,7 @@ fn place_orders() {
 		4_000_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -801,6 +859,7 @@ fn place_orders() {
 		7_000_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -810,6 +869,7 @@ fn place_orders() {
 		2_000_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -819,6 +879,7 @@ fn place_orders() {
 		2_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -828,6 +889,7 @@ fn place_orders() {
 		2_000_000_000,
 		3_000_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -837,6 +899,7 @@ fn place_orders() {
 		2_000_000_000,
 		3_000_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -846,6 +909,7 @@ fn place_orders() {
 		9_000_000_000,
 		3_000_000_000,
 		true,
+		None,
 	));
 
 	assert_ok!(OTC::place_order(
@@ -855,5 +919,6 @@ fn place_orders() {
 		2_000_000_000,
 		13_000_000_000,
 		true,
+		None,
 	));
 }
@@ -35,7 +35,8 @@ benchmarks! {
 		let (dot, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
-  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+		let expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 10u32.into();
+  }:  _(RawOrigin::Signed(owner.clone()), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(expires_at))
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 100 * ONE);
 	}
@@ -46,8 +47,9 @@ benchmarks! {
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
 
+		let expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 10u32.into();
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(expires_at))
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32, 10 * ONE)
 	verify {
@@ -60,8 +62,9 @@ benchmarks! {
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
 		let filler: T::AccountId = create_account_with_balances::<T>("filler", 2, vec!(dot, dai))?;
 
+		let expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 10u32.into();
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(expires_at))
 		);
   }:  _(RawOrigin::Signed(filler.clone()), 0u32)
 	verify {
@@ -72,13 +75,29 @@ benchmarks! {
 		let (dot, dai) = seed_registry::<T>()?;
 
 		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		let expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 10u32.into();
 		assert_ok!(
-			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true)
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(expires_at))
 		);
   }:  _(RawOrigin::Signed(owner.clone()), 0u32)
 	verify {
 		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
 	}
+
+	cancel_expired_order {
+		let (dot, dai) = seed_registry::<T>()?;
+
+		let owner: T::AccountId = create_account_with_balances::<T>("owner", 1, vec!(dot, dai))?;
+		let expires_at: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		assert_ok!(
+			crate::Pallet::<T>::place_order(RawOrigin::Signed(owner.clone()).into(), dai.into(), dot.into(), 20 * ONE, 100 * ONE, true, Some(expires_at))
+		);
+		frame_system::Pallet::<T>::set_block_number(expires_at);
+  }:  { crate::Pallet::<T>::cancel_expired_order(expires_at, 0u32) }
+	verify {
+		assert_eq!(T::Currency::reserved_balance_named(&NAMED_RESERVE_ID, dot.into(), &owner), 0);
+		assert!(crate::Pallet::<T>::orders(0u32).is_none());
+	}
 }
 
 fn seed_registry<T: Config>() -> Result<(u32, u32), DispatchError>
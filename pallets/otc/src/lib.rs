@@ -20,6 +20,9 @@
 // whether the order is partially fillable. Fee is applied to all trades and is deducted from the `amount_out`.
 // Because of the fee, the order price is static and calculated as `(amount_out - fee) / amount_in`.
 //
+// An order can optionally be placed with an expiry block. Expired orders cannot be filled and are cancelled
+// in `on_idle` - the reserved `amount_out` is returned to the owner.
+//
 // ## Notes
 // The pallet implements a minimum order size as an alternative to storage fees. The amounts of an open order cannot
 // be lower than the existential deposit for the respective asset, multiplied by `ExistentialDepositMultiplier`.
@@ -36,12 +39,16 @@
 
 use codec::MaxEncodedLen;
 use frame_support::{pallet_prelude::*, require_transactional};
-use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use frame_system::{
+	ensure_signed,
+	pallet_prelude::{BlockNumberFor, OriginFor},
+};
 use hydradx_traits::Inspect;
 use orml_traits::{GetByKey, MultiCurrency, NamedMultiReservableCurrency};
 use sp_core::U256;
-use sp_runtime::traits::{One, Zero};
+use sp_runtime::traits::{One, Saturating, Zero};
 use sp_runtime::Permill;
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -147,7 +154,10 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			expires_at: Option<BlockNumberFor<T>>,
 		},
+		/// An Order has expired and has been cancelled
+		Expired { order_id: OrderId },
 	}
 
 	#[pallet::error]
@@ -170,6 +180,10 @@ pub mod pallet {
 		Forbidden,
 		/// Reserved amount not sufficient.
 		InsufficientReservedAmount,
+		/// Expiry block must be in the future
+		InvalidExpiry,
+		/// Order has expired and cannot be filled
+		OrderExpired,
 	}
 
 	/// ID sequencer for Orders
@@ -181,6 +195,28 @@ pub mod pallet {
 	#[pallet::getter(fn orders)]
 	pub type Orders<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, Order<T::AccountId, T::AssetId>, OptionQuery>;
 
+	/// Block at which an order expires. Only present for orders placed with an expiry.
+	#[pallet::storage]
+	#[pallet::getter(fn order_expiration)]
+	pub type OrderExpiration<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, BlockNumberFor<T>, OptionQuery>;
+
+	/// Orders which expire at given block.
+	#[pallet::storage]
+	pub type ExpiringOrders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, BlockNumberFor<T>, Blake2_128Concat, OrderId, (), OptionQuery>;
+
+	/// Next block to be checked for expired orders in `on_idle`.
+	/// Set to the earliest expiry when an order with expiry is placed and cleared when no orders expire.
+	#[pallet::storage]
+	pub type NextExpiryCheck<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			Self::cancel_expired_orders(n, remaining_weight)
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new OTC order
@@ -191,6 +227,7 @@ pub mod pallet {
 		/// - `amount_in`: Amount that the order is seeking to buy
 		/// - `amount_out`: Amount that the order is selling
 		/// - `partially_fillable`: Flag indicating whether users can fill the order partially
+		/// - `expires_at`: Optional block from which the order can no longer be filled
 		///
 		/// Validations:
 		/// - asset_in must be registered
@@ -198,6 +235,7 @@ pub mod pallet {
 		///   ExistentialDepositMultiplier
 		/// - amount_out must be higher than the existential deposit of asset_out multiplied by
 		///   ExistentialDepositMultiplier
+		/// - expires_at, if set, must be greater than the current block
 		///
 		/// Events:
 		/// - `Placed` event when successful.
@@ -210,6 +248,7 @@ pub mod pallet {
 			amount_in: Balance,
 			amount_out: Balance,
 			partially_fillable: bool,
+			expires_at: Option<BlockNumberFor<T>>,
 		) -> DispatchResult {
 			let owner = ensure_signed(origin)?;
 			let order = Order {
@@ -223,6 +262,11 @@ pub mod pallet {
 
 			ensure!(T::AssetRegistry::exists(order.asset_in), Error::<T>::AssetNotRegistered);
 
+			let current_block = frame_system::Pallet::<T>::block_number();
+			if let Some(expiry) = expires_at {
+				ensure!(expiry > current_block, Error::<T>::InvalidExpiry);
+			}
+
 			let fee = Self::calculate_fee(order.amount_out);
 
 			Self::ensure_min_order_amount(order.asset_in, order.amount_in)?;
@@ -238,6 +282,14 @@ pub mod pallet {
 				T::Currency::reserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out)?;
 				<Orders<T>>::insert(order_id, &order);
 
+				if let Some(expiry) = expires_at {
+					<OrderExpiration<T>>::insert(order_id, expiry);
+					<ExpiringOrders<T>>::insert(expiry, order_id, ());
+					<NextExpiryCheck<T>>::mutate(|next| {
+						*next = Some(next.map_or(expiry, |next| next.min(expiry)));
+					});
+				}
+
 				Self::deposit_event(Event::Placed {
 					order_id,
 					asset_in: order.asset_in,
@@ -245,6 +297,7 @@ pub mod pallet {
 					amount_in: order.amount_in,
 					amount_out,
 					partially_fillable: order.partially_fillable,
+					expires_at,
 				});
 
 				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
@@ -260,6 +313,7 @@ pub mod pallet {
 		///
 		/// Validations:
 		/// - order must be partially_fillable
+		/// - order must not be expired
 		/// - after the partial_fill, the remaining order.amount_in must be higher than the existential deposit
 		///   of asset_in multiplied by ExistentialDepositMultiplier
 		/// - after the partial_fill, the remaining order.amount_out must be higher than the existential deposit
@@ -275,6 +329,7 @@ pub mod pallet {
 				let order = maybe_order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
 
 				ensure!(order.partially_fillable, Error::<T>::OrderNotPartiallyFillable);
+				ensure!(!Self::is_expired(order_id), Error::<T>::OrderExpired);
 
 				let amount_out_calculation = U256::from(order.amount_out)
 					.checked_mul(U256::from(amount_in))
@@ -312,6 +367,9 @@ pub mod pallet {
 		/// Parameters:
 		/// - `order_id`: ID of the order
 		///
+		/// Validations:
+		/// - order must not be expired
+		///
 		/// Events:
 		/// `Filled` event when successful.
 		#[pallet::call_index(2)]
@@ -319,11 +377,13 @@ pub mod pallet {
 		pub fn fill_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(!Self::is_expired(order_id), Error::<T>::OrderExpired);
 
 			let fee = Self::calculate_fee(order.amount_out);
 
 			Self::execute_order(&order, &who, order.amount_in, order.amount_out, fee)?;
 			<Orders<T>>::remove(order_id);
+			Self::remove_expiration(order_id);
 
 			Self::deposit_event(Event::Filled {
 				order_id,
@@ -359,6 +419,7 @@ pub mod pallet {
 					T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
 				ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
 				*maybe_order = None;
+				Self::remove_expiration(order_id);
 
 				Self::deposit_event(Event::Cancelled { order_id });
 				Ok(())
@@ -403,4 +464,92 @@ impl<T: Config> Pallet<T> {
 	pub fn calculate_fee(amount: Balance) -> Balance {
 		T::Fee::get().mul_ceil(amount)
 	}
+
+	/// Returns true if the order was placed with an expiry which has been reached.
+	pub fn is_expired(order_id: OrderId) -> bool {
+		Self::is_expired_at(order_id, frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Returns true if the order was placed with an expiry which is reached at given block.
+	pub fn is_expired_at(order_id: OrderId, block_number: BlockNumberFor<T>) -> bool {
+		<OrderExpiration<T>>::get(order_id)
+			.map(|expiry| block_number >= expiry)
+			.unwrap_or(false)
+	}
+
+	fn remove_expiration(order_id: OrderId) {
+		if let Some(expiry) = <OrderExpiration<T>>::take(order_id) {
+			<ExpiringOrders<T>>::remove(expiry, order_id);
+		}
+	}
+
+	/// Cancel orders which expired up to and including block `now`.
+	/// Blocks are processed in order, starting with `NextExpiryCheck`, as long as there is weight left.
+	/// `NextExpiryCheck` is cleared once there are no orders left to expire.
+	fn cancel_expired_orders(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+		let cancel_weight = T::WeightInfo::cancel_expired_order();
+		let per_block_weight = T::DbWeight::get().reads(1);
+		// `NextExpiryCheck` read and write and the check whether any orders are left to expire
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+		if used_weight.any_gt(remaining_weight) {
+			return Weight::zero();
+		}
+
+		let Some(mut block) = <NextExpiryCheck<T>>::get() else {
+			return T::DbWeight::get().reads(1);
+		};
+
+		while block <= now {
+			let Some(weight_left) = remaining_weight
+				.checked_sub(&used_weight)
+				.and_then(|w| w.checked_sub(&per_block_weight))
+			else {
+				break;
+			};
+			used_weight.saturating_accrue(per_block_weight);
+
+			let max_cancels = if cancel_weight.ref_time().is_zero() {
+				u64::MAX
+			} else {
+				weight_left.ref_time() / cancel_weight.ref_time()
+			};
+			// One more than we can process, to find out if the block has been fully processed.
+			let expired: Vec<OrderId> = <ExpiringOrders<T>>::iter_key_prefix(block)
+				.take(max_cancels.saturating_add(1) as usize)
+				.collect();
+			let block_done = (expired.len() as u64) <= max_cancels;
+
+			for order_id in expired.into_iter().take(max_cancels as usize) {
+				Self::cancel_expired_order(block, order_id);
+				used_weight.saturating_accrue(cancel_weight);
+			}
+
+			if !block_done {
+				break;
+			}
+			block.saturating_inc();
+		}
+
+		if <ExpiringOrders<T>>::iter_keys().next().is_none() {
+			<NextExpiryCheck<T>>::kill();
+		} else {
+			<NextExpiryCheck<T>>::put(block);
+		}
+		used_weight
+	}
+
+	pub(crate) fn cancel_expired_order(expiry: BlockNumberFor<T>, order_id: OrderId) {
+		<ExpiringOrders<T>>::remove(expiry, order_id);
+		<OrderExpiration<T>>::remove(order_id);
+
+		if let Some(order) = <Orders<T>>::take(order_id) {
+			let remaining_to_unreserve =
+				T::Currency::unreserve_named(&NAMED_RESERVE_ID, order.asset_out, &order.owner, order.amount_out);
+			if !remaining_to_unreserve.is_zero() {
+				frame_support::defensive!("Reserved amount of expired order not sufficient");
+			}
+
+			Self::deposit_event(Event::Expired { order_id });
+		}
+	}
 }
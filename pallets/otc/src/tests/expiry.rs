@@ -0,0 +1,251 @@
+// This file is part of galacticcouncil/warehouse.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as otc;
+use crate::tests::mock::*;
+use crate::{Error, Event, ExpiringOrders, NextExpiryCheck, OrderExpiration};
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn place_order_expiring_at(expires_at: Option<u64>) {
+	assert_ok!(OTC::place_order(
+		RuntimeOrigin::signed(ALICE),
+		DAI,
+		HDX,
+		20 * ONE,
+		100 * ONE,
+		true,
+		expires_at
+	));
+}
+
+#[test]
+fn place_order_should_store_expiry_when_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_order_expiring_at(Some(10));
+
+		// Assert
+		assert_eq!(OrderExpiration::<Test>::get(0), Some(10));
+		assert!(ExpiringOrders::<Test>::contains_key(10, 0));
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(10));
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			asset_in: DAI,
+			asset_out: HDX,
+			amount_in: 20 * ONE,
+			amount_out: 100 * ONE,
+			partially_fillable: true,
+			expires_at: Some(10),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_not_store_expiry_when_not_set() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_order_expiring_at(None);
+
+		// Assert
+		assert_eq!(OrderExpiration::<Test>::get(0), None);
+		assert_eq!(NextExpiryCheck::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_expiry_is_not_in_future() {
+	ExtBuilder::default().build().execute_with(|| {
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::place_order(
+				RuntimeOrigin::signed(ALICE),
+				DAI,
+				HDX,
+				20 * ONE,
+				100 * ONE,
+				true,
+				Some(10)
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_fail_when_order_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+		System::set_block_number(10);
+
+		// Act & Assert
+		assert_noop!(
+			OTC::fill_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::OrderExpired
+		);
+		assert_noop!(
+			OTC::partial_fill_order(RuntimeOrigin::signed(BOB), 0, 10 * ONE),
+			Error::<Test>::OrderExpired
+		);
+	});
+}
+
+#[test]
+fn fill_order_should_work_when_order_not_yet_expired() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+		System::set_block_number(9);
+
+		// Act
+		assert_ok!(OTC::fill_order(RuntimeOrigin::signed(BOB), 0));
+
+		// Assert
+		assert_eq!(OrderExpiration::<Test>::get(0), None);
+		assert!(!ExpiringOrders::<Test>::contains_key(10, 0));
+	});
+}
+
+#[test]
+fn cancel_order_should_remove_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+
+		// Act
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(OrderExpiration::<Test>::get(0), None);
+		assert!(!ExpiringOrders::<Test>::contains_key(10, 0));
+	});
+}
+
+#[test]
+fn on_idle_should_cancel_expired_orders() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(5));
+		place_order_expiring_at(Some(10));
+		place_order_expiring_at(None);
+		System::set_block_number(7);
+
+		// Act
+		OTC::on_idle(7, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_some());
+		assert!(OTC::orders(2).is_some());
+		assert_eq!(OrderExpiration::<Test>::get(0), None);
+		assert!(!ExpiringOrders::<Test>::contains_key(5, 0));
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(8));
+		assert_eq!(
+			Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE),
+			200 * ONE
+		);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_cancel_orders_expired_in_previous_blocks() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(5));
+		place_order_expiring_at(Some(10));
+		System::set_block_number(20);
+
+		// Act
+		OTC::on_idle(20, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_none());
+		assert!(OTC::orders(1).is_none());
+		assert_eq!(Tokens::reserved_balance_named(&otc::NAMED_RESERVE_ID, HDX, &ALICE), 0);
+		assert_eq!(NextExpiryCheck::<Test>::get(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_not_cancel_orders_when_weight_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(5));
+		System::set_block_number(7);
+
+		// Act
+		let used_weight = OTC::on_idle(7, Weight::zero());
+
+		// Assert
+		assert_eq!(used_weight, Weight::zero());
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn place_order_should_set_next_expiry_check_to_earliest_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		place_order_expiring_at(Some(10));
+		place_order_expiring_at(Some(5));
+		place_order_expiring_at(Some(20));
+
+		// Assert
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn on_idle_should_not_advance_next_expiry_check_before_earliest_expiry() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+		System::set_block_number(7);
+
+		// Act
+		OTC::on_idle(7, Weight::MAX);
+
+		// Assert
+		assert!(OTC::orders(0).is_some());
+		assert_eq!(NextExpiryCheck::<Test>::get(), Some(10));
+	});
+}
+
+#[test]
+fn on_idle_should_clear_next_expiry_check_when_no_orders_expire() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order_expiring_at(Some(10));
+		assert_ok!(OTC::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+		System::set_block_number(2);
+
+		// Act
+		OTC::on_idle(2, Weight::MAX);
+
+		// Assert
+		assert_eq!(NextExpiryCheck::<Test>::get(), None);
+	});
+}
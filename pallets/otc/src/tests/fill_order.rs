@@ -32,7 +32,8 @@ fn complete_fill_order_should_work() {
 			HDX,
 			amount_in,
 			amount_out,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -94,7 +95,8 @@ fn complete_fill_order_should_work_when_order_is_not_partially_fillable() {
 			HDX,
 			amount_in,
 			amount_out,
-			false
+			false,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -156,7 +158,8 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			amount_in,
 			amount_out,
-			true
+			true,
+			None
 		));
 
 		assert_ok!(OTC::place_order(
@@ -165,7 +168,8 @@ fn complete_fill_order_should_work_when_there_are_multiple_orders() {
 			HDX,
 			10 * ONE,
 			50 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -228,7 +232,8 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 			HDX,
 			200 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -30,7 +30,8 @@ fn partial_fill_order_should_work_when_order_is_partially_fillable() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -104,7 +105,8 @@ fn partial_fill_order_should_throw_error_when_order_is_not_partially_fillable()
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			false
+			false,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -150,7 +152,8 @@ fn partial_fill_order_should_throw_error_when_fill_is_complete() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -196,7 +199,8 @@ fn partial_fill_order_should_throw_error_when_remaining_amounts_are_too_low() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -242,7 +246,8 @@ fn fill_order_should_throw_error_when_insufficient_balance() {
 			HDX,
 			200 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
@@ -288,7 +293,8 @@ fn partial_fill_order_should_throw_error_when_amount_is_larger_than_order() {
 			HDX,
 			20 * ONE,
 			100 * ONE,
-			true
+			true,
+			None
 		));
 
 		let alice_free_hdx_balance_before = Tokens::free_balance(HDX, &ALICE);
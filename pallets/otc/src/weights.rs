@@ -54,6 +54,7 @@ pub trait WeightInfo {
 	fn partial_fill_order() -> Weight;
 	fn fill_order() -> Weight;
 	fn cancel_order() -> Weight;
+	fn cancel_expired_order() -> Weight;
 }
 
 /// Weights for pallet_otc using the hydraDX node and recommended hardware.
@@ -152,4 +153,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Expired order is cancelled with the same storage access as `cancel_order`, without the owner check.
+	fn cancel_expired_order() -> Weight {
+		Self::cancel_order()
+	}
 }
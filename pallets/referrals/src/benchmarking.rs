@@ -57,6 +57,53 @@ benchmarks! {
 		assert_eq!(entry, Some(caller));
 	}
 
+	register_sub_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let user: T::AccountId = account("user", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let sub_code: ReferralCode<T::CodeLength> = vec![b'y'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		T::Currency::mint_into(asset, &user, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		let parent_share = T::MaxParentShare::get();
+
+		// worst case - the parent code is at the max depth allowing a sub-code
+		let mut parent_code = code;
+		for i in 1..T::MaxSubCodeDepth::get() {
+			let owner: T::AccountId = account("owner", i, 1);
+			let owner_code: ReferralCode<T::CodeLength> = vec![b'a' + i as u8; T::CodeLength::get() as usize].try_into().unwrap();
+			T::Currency::mint_into(asset, &owner, 2 * fee)?;
+			Pallet::<T>::register_sub_code(RawOrigin::Signed(owner).into(), owner_code.clone(), parent_code, parent_share)?;
+			parent_code = owner_code;
+		}
+	}: _(RawOrigin::Signed(user.clone()), sub_code.clone(), parent_code.clone(), parent_share)
+	verify {
+		let c = Pallet::<T>::normalize_code(sub_code);
+		let entry = Pallet::<T>::referral_account(c.clone());
+		assert_eq!(entry, Some(user));
+		let entry = Pallet::<T>::parent_code(c);
+		assert_eq!(entry, Some((Pallet::<T>::normalize_code(parent_code), parent_share)));
+	}
+
+	transfer_code{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let user: T::AccountId = account("user", 0, 1);
+		let code: ReferralCode<T::CodeLength> = vec![b'x'; T::CodeLength::get() as usize].try_into().unwrap();
+		let (asset, fee, _) = T::RegistrationFee::get();
+		T::Currency::mint_into(asset, &caller, 2 * fee)?;
+		Pallet::<T>::register_code(RawOrigin::Signed(caller.clone()).into(), code.clone())?;
+		ReferrerShares::<T>::insert(caller.clone(), 1_000_000_000_000);
+	}: _(RawOrigin::Signed(caller.clone()), user.clone())
+	verify {
+		let c = Pallet::<T>::normalize_code(code);
+		let entry = Pallet::<T>::referral_account(c.clone());
+		assert_eq!(entry, Some(user.clone()));
+		let entry = Pallet::<T>::transferred_code(caller);
+		assert_eq!(entry, Some(c));
+		assert_eq!(ReferrerShares::<T>::get(user), 1_000_000_000_000);
+	}
+
 	convert{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let (asset_id, amount) = T::BenchmarkHelper::prepare_convertible_asset_and_amount();
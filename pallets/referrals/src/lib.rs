@@ -29,10 +29,18 @@
 //!
 //! Rewards are accumulated in the pallet's account and if it is not RewardAsset, it is converted to RewardAsset prior to claim.
 //!
+//! Account can register its code as a sub-code of another code. Part of the referrer rewards generated by a sub-code flows
+//! to the owner of the parent code, up to `MaxSubCodeDepth` levels of parent codes. The share of the parent is chosen by
+//! the owner of the sub-code and is limited by `MaxParentShare`.
+//!
+//! Referral code can be transferred to another account together with the referrer level and unclaimed referrer rewards.
+//! Accounts linked to the previous owner reward the new owner of the code.
+//!
 //! ### Terminology
 //!
 //! * **Referral code:**  a string of certain size that identifies the referrer. Must be alphanumeric and upper case.
 //! * **Referrer:**  user that registered a code
+//! * **Sub-code:**  a referral code registered under a parent code
 //! * **Trader:**  user that does a trade
 //! * **Reward Asset:**  id of an asset which rewards are paid in. Usually native asset.
 //!
@@ -210,6 +218,14 @@ pub mod pallet {
 		#[pallet::constant]
 		type SeedNativeAmount: Get<u128>;
 
+		/// Maximum number of parent codes above a sub-code.
+		#[pallet::constant]
+		type MaxSubCodeDepth: Get<u32>;
+
+		/// Maximum share of the referrer rewards of a sub-code that flows to the owner of the parent code.
+		#[pallet::constant]
+		type MaxParentShare: Get<Permill>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -237,6 +253,20 @@ pub mod pallet {
 	#[pallet::getter(fn linked_referral_account)]
 	pub(super) type LinkedAccounts<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// Parent codes
+	/// Maps a sub-code to its parent code and the share of the referrer rewards that flows to the parent.
+	#[pallet::storage]
+	#[pallet::getter(fn parent_code)]
+	pub(super) type ParentCodes<T: Config> =
+		StorageMap<_, Blake2_128Concat, ReferralCode<T::CodeLength>, (ReferralCode<T::CodeLength>, Permill)>;
+
+	/// Transferred codes
+	/// Maps a previous owner of a code to the code, so accounts linked to the previous owner reward the current owner.
+	#[pallet::storage]
+	#[pallet::getter(fn transferred_code)]
+	pub(super) type TransferredCodes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ReferralCode<T::CodeLength>>;
+
 	/// Shares of a referral account
 	#[pallet::storage]
 	#[pallet::getter(fn referrer_shares)]
@@ -305,6 +335,19 @@ pub mod pallet {
 		},
 		/// Referrer reached new level.
 		LevelUp { who: T::AccountId, level: Level },
+		/// Sub-code has been registered.
+		SubCodeRegistered {
+			code: ReferralCode<T::CodeLength>,
+			account: T::AccountId,
+			parent_code: ReferralCode<T::CodeLength>,
+			parent_share: Permill,
+		},
+		/// Referral code has been transferred to another account.
+		CodeTransferred {
+			code: ReferralCode<T::CodeLength>,
+			from: T::AccountId,
+			to: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -338,6 +381,16 @@ pub mod pallet {
 		ConversionMinTradingAmountNotReached,
 		/// Zero amount received from conversion.
 		ConversionZeroAmountReceived,
+		/// The account has no code registered.
+		NotRegistered,
+		/// The account has transferred its code and can't own a different one.
+		CodeAlreadyTransferred,
+		/// Transferring a code to the same account is not allowed.
+		TransferNotAllowed,
+		/// Maximum depth of sub-codes has been reached.
+		MaxSubCodeDepthReached,
+		/// Parent share exceeds `MaxParentShare`.
+		ParentShareTooHigh,
 	}
 
 	#[pallet::call]
@@ -359,35 +412,9 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::register_code())]
 		pub fn register_code(origin: OriginFor<T>, code: ReferralCode<T::CodeLength>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			ensure!(
-				ReferralAccounts::<T>::get(&who).is_none(),
-				Error::<T>::AlreadyRegistered
-			);
-
-			ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::TooShort);
-
-			ensure!(
-				code.clone()
-					.into_inner()
-					.iter()
-					.all(|c| char::is_alphanumeric(*c as char)),
-				Error::<T>::InvalidCharacter
-			);
-
-			let code = Self::normalize_code(code);
-
-			ReferralCodes::<T>::mutate(code.clone(), |v| -> DispatchResult {
-				ensure!(v.is_none(), Error::<T>::AlreadyExists);
-
-				let (fee_asset, fee_amount, beneficiary) = T::RegistrationFee::get();
-				T::Currency::transfer(fee_asset, &who, &beneficiary, fee_amount, Preservation::Preserve)?;
-
-				*v = Some(who.clone());
-				Referrer::<T>::insert(&who, (Level::default(), Balance::zero()));
-				ReferralAccounts::<T>::insert(&who, code.clone());
-				Self::deposit_event(Event::CodeRegistered { code, account: who });
-				Ok(())
-			})
+			let code = Self::do_register_code(&who, code)?;
+			Self::deposit_event(Event::CodeRegistered { code, account: who });
+			Ok(())
 		}
 
 		/// Link a code to an account.
@@ -461,6 +488,7 @@ pub mod pallet {
 		/// Reward amount is calculated based on the shares of the signer account.
 		///
 		/// if the signer account is referrer account, total accumulated rewards is updated as well as referrer level if reached.
+		/// Referrer rewards include the shares received from sub-codes.
 		///
 		/// Emits `Claimed` event when successful.
 		#[pallet::call_index(3)]
@@ -597,6 +625,94 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Register new referral code as a sub-code of an existing code.
+		///
+		/// `origin` pays the registration fee and `code` is assigned to it.
+		///
+		/// `parent_share` of the referrer rewards generated by the sub-code flows to the owner of the parent code.
+		/// The same applies to the parent code if it is a sub-code too, up to `T::MaxSubCodeDepth` levels.
+		///
+		/// `code` must follow the same restrictions as in `register_code`.
+		///
+		/// Parameters:
+		/// - `code`: Code to register. Must follow the restrictions.
+		/// - `parent_code`: Registered code the sub-code is registered under.
+		/// - `parent_share`: Share of the referrer rewards that flows to the owner of the parent code.
+		/// Must not exceed `T::MaxParentShare`.
+		///
+		/// Emits `SubCodeRegistered` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_sub_code())]
+		pub fn register_sub_code(
+			origin: OriginFor<T>,
+			code: ReferralCode<T::CodeLength>,
+			parent_code: ReferralCode<T::CodeLength>,
+			parent_share: Permill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(parent_share <= T::MaxParentShare::get(), Error::<T>::ParentShareTooHigh);
+
+			let parent_code = Self::normalize_code(parent_code);
+			ensure!(Self::referral_account(&parent_code).is_some(), Error::<T>::InvalidCode);
+			ensure!(
+				Self::code_depth(&parent_code) < T::MaxSubCodeDepth::get(),
+				Error::<T>::MaxSubCodeDepthReached
+			);
+
+			let code = Self::do_register_code(&who, code)?;
+			ParentCodes::<T>::insert(&code, (parent_code.clone(), parent_share));
+
+			Self::deposit_event(Event::SubCodeRegistered {
+				code,
+				account: who,
+				parent_code,
+				parent_share,
+			});
+			Ok(())
+		}
+
+		/// Transfer the code of the signer account to another account.
+		///
+		/// Referrer level, total accumulated rewards and unclaimed referrer rewards are transferred too.
+		/// Accounts linked to the signer account reward the new owner of the code.
+		///
+		/// `to` must not have a code registered.
+		///
+		/// Parameters:
+		/// - `to`: New owner of the code.
+		///
+		/// Emits `CodeTransferred` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_code())]
+		pub fn transfer_code(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(who != to, Error::<T>::TransferNotAllowed);
+
+			let code = ReferralAccounts::<T>::take(&who).ok_or(Error::<T>::NotRegistered)?;
+			ensure!(ReferralAccounts::<T>::get(&to).is_none(), Error::<T>::AlreadyRegistered);
+			// The new owner can get back only the code it has transferred before.
+			if let Some(transferred) = TransferredCodes::<T>::take(&to) {
+				ensure!(transferred == code, Error::<T>::CodeAlreadyTransferred);
+			}
+
+			ReferralCodes::<T>::insert(&code, &to);
+			ReferralAccounts::<T>::insert(&to, &code);
+			TransferredCodes::<T>::insert(&who, &code);
+
+			if let Some(entry) = Referrer::<T>::take(&who) {
+				Referrer::<T>::insert(&to, entry);
+			}
+			let shares = ReferrerShares::<T>::take(&who);
+			if !shares.is_zero() {
+				ReferrerShares::<T>::mutate(&to, |v| {
+					*v = v.saturating_add(shares);
+				});
+			}
+
+			Self::deposit_event(Event::CodeTransferred { code, from: who, to });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -635,6 +751,102 @@ impl<T: Config> Pallet<T> {
 		ReferralCode::<T::CodeLength>::truncate_from(r)
 	}
 
+	/// Validate and register `code` for `account`. Registration fee is paid by `account`.
+	///
+	/// Returns normalized code on success.
+	fn do_register_code(
+		account: &T::AccountId,
+		code: ReferralCode<T::CodeLength>,
+	) -> Result<ReferralCode<T::CodeLength>, DispatchError> {
+		ensure!(
+			ReferralAccounts::<T>::get(account).is_none(),
+			Error::<T>::AlreadyRegistered
+		);
+		ensure!(
+			!TransferredCodes::<T>::contains_key(account),
+			Error::<T>::CodeAlreadyTransferred
+		);
+
+		ensure!(code.len() >= T::MinCodeLength::get() as usize, Error::<T>::TooShort);
+
+		ensure!(
+			code.clone()
+				.into_inner()
+				.iter()
+				.all(|c| char::is_alphanumeric(*c as char)),
+			Error::<T>::InvalidCharacter
+		);
+
+		let code = Self::normalize_code(code);
+
+		ReferralCodes::<T>::mutate(code.clone(), |v| -> DispatchResult {
+			ensure!(v.is_none(), Error::<T>::AlreadyExists);
+
+			let (fee_asset, fee_amount, beneficiary) = T::RegistrationFee::get();
+			T::Currency::transfer(fee_asset, account, &beneficiary, fee_amount, Preservation::Preserve)?;
+
+			*v = Some(account.clone());
+			Referrer::<T>::insert(account, (Level::default(), Balance::zero()));
+			ReferralAccounts::<T>::insert(account, code.clone());
+			Ok(())
+		})?;
+
+		Ok(code)
+	}
+
+	/// Number of parent codes above the given code.
+	fn code_depth(code: &ReferralCode<T::CodeLength>) -> u32 {
+		let mut depth = 0u32;
+		let mut current = code.clone();
+		while let Some((parent, _)) = Self::parent_code(&current) {
+			depth = depth.saturating_add(1);
+			if depth >= T::MaxSubCodeDepth::get() {
+				break;
+			}
+			current = parent;
+		}
+		depth
+	}
+
+	/// Current referrer of an account linked to `linked_account`.
+	/// If `linked_account` has transferred its code, the current owner of the code is returned.
+	fn current_referrer(linked_account: T::AccountId) -> Option<T::AccountId> {
+		match Self::transferred_code(&linked_account) {
+			Some(code) => Self::referral_account(code),
+			None => Some(linked_account),
+		}
+	}
+
+	/// Distribute referrer shares across the chain of parent codes of the referrer code.
+	/// Each owner keeps its shares minus the parent share of the code, which flows to the owner of the parent code.
+	fn distribute_referrer_shares(referrer: T::AccountId, shares: Balance) {
+		let mut account = referrer;
+		let mut remaining = shares;
+		let mut code = Self::referral_code(&account);
+
+		for _ in 0..T::MaxSubCodeDepth::get() {
+			let Some((parent_code, parent_share)) = code.and_then(Self::parent_code) else {
+				break;
+			};
+			let Some(parent_account) = Self::referral_account(&parent_code) else {
+				break;
+			};
+
+			let parent_shares = parent_share.mul_floor(remaining);
+			ReferrerShares::<T>::mutate(&account, |v| {
+				*v = v.saturating_add(remaining.saturating_sub(parent_shares));
+			});
+
+			account = parent_account;
+			remaining = parent_shares;
+			code = Some(parent_code);
+		}
+
+		ReferrerShares::<T>::mutate(account, |v| {
+			*v = v.saturating_add(remaining);
+		});
+	}
+
 	/// Process trader fee
 	/// `source`: account to take the fee from
 	/// `trader`: account that does the trade
@@ -652,7 +864,11 @@ impl<T: Config> Pallet<T> {
 			return Ok(Balance::zero());
 		};
 
-		let (level, ref_account) = if let Some(acc) = Self::linked_referral_account(&trader) {
+		let ref_account = Self::linked_referral_account(&trader)
+			.and_then(Self::current_referrer)
+			.filter(|acc| *acc != trader);
+
+		let (level, ref_account) = if let Some(acc) = ref_account {
 			if let Some((level, _)) = Self::referrer_level(&acc) {
 				// Should not really happen, the ref entry should be always there.
 				(level, Some(acc))
@@ -717,9 +933,7 @@ impl<T: Config> Pallet<T> {
 		});
 
 		if let Some(acc) = ref_account {
-			ReferrerShares::<T>::mutate(acc, |v| {
-				*v = v.saturating_add(referrer_shares);
-			});
+			Self::distribute_referrer_shares(acc, referrer_shares);
 		}
 
 		// don't store zero values
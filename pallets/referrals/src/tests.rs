@@ -21,8 +21,10 @@ mod flow;
 mod link;
 mod mock_amm;
 mod register;
+mod sub_codes;
 mod tiers;
 mod trade_fee;
+mod transfer;
 
 use crate as pallet_referrals;
 use crate::*;
@@ -89,6 +91,8 @@ parameter_types! {
 	pub const MinCodeLength: u32 = 4;
 	pub const RegistrationFee: (AssetId,Balance, AccountId) = (HDX, 222 * 1_000_000_000_000, TREASURY) ;
 	pub const RewardAsset: AssetId = HDX;
+	pub const MaxSubCodeDepth: u32 = 2;
+	pub const MaxParentShare: Permill = Permill::from_percent(50);
 }
 
 pub struct LevelVolumeAndRewards;
@@ -142,6 +146,8 @@ impl Config for Test {
 	type LevelVolumeAndRewardPercentages = LevelVolumeAndRewards;
 	type ExternalAccount = ExtAccount;
 	type SeedNativeAmount = SeedAmount;
+	type MaxSubCodeDepth = MaxSubCodeDepth;
+	type MaxParentShare = MaxParentShare;
 	type WeightInfo = ();
 
 	#[cfg(feature = "runtime-benchmarks")]
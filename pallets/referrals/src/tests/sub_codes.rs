@@ -0,0 +1,317 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+const DAVE: AccountId = 4;
+
+fn default_tiers() -> Vec<(AssetId, Level, FeeDistribution)> {
+	vec![(
+		DAI,
+		Level::Tier0,
+		FeeDistribution {
+			referrer: Permill::from_percent(50),
+			trader: Permill::from_percent(0),
+			external: Permill::from_percent(0),
+		},
+	)]
+}
+
+#[test]
+fn register_sub_code_should_work_when_parent_code_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"child".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			// Act
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				b"parent".to_vec().try_into().unwrap(),
+				Permill::from_percent(20),
+			));
+			// Assert
+			let sub_code = Referrals::normalize_code(sub_code);
+			assert_eq!(Referrals::referral_account(sub_code.clone()), Some(CHARLIE));
+			assert_eq!(Referrals::referral_code(CHARLIE), Some(sub_code.clone()));
+			assert_eq!(Referrals::referrer_level(CHARLIE), Some((Level::Tier0, 0)));
+			assert_eq!(
+				Referrals::parent_code(sub_code),
+				Some((code, Permill::from_percent(20)))
+			);
+		});
+}
+
+#[test]
+fn register_sub_code_should_charge_the_signer() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			// Act
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code,
+				code,
+				Permill::from_percent(20),
+			));
+			// Assert
+			let (fee_asset, amount, beneficiary) = RegistrationFee::get();
+			assert_eq!(Tokens::free_balance(fee_asset, &ALICE), INITIAL_ALICE_BALANCE - amount);
+			assert_eq!(
+				Tokens::free_balance(fee_asset, &CHARLIE),
+				INITIAL_ALICE_BALANCE - amount
+			);
+			assert_eq!(Tokens::free_balance(fee_asset, &beneficiary), 2 * amount);
+		});
+}
+
+#[test]
+fn register_sub_code_should_emit_event() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			// Act
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				code.clone(),
+				Permill::from_percent(20),
+			));
+			// Assert
+			expect_events(vec![Event::SubCodeRegistered {
+				code: sub_code,
+				account: CHARLIE,
+				parent_code: code,
+				parent_share: Permill::from_percent(20),
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn register_sub_code_should_fail_when_parent_code_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_noop!(
+				Referrals::register_sub_code(
+					RuntimeOrigin::signed(CHARLIE),
+					sub_code,
+					code,
+					Permill::from_percent(20)
+				),
+				Error::<Test>::InvalidCode
+			);
+		});
+}
+
+#[test]
+fn register_sub_code_should_fail_when_parent_share_is_too_high() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			// Act & Assert
+			assert_noop!(
+				Referrals::register_sub_code(
+					RuntimeOrigin::signed(CHARLIE),
+					sub_code.clone(),
+					code.clone(),
+					Permill::from_percent(51)
+				),
+				Error::<Test>::ParentShareTooHigh
+			);
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code,
+				code,
+				MaxParentShare::get(),
+			));
+		});
+}
+
+#[test]
+fn register_sub_code_should_fail_when_signer_already_has_code() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let other_code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(CHARLIE), other_code));
+			// Act
+			assert_noop!(
+				Referrals::register_sub_code(
+					RuntimeOrigin::signed(CHARLIE),
+					sub_code,
+					code,
+					Permill::from_percent(20)
+				),
+				Error::<Test>::AlreadyRegistered
+			);
+		});
+}
+
+#[test]
+fn register_sub_code_should_fail_when_max_depth_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			let sub_sub_code: ReferralCode<<Test as Config>::CodeLength> = b"GRANDCHILD".to_vec().try_into().unwrap();
+			let too_deep_code: ReferralCode<<Test as Config>::CodeLength> = b"TOODEEP".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				code,
+				Permill::from_percent(20),
+			));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(BOB),
+				sub_sub_code.clone(),
+				sub_code,
+				Permill::from_percent(20),
+			));
+			// Act
+			assert_noop!(
+				Referrals::register_sub_code(
+					RuntimeOrigin::signed(DAVE),
+					too_deep_code,
+					sub_sub_code,
+					Permill::from_percent(20)
+				),
+				Error::<Test>::MaxSubCodeDepthReached
+			);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_split_referrer_shares_with_parent() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, DAI, 2_000_000_000_000_000_000),
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_tiers())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				code,
+				Permill::from_percent(20),
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), sub_code));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 4_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 1_000_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 5_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_split_referrer_shares_across_all_parents() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+			(BOB, HDX, INITIAL_ALICE_BALANCE),
+			(DAVE, DAI, 2_000_000_000_000_000_000),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_tiers())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			let sub_sub_code: ReferralCode<<Test as Config>::CodeLength> = b"GRANDCHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				code,
+				Permill::from_percent(50),
+			));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(BOB),
+				sub_sub_code.clone(),
+				sub_code,
+				Permill::from_percent(20),
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(DAVE), sub_sub_code));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(DAVE), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 4_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 500_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 500_000_000);
+			assert_eq!(TotalShares::<Test>::get(), 5_000_000_000);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_split_referrer_shares_when_parent_share_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, DAI, 2_000_000_000_000_000_000),
+			(CHARLIE, HDX, INITIAL_ALICE_BALANCE),
+		])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(default_tiers())
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"PARENT".to_vec().try_into().unwrap();
+			let sub_code: ReferralCode<<Test as Config>::CodeLength> = b"CHILD".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::register_sub_code(
+				RuntimeOrigin::signed(CHARLIE),
+				sub_code.clone(),
+				code,
+				Permill::from_percent(0),
+			));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), sub_code));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 5_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+		});
+}
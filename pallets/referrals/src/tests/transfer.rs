@@ -0,0 +1,211 @@
+use crate::tests::*;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn transfer_code_should_move_code_to_new_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Act
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// Assert
+		assert_eq!(Referrals::referral_account(code.clone()), Some(CHARLIE));
+		assert_eq!(Referrals::referral_code(CHARLIE), Some(code.clone()));
+		assert_eq!(Referrals::referral_code(ALICE), None);
+		assert_eq!(Referrals::transferred_code(ALICE), Some(code));
+	});
+}
+
+#[test]
+fn transfer_code_should_move_level_and_shares_to_new_owner() {
+	ExtBuilder::default()
+		.with_referrer_shares(vec![(ALICE, 5_000_000_000)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			Referrer::<Test>::insert(ALICE, (Level::Tier2, 1_000_000_000_000));
+			// Act
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+			// Assert
+			assert_eq!(
+				Referrals::referrer_level(CHARLIE),
+				Some((Level::Tier2, 1_000_000_000_000))
+			);
+			assert_eq!(Referrals::referrer_level(ALICE), None);
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 5_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+		});
+}
+
+#[test]
+fn transfer_code_should_emit_event() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		// Act
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// Assert
+		expect_events(vec![Event::CodeTransferred {
+			code,
+			from: ALICE,
+			to: CHARLIE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_signer_has_no_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE),
+			Error::<Test>::NotRegistered
+		);
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_transferring_to_self() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		// Act
+		assert_noop!(
+			Referrals::transfer_code(RuntimeOrigin::signed(ALICE), ALICE),
+			Error::<Test>::TransferNotAllowed
+		);
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_new_owner_has_code() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			let other_code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(CHARLIE), other_code));
+			// Act
+			assert_noop!(
+				Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE),
+				Error::<Test>::AlreadyRegistered
+			);
+		});
+}
+
+#[test]
+fn register_code_should_fail_when_signer_has_transferred_code() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		let other_code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// Act
+		assert_noop!(
+			Referrals::register_code(RuntimeOrigin::signed(ALICE), other_code),
+			Error::<Test>::CodeAlreadyTransferred
+		);
+	});
+}
+
+#[test]
+fn transfer_code_should_fail_when_new_owner_has_transferred_different_code() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(CHARLIE, HDX, INITIAL_ALICE_BALANCE)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			let other_code: ReferralCode<<Test as Config>::CodeLength> = b"OTHER".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code));
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(CHARLIE), other_code));
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(CHARLIE), BOB));
+			// Act
+			assert_noop!(
+				Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE),
+				Error::<Test>::CodeAlreadyTransferred
+			);
+		});
+}
+
+#[test]
+fn transfer_code_should_work_when_code_is_transferred_back() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+		assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+		// Act
+		assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(CHARLIE), ALICE));
+		// Assert
+		assert_eq!(Referrals::referral_account(code.clone()), Some(ALICE));
+		assert_eq!(Referrals::transferred_code(ALICE), None);
+		assert_eq!(Referrals::transferred_code(CHARLIE), Some(code));
+	});
+}
+
+#[test]
+fn process_trade_fee_should_reward_new_owner_when_code_is_transferred() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::from_percent(0),
+				external: Permill::from_percent(0),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), CHARLIE));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(CHARLIE), 5_000_000_000);
+			assert_eq!(ReferrerShares::<Test>::get(ALICE), 0);
+		});
+}
+
+#[test]
+fn process_trade_fee_should_not_reward_trader_when_code_is_transferred_to_trader() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, DAI, 2_000_000_000_000_000_000)])
+		.with_conversion_price((HDX, DAI), EmaPrice::new(1_000_000_000_000, 1_000_000_000_000_000_000))
+		.with_tiers(vec![(
+			DAI,
+			Level::Tier0,
+			FeeDistribution {
+				referrer: Permill::from_percent(50),
+				trader: Permill::from_percent(0),
+				external: Permill::from_percent(0),
+			},
+		)])
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let code: ReferralCode<<Test as Config>::CodeLength> = b"BALLS69".to_vec().try_into().unwrap();
+			assert_ok!(Referrals::register_code(RuntimeOrigin::signed(ALICE), code.clone()));
+			assert_ok!(Referrals::link_code(RuntimeOrigin::signed(BOB), code));
+			assert_ok!(Referrals::transfer_code(RuntimeOrigin::signed(ALICE), BOB));
+			// Act
+			assert_ok!(MockAmm::trade(RuntimeOrigin::signed(BOB), HDX, DAI, 1_000_000_000_000,));
+			// Assert
+			assert_eq!(ReferrerShares::<Test>::get(BOB), 0);
+		});
+}
@@ -55,6 +55,8 @@ pub trait WeightInfo {
 	fn convert() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn set_reward_percentage() -> Weight;
+	fn register_sub_code() -> Weight;
+	fn transfer_code() -> Weight;
 }
 
 /// Weights for pallet_referrals using the hydraDX node and recommended hardware.
@@ -170,4 +172,44 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Referrals::ReferralAccounts` (r:1 w:1)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ParentCodes` (r:3 w:1)
+	/// Proof: `Referrals::ParentCodes` (`max_values`: None, `max_size`: Some(71), added: 2546, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::TransferredCodes` (r:1 w:0)
+	/// Proof: `Referrals::TransferredCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:2 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:0 w:1)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	fn register_sub_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `9528`
+		// Minimum execution time: 74_312_000 picoseconds.
+		Weight::from_parts(75_186_000, 9528)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `Referrals::ReferralAccounts` (r:2 w:2)
+	/// Proof: `Referrals::ReferralAccounts` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::TransferredCodes` (r:1 w:2)
+	/// Proof: `Referrals::TransferredCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferralCodes` (r:0 w:1)
+	/// Proof: `Referrals::ReferralCodes` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::Referrer` (r:1 w:2)
+	/// Proof: `Referrals::Referrer` (`max_values`: None, `max_size`: Some(65), added: 2540, mode: `MaxEncodedLen`)
+	/// Storage: `Referrals::ReferrerShares` (r:2 w:2)
+	/// Proof: `Referrals::ReferrerShares` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn transfer_code() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `455`
+		//  Estimated: `6058`
+		// Minimum execution time: 33_871_000 picoseconds.
+		Weight::from_parts(34_409_000, 6058)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
+	}
 }
@@ -0,0 +1,45 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, Trade};
+use sp_runtime::{DispatchError, FixedU128};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to quote trades executed by the router.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Calculate the amounts of every trade in the route when selling `amount_in`.
+		/// Amounts are returned in the order of the trades in the route.
+		fn calculate_sell_trade_amounts(route: Vec<Trade<AssetId>>, amount_in: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError>;
+
+		/// Calculate the amounts of every trade in the route when buying `amount_out`.
+		/// Amounts are returned in the reverse order of the trades in the route.
+		fn calculate_buy_trade_amounts(route: Vec<Trade<AssetId>>, amount_out: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError>;
+
+		/// Get the on-chain route for the asset pair. Returns the default route if no route is stored.
+		fn get_route(asset_pair: AssetPair<AssetId>) -> Vec<Trade<AssetId>>;
+
+		/// Get the spot price of the route, including the fees of the pools.
+		fn spot_price_with_fee(route: Vec<Trade<AssetId>>) -> Option<FixedU128>;
+	}
+}
@@ -0,0 +1,203 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the route executor pallet.
+//!
+//! Exposes trade quotes of the router, so clients don't need to replicate the math of the underlying AMMs.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, Trade};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::{error::ErrorObject, ErrorObjectOwned},
+};
+pub use pallet_route_executor_rpc_runtime_api::RouterApi as RouterRuntimeApi;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::{traits::Block as BlockT, FixedU128};
+
+/// Amounts of a single trade in the route.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TradeQuote {
+	pub amount_in: NumberOrHex,
+	pub amount_out: NumberOrHex,
+}
+
+#[rpc(client, server)]
+pub trait RouterQuoteApi<BlockHash, AssetId> {
+	/// Quote a sell of `amount_in` through the route. Amounts are returned in the order of the trades.
+	#[method(name = "router_quoteSell")]
+	fn quote_sell(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_in: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TradeQuote>>;
+
+	/// Quote a buy of `amount_out` through the route. Amounts are returned in the reverse order of the trades.
+	#[method(name = "router_quoteBuy")]
+	fn quote_buy(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_out: NumberOrHex,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<TradeQuote>>;
+
+	/// Get the on-chain route for the asset pair, or the default route if none is stored.
+	#[method(name = "router_getRoute")]
+	fn get_route(&self, asset_pair: AssetPair<AssetId>, at: Option<BlockHash>) -> RpcResult<Vec<Trade<AssetId>>>;
+
+	/// Get the spot price of the route including pool fees.
+	#[method(name = "router_spotPriceWithFee")]
+	fn spot_price_with_fee(&self, route: Vec<Trade<AssetId>>, at: Option<BlockHash>) -> RpcResult<Option<FixedU128>>;
+}
+
+/// Router quote RPC methods.
+pub struct RouterQuote<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> RouterQuote<C, Block> {
+	/// Create new `RouterQuote` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The router failed to calculate the trade amounts.
+	CalculationError,
+	/// The provided amount is not valid.
+	InvalidParams,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::CalculationError => 2,
+			Error::InvalidParams => 3,
+		}
+	}
+}
+
+impl<C, Block, AssetId, Balance> RouterQuoteApiServer<<Block as BlockT>::Hash, AssetId> for RouterQuote<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: RouterRuntimeApi<Block, AssetId, Balance>,
+	AssetId: Codec + Send + Sync + 'static,
+	Balance: Codec + Copy + Into<NumberOrHex> + TryFrom<NumberOrHex> + Send + Sync + 'static,
+{
+	fn quote_sell(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_in: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<TradeQuote>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let amount_in = into_balance::<Balance>(amount_in)?;
+
+		let amounts = api
+			.calculate_sell_trade_amounts(at, route, amount_in)
+			.map_err(runtime_error)?
+			.map_err(|e| calculation_error(format!("{e:?}")))?;
+
+		Ok(amounts.into_iter().map(into_quote).collect())
+	}
+
+	fn quote_buy(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		amount_out: NumberOrHex,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<TradeQuote>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let amount_out = into_balance::<Balance>(amount_out)?;
+
+		let amounts = api
+			.calculate_buy_trade_amounts(at, route, amount_out)
+			.map_err(runtime_error)?
+			.map_err(|e| calculation_error(format!("{e:?}")))?;
+
+		Ok(amounts.into_iter().map(into_quote).collect())
+	}
+
+	fn get_route(
+		&self,
+		asset_pair: AssetPair<AssetId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Trade<AssetId>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.get_route(at, asset_pair).map_err(runtime_error)
+	}
+
+	fn spot_price_with_fee(
+		&self,
+		route: Vec<Trade<AssetId>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<FixedU128>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.spot_price_with_fee(at, route).map_err(runtime_error)
+	}
+}
+
+fn into_balance<Balance: TryFrom<NumberOrHex>>(amount: NumberOrHex) -> Result<Balance, ErrorObjectOwned> {
+	amount.try_into().map_err(|_| {
+		ErrorObject::owned(
+			Error::InvalidParams.into(),
+			"Amount does not fit into the balance type.",
+			None::<()>,
+		)
+	})
+}
+
+fn into_quote<Balance: Into<NumberOrHex>>(amounts: AmountInAndOut<Balance>) -> TradeQuote {
+	TradeQuote {
+		amount_in: amounts.amount_in.into(),
+		amount_out: amounts.amount_out.into(),
+	}
+}
+
+fn runtime_error(e: impl std::fmt::Display) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		Error::RuntimeError.into(),
+		"Unable to query the router.",
+		Some(e.to_string()),
+	)
+}
+
+fn calculation_error(e: String) -> ErrorObjectOwned {
+	ErrorObject::owned(Error::CalculationError.into(), "Unable to calculate the route.", Some(e))
+}
@@ -33,14 +33,18 @@ use hydra_dx_math::support::rational::{round_u512_to_rational, Rounding};
 use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::registry::Inspect as RegistryInspect;
-use hydradx_traits::router::{inverse_route, AssetPair, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider};
+use hydradx_traits::router::{
+	inverse_route, AssetPair, PoolsProvider, RefundEdCalculator, RouteProvider, RouteSpotPriceProvider,
+};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution, WeightedRoute,
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
 use sp_core::U512;
 use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Saturating, TransactionOutcome};
+use sp_runtime::{
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128, PerThing, Permill, Saturating, TransactionOutcome,
+};
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -55,6 +59,7 @@ pub use weights::WeightInfo;
 pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+pub const MAX_NUMBER_OF_SPLITS: u32 = 4;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -89,7 +94,8 @@ pub mod pallet {
 			+ CheckedAdd
 			+ CheckedDiv
 			+ Saturating
-			+ Zero;
+			+ Zero
+			+ Into<u128>;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -126,6 +132,17 @@ pub mod pallet {
 		/// Origin able to set route without validation
 		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Provider of the pools used to discover routes between assets
+		type PoolsProvider: PoolsProvider<Self::AssetId>;
+
+		/// Max number of candidate routes evaluated when a route is discovered on-chain
+		#[pallet::constant]
+		type MaxRouteCandidates: Get<u32>;
+
+		/// Max number of pool assets read from `PoolsProvider` when a route is discovered on-chain
+		#[pallet::constant]
+		type MaxRoutePoolAssets: Get<u32>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -166,6 +183,12 @@ pub mod pallet {
 		InvalidRouteExecution,
 		/// Trading same assets is not allowed.
 		NotAllowed,
+		///No route has been found between the assets
+		RouteNotFound,
+		///The weights of the split routes are invalid, they must be non-zero and sum up to 100%
+		InvalidSplit,
+		///The max number of routes in a split trade is reached
+		MaxSplitsExceeded,
 	}
 
 	///Flag to indicate when to skip ED handling
@@ -239,58 +262,13 @@ pub mod pallet {
 			let who = ensure_signed(origin.clone())?;
 
 			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
-			Self::ensure_route_size(route.len())?;
 
-			let asset_pair = AssetPair::new(asset_in, asset_out);
-			let route = Self::get_route_or_default(route, asset_pair)?;
-			Self::ensure_route_arguments(&asset_pair, &route)?;
-
-			let user_balance_of_asset_in_before_trade =
-				T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
-
-			let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
-
-			let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
-			ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
-
-			let route_length = route.len();
-			for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
-				Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
-				let user_balance_of_asset_out_before_trade =
-					T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
-				let execution_result = T::AMM::execute_buy(
-					origin.clone(),
-					trade.pool,
-					trade.asset_in,
-					trade.asset_out,
-					trade_amount.amount_out,
-					trade_amount.amount_in,
-				);
-
-				handle_execution_error!(execution_result);
-
-				Self::ensure_that_user_received_asset_out_at_most(
-					who.clone(),
-					trade.asset_in,
-					trade.asset_out,
-					user_balance_of_asset_out_before_trade,
-					trade_amount.amount_out,
-				)?;
-			}
-
-			SkipEd::<T>::kill();
-
-			Self::ensure_that_user_spent_asset_in_at_least(
-				who,
-				asset_in,
-				user_balance_of_asset_in_before_trade,
-				first_trade.amount_in,
-			)?;
+			let amount_in = Self::buy_route(origin, who, asset_in, asset_out, amount_out, max_amount_in, route)?;
 
 			Self::deposit_event(Event::Executed {
 				asset_in,
 				asset_out,
-				amount_in: first_trade.amount_in,
+				amount_in,
 				amount_out,
 			});
 
@@ -310,6 +288,8 @@ pub mod pallet {
 		///
 		/// If the route is set successfully, then the fee is payed back.
 		///
+		/// If the new route is empty, the best route is discovered on-chain from the candidate routes across all pools.
+		///
 		/// - `origin`: The origin of the route setter
 		/// - `asset_pair`: The identifier of the asset-pair for which the route is set
 		/// - `new_route`: Series of [`Trade<AssetId>`] to be executed. A [`Trade<AssetId>`] specifies the asset pair (`asset_in`, `asset_out`) and the AMM (`pool`) in which the trade is executed.
@@ -317,9 +297,16 @@ pub mod pallet {
 		/// Emits `RouteUpdated` when successful.
 		///
 		/// Fails with `RouteUpdateIsNotSuccessful` error when failed to set the route
+		/// Fails with `RouteNotFound` error when the route is to be discovered but no route is found
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::set_route_weight(new_route))]
+		#[pallet::weight(T::WeightInfo::set_route_weight(new_route).saturating_add(
+			if new_route.is_empty() {
+				T::WeightInfo::discover_route_weight().saturating_add(T::WeightInfo::set_discovered_route_weight())
+			} else {
+				Weight::zero()
+			}
+		))]
 		#[transactional]
 		pub fn set_route(
 			origin: OriginFor<T>,
@@ -327,6 +314,10 @@ pub mod pallet {
 			mut new_route: Vec<Trade<T::AssetId>>,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin.clone())?;
+			if new_route.is_empty() {
+				new_route =
+					<Self as RouteProvider<T::AssetId>>::discover_route(asset_pair).ok_or(Error::<T>::RouteNotFound)?;
+			}
 			Self::ensure_route_size(new_route.len())?;
 			Self::ensure_route_arguments(&asset_pair, &new_route)?;
 			T::OraclePriceProvider::price(&new_route, T::OraclePeriod::get()).ok_or(Error::<T>::RouteHasNoOracle)?;
@@ -445,6 +436,132 @@ pub mod pallet {
 
 			Self::do_sell(origin, asset_in, asset_out, amount_in, min_amount_out, route)
 		}
+
+		/// Executes a sell split across several routes.
+		/// The `amount_in` is split between the routes based on their weights, which must sum up to 100%.
+		/// The last route receives the rounding remainder.
+		///
+		/// The routes are executed one after another, and the limit is checked for the aggregated amount out.
+		/// If any route fails, or the limit is not reached, the whole sell is reverted.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive.
+		/// - `routes`: The routes with their weights. An empty route means the on-chain (or default) route.
+		///
+		/// Emits `Executed` with the aggregated amounts when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Pallet::<T>::sell_split_weight(routes))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+			let amounts_in = Self::split_amount(amount_in, &routes)?;
+
+			let mut amount_out = T::Balance::zero();
+			for (split_amount_in, weighted_route) in amounts_in.into_iter().zip(routes) {
+				if split_amount_in.is_zero() {
+					continue;
+				}
+				let route_amount_out = Self::sell_route(
+					origin.clone(),
+					who.clone(),
+					asset_in,
+					asset_out,
+					split_amount_in,
+					T::Balance::zero(),
+					weighted_route.route,
+				)?;
+				amount_out = amount_out
+					.checked_add(&route_amount_out)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Executes a buy split across several routes.
+		/// The `amount_out` is split between the routes based on their weights, which must sum up to 100%.
+		/// The last route receives the rounding remainder.
+		///
+		/// The routes are executed one after another, and the limit is checked for the aggregated amount in.
+		/// If any route fails, or the limit is exceeded, the whole buy is reverted.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on the buy.
+		/// - `routes`: The routes with their weights. An empty route means the on-chain (or default) route.
+		///
+		/// Emits `Executed` with the aggregated amounts when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(Pallet::<T>::buy_split_weight(routes))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
+
+			let amounts_out = Self::split_amount(amount_out, &routes)?;
+
+			let mut amount_in = T::Balance::zero();
+			for (split_amount_out, weighted_route) in amounts_out.into_iter().zip(routes) {
+				if split_amount_out.is_zero() {
+					continue;
+				}
+				let route_amount_in = Self::buy_route(
+					origin.clone(),
+					who.clone(),
+					asset_in,
+					asset_out,
+					split_amount_out,
+					max_amount_in,
+					weighted_route.route,
+				)?;
+				amount_in = amount_in
+					.checked_add(&route_amount_in)
+					.ok_or(ArithmeticError::Overflow)?;
+			}
+
+			ensure!(amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::Executed {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
 	}
 }
 
@@ -466,6 +583,28 @@ impl<T: Config> Pallet<T> {
 
 		ensure!(asset_in != asset_out, Error::<T>::NotAllowed);
 
+		let amount_out = Self::sell_route(origin, who, asset_in, asset_out, amount_in, min_amount_out, route)?;
+
+		Self::deposit_event(Event::Executed {
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+		});
+
+		Ok(())
+	}
+
+	/// Executes the sell trades of the route, returning the amount of `asset_out` received.
+	fn sell_route(
+		origin: T::RuntimeOrigin,
+		who: T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
 		Self::ensure_route_size(route.len())?;
 
 		let asset_pair = AssetPair::new(asset_in, asset_out);
@@ -519,14 +658,120 @@ impl<T: Config> Pallet<T> {
 			last_trade_amount.amount_out,
 		)?;
 
-		Self::deposit_event(Event::Executed {
+		Ok(last_trade_amount.amount_out)
+	}
+
+	/// Executes the buy trades of the route, returning the amount of `asset_in` spent.
+	fn buy_route(
+		origin: T::RuntimeOrigin,
+		who: T::AccountId,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		route: Vec<Trade<T::AssetId>>,
+	) -> Result<T::Balance, DispatchError> {
+		Self::ensure_route_size(route.len())?;
+
+		let asset_pair = AssetPair::new(asset_in, asset_out);
+		let route = Self::get_route_or_default(route, asset_pair)?;
+		Self::ensure_route_arguments(&asset_pair, &route)?;
+
+		let user_balance_of_asset_in_before_trade =
+			T::Currency::reducible_balance(asset_in, &who, Preservation::Expendable, Fortitude::Polite);
+
+		let trade_amounts = Self::calculate_buy_trade_amounts(&route, amount_out)?;
+
+		let first_trade = trade_amounts.last().ok_or(Error::<T>::RouteCalculationFailed)?;
+		ensure!(first_trade.amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+		let route_length = route.len();
+		for (trade_index, (trade_amount, trade)) in trade_amounts.iter().rev().zip(route).enumerate() {
+			Self::disable_ed_handling_for_insufficient_assets(route_length, trade_index, trade);
+			let user_balance_of_asset_out_before_trade =
+				T::Currency::reducible_balance(trade.asset_out, &who, Preservation::Preserve, Fortitude::Polite);
+			let execution_result = T::AMM::execute_buy(
+				origin.clone(),
+				trade.pool,
+				trade.asset_in,
+				trade.asset_out,
+				trade_amount.amount_out,
+				trade_amount.amount_in,
+			);
+
+			handle_execution_error!(execution_result);
+
+			Self::ensure_that_user_received_asset_out_at_most(
+				who.clone(),
+				trade.asset_in,
+				trade.asset_out,
+				user_balance_of_asset_out_before_trade,
+				trade_amount.amount_out,
+			)?;
+		}
+
+		SkipEd::<T>::kill();
+
+		Self::ensure_that_user_spent_asset_in_at_least(
+			who,
 			asset_in,
-			asset_out,
-			amount_in,
-			amount_out: last_trade_amount.amount_out,
-		});
+			user_balance_of_asset_in_before_trade,
+			first_trade.amount_in,
+		)?;
 
-		Ok(())
+		Ok(first_trade.amount_in)
+	}
+
+	/// Splits the amount between the weighted routes.
+	/// The last route gets the remainder, so the whole amount is always distributed.
+	fn split_amount(
+		amount: T::Balance,
+		routes: &[WeightedRoute<Trade<T::AssetId>>],
+	) -> Result<Vec<T::Balance>, DispatchError> {
+		ensure!(!routes.is_empty(), Error::<T>::InvalidSplit);
+		ensure!(
+			(routes.len() as u32) <= MAX_NUMBER_OF_SPLITS,
+			Error::<T>::MaxSplitsExceeded
+		);
+
+		let total_weight = routes
+			.iter()
+			.try_fold(0u32, |acc, r| {
+				if r.weight.is_zero() {
+					None
+				} else {
+					acc.checked_add(r.weight.deconstruct())
+				}
+			})
+			.ok_or(Error::<T>::InvalidSplit)?;
+		ensure!(total_weight == Permill::one().deconstruct(), Error::<T>::InvalidSplit);
+
+		let amount: u128 = amount.into();
+		let mut remaining = amount;
+		let mut amounts = Vec::with_capacity(routes.len());
+		for (i, r) in routes.iter().enumerate() {
+			let split = if i == routes.len().saturating_sub(1) {
+				remaining
+			} else {
+				r.weight.mul_floor(amount)
+			};
+			remaining = remaining.checked_sub(split).ok_or(ArithmeticError::Underflow)?;
+			amounts.push(split.into());
+		}
+
+		Ok(amounts)
+	}
+
+	pub fn sell_split_weight(routes: &[WeightedRoute<Trade<T::AssetId>>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |acc, r| {
+			acc.saturating_add(T::WeightInfo::sell_weight(&r.route))
+		})
+	}
+
+	pub fn buy_split_weight(routes: &[WeightedRoute<Trade<T::AssetId>>]) -> Weight {
+		routes.iter().fold(Weight::zero(), |acc, r| {
+			acc.saturating_add(T::WeightInfo::buy_weight(&r.route))
+		})
 	}
 
 	fn ensure_route_size(route_length: usize) -> Result<(), DispatchError> {
@@ -687,6 +932,7 @@ impl<T: Config> Pallet<T> {
 			PoolType::Stableswap(pool_id) => pool_id,
 			PoolType::XYK => first_route.asset_out,
 			PoolType::LBP => first_route.asset_out,
+			PoolType::Bonds => first_route.asset_out,
 		};
 
 		let asset_in_liquidity = T::AMM::get_liquidity_depth(first_route.pool, first_route.asset_in, asset_b);
@@ -743,7 +989,7 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_out)
 	}
 
-	fn calculate_sell_trade_amounts(
+	pub fn calculate_sell_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
 	) -> Result<Vec<AmountInAndOut<T::Balance>>, DispatchError> {
@@ -765,7 +1011,7 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
-	fn calculate_buy_trade_amounts(
+	pub fn calculate_buy_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_out: T::Balance,
 	) -> Result<Vec<AmountInAndOut<T::Balance>>, DispatchError> {
@@ -788,6 +1034,166 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Finds the route with the highest amount out when selling `amount_in`.
+	///
+	/// At most `max_candidates` candidate routes are evaluated, see [`Pallet::candidate_routes`].
+	pub fn find_best_sell_route(
+		asset_pair: AssetPair<T::AssetId>,
+		amount_in: T::Balance,
+		max_candidates: u32,
+	) -> Option<(Vec<Trade<T::AssetId>>, T::Balance)> {
+		Self::best_sell_route(Self::candidate_routes(asset_pair, max_candidates), amount_in)
+	}
+
+	/// Finds the route with the lowest amount in when buying `amount_out`.
+	///
+	/// At most `max_candidates` candidate routes are evaluated, see [`Pallet::candidate_routes`].
+	pub fn find_best_buy_route(
+		asset_pair: AssetPair<T::AssetId>,
+		amount_out: T::Balance,
+		max_candidates: u32,
+	) -> Option<(Vec<Trade<T::AssetId>>, T::Balance)> {
+		Self::candidate_routes(asset_pair, max_candidates)
+			.into_iter()
+			.filter_map(|route| {
+				let amount_in = Self::calculate_buy_trade_amounts(&route, amount_out)
+					.ok()?
+					.last()?
+					.amount_in;
+				Some((route, amount_in))
+			})
+			.fold(None, |best, (route, amount_in)| match best {
+				Some((_, best_amount_in)) if best_amount_in <= amount_in => best,
+				_ => Some((route, amount_in)),
+			})
+	}
+
+	/// Enumerates the candidate routes between the assets of the pair, across the pools of `T::PoolsProvider`.
+	///
+	/// The current on-chain (or default) route is always the first candidate.
+	/// Other candidates follow ordered by their number of trades, up to `MAX_NUMBER_OF_TRADES`.
+	/// Routes visiting the same asset twice, or trading twice in a row in the same pool, are skipped.
+	///
+	/// At most `MaxRoutePoolAssets` pool assets are read, and the search goes over them
+	/// at most `MAX_NUMBER_OF_TRADES` times in total, so the discovery cost does not grow with the number of pools.
+	pub fn candidate_routes(asset_pair: AssetPair<T::AssetId>, max_candidates: u32) -> Vec<Vec<Trade<T::AssetId>>> {
+		let max_candidates = max_candidates as usize;
+		if asset_pair.asset_in == asset_pair.asset_out || max_candidates == 0 {
+			return vec![];
+		}
+
+		let mut routes = vec![<Self as RouteProvider<T::AssetId>>::get_route(asset_pair)];
+		let max_pool_assets = T::MaxRoutePoolAssets::get();
+		let pools = T::PoolsProvider::pools(max_pool_assets);
+		let mut search_budget = max_pool_assets.saturating_mul(MAX_NUMBER_OF_TRADES);
+
+		for number_of_trades in 1..=MAX_NUMBER_OF_TRADES as usize {
+			if routes.len() >= max_candidates || search_budget == 0 {
+				break;
+			}
+			let mut route = Vec::with_capacity(number_of_trades);
+			Self::collect_routes(
+				&pools,
+				asset_pair,
+				asset_pair.asset_in,
+				number_of_trades,
+				&mut route,
+				&mut routes,
+				max_candidates,
+				&mut search_budget,
+			);
+		}
+
+		routes.truncate(max_candidates);
+		routes
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	fn collect_routes(
+		pools: &[(PoolType<T::AssetId>, Vec<T::AssetId>)],
+		asset_pair: AssetPair<T::AssetId>,
+		current_asset: T::AssetId,
+		number_of_trades: usize,
+		route: &mut Vec<Trade<T::AssetId>>,
+		routes: &mut Vec<Vec<Trade<T::AssetId>>>,
+		max_candidates: usize,
+		search_budget: &mut u32,
+	) {
+		let is_last_trade = route.len().saturating_add(1) == number_of_trades;
+
+		for (pool, assets) in pools {
+			// Every visited pool costs the number of its assets, as all of them may be inspected
+			if *search_budget == 0 {
+				return;
+			}
+			*search_budget = search_budget.saturating_sub(assets.len() as u32);
+
+			if !assets.contains(&current_asset) {
+				continue;
+			}
+
+			// Two consecutive trades in the same pool can always be replaced by a single trade
+			let is_same_pool_as_previous = route.last().map_or(false, |previous| {
+				previous.pool == *pool && assets.contains(&previous.asset_in)
+			});
+			if is_same_pool_as_previous {
+				continue;
+			}
+
+			for asset_out in assets.iter().copied() {
+				if routes.len() >= max_candidates {
+					return;
+				}
+
+				let is_visited = asset_out == current_asset
+					|| asset_out == asset_pair.asset_in
+					|| route.iter().any(|trade| trade.asset_out == asset_out);
+				if is_visited || is_last_trade != (asset_out == asset_pair.asset_out) {
+					continue;
+				}
+
+				route.push(Trade {
+					pool: *pool,
+					asset_in: current_asset,
+					asset_out,
+				});
+
+				if !is_last_trade {
+					Self::collect_routes(
+						pools,
+						asset_pair,
+						asset_out,
+						number_of_trades,
+						route,
+						routes,
+						max_candidates,
+						search_budget,
+					);
+				} else if !routes.contains(route) {
+					routes.push(route.clone());
+				}
+
+				route.pop();
+			}
+		}
+	}
+
+	fn best_sell_route(
+		candidates: Vec<Vec<Trade<T::AssetId>>>,
+		amount_in: T::Balance,
+	) -> Option<(Vec<Trade<T::AssetId>>, T::Balance)> {
+		candidates
+			.into_iter()
+			.filter_map(|route| {
+				let amount_out = Self::calculate_expected_amount_out(&route, amount_in).ok()?;
+				Some((route, amount_out))
+			})
+			.fold(None, |best, (route, amount_out)| match best {
+				Some((_, best_amount_out)) if best_amount_out >= amount_out => best,
+				_ => Some((route, amount_out)),
+			})
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
@@ -837,6 +1243,28 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Pallet::<T>::buy(origin, asset_in, asset_out, amount_out, max_amount_in, route)
 	}
 
+	fn sell_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+		min_amount_out: T::Balance,
+		routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+	) -> DispatchResult {
+		Pallet::<T>::sell_split(origin, asset_in, asset_out, amount_in, min_amount_out, routes)
+	}
+
+	fn buy_split(
+		origin: T::RuntimeOrigin,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+		max_amount_in: T::Balance,
+		routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+	) -> DispatchResult {
+		Pallet::<T>::buy_split(origin, asset_in, asset_out, amount_out, max_amount_in, routes)
+	}
+
 	fn calculate_sell_trade_amounts(
 		route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -904,6 +1332,28 @@ impl<T: Config> RouterT<T::RuntimeOrigin, T::AssetId, T::Balance, Trade<T::Asset
 		Ok(())
 	}
 
+	fn sell_split(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_amount_in: T::Balance,
+		_min_amount_out: T::Balance,
+		_routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
+	fn buy_split(
+		_origin: T::RuntimeOrigin,
+		_asset_in: T::AssetId,
+		_asset_out: T::AssetId,
+		_amount_out: T::Balance,
+		_max_amount_in: T::Balance,
+		_routes: Vec<WeightedRoute<Trade<T::AssetId>>>,
+	) -> DispatchResult {
+		Ok(())
+	}
+
 	fn calculate_sell_trade_amounts(
 		_route: &[Trade<T::AssetId>],
 		amount_in: T::Balance,
@@ -990,6 +1440,17 @@ impl<T: Config> RouteProvider<T::AssetId> for Pallet<T> {
 			None => default_route,
 		}
 	}
+
+	fn discover_route(asset_pair: AssetPair<T::AssetId>) -> Option<Vec<Trade<T::AssetId>>> {
+		let candidates = Self::candidate_routes(asset_pair, T::MaxRouteCandidates::get());
+
+		// The candidates are compared by selling the same amount, based on the liquidity of the first evaluable route
+		let reference_amount_in = candidates
+			.iter()
+			.find_map(|route| Self::calculate_reference_amount_in(route).ok())?;
+
+		Self::best_sell_route(candidates, reference_amount_in).map(|(route, _)| route)
+	}
 }
 
 impl<T: Config> RouteSpotPriceProvider<T::AssetId> for Pallet<T> {
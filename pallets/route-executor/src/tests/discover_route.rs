@@ -0,0 +1,212 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade};
+use frame_support::pallet_prelude::*;
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::RouteProvider;
+use hydradx_traits::router::{AssetPair, PoolType};
+use pretty_assertions::assert_eq;
+
+fn pools() -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+	vec![
+		(PoolType::Omnipool, vec![HDX, AUSD, DOT]),
+		(PoolType::XYK, vec![DOT, AUSD]),
+		(PoolType::XYK, vec![HDX, DOT]),
+	]
+}
+
+fn omnipool_then_xyk_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: DOT,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: DOT,
+			asset_out: AUSD,
+		},
+	]
+}
+
+fn xyk_then_omnipool_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: HDX,
+			asset_out: DOT,
+		},
+		Trade {
+			pool: PoolType::Omnipool,
+			asset_in: DOT,
+			asset_out: AUSD,
+		},
+	]
+}
+
+fn xyk_only_route() -> Vec<Trade<AssetId>> {
+	vec![
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: HDX,
+			asset_out: DOT,
+		},
+		Trade {
+			pool: PoolType::XYK,
+			asset_in: DOT,
+			asset_out: AUSD,
+		},
+	]
+}
+
+#[test]
+fn candidate_routes_should_start_with_current_route_and_follow_by_number_of_trades() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let routes = Router::candidate_routes(AssetPair::new(HDX, AUSD), 20);
+
+		//Assert
+		assert_eq!(
+			routes,
+			vec![
+				default_omnipool_route(),
+				omnipool_then_xyk_route(),
+				xyk_then_omnipool_route(),
+				xyk_only_route(),
+			]
+		);
+	});
+}
+
+#[test]
+fn candidate_routes_should_be_limited_by_max_candidates() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let routes = Router::candidate_routes(AssetPair::new(HDX, AUSD), 2);
+
+		//Assert
+		assert_eq!(routes, vec![default_omnipool_route(), omnipool_then_xyk_route()]);
+	});
+}
+
+#[test]
+fn candidate_routes_should_be_empty_when_assets_are_the_same() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let routes = Router::candidate_routes(AssetPair::new(HDX, HDX), 20);
+
+		//Assert
+		assert!(routes.is_empty());
+	});
+}
+
+#[test]
+fn candidate_routes_should_ignore_pools_beyond_max_pool_assets() {
+	//Arrange
+	let mut many_pools: Vec<(PoolType<AssetId>, Vec<AssetId>)> = (0..MaxRoutePoolAssets::get() / 2)
+		.map(|i| (PoolType::XYK, vec![10_000 + i, 20_000 + i]))
+		.collect();
+	many_pools.extend(pools());
+
+	ExtBuilder::default().with_pools(many_pools).build().execute_with(|| {
+		//Act
+		let routes = Router::candidate_routes(AssetPair::new(HDX, AUSD), 20);
+
+		//Assert
+		assert_eq!(routes, vec![default_omnipool_route()]);
+	});
+}
+
+#[test]
+fn find_best_sell_route_should_return_route_with_highest_amount_out() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let best = Router::find_best_sell_route(AssetPair::new(HDX, AUSD), 100, 20);
+
+		//Assert
+		assert_eq!(best, Some((omnipool_then_xyk_route(), XYK_SELL_CALCULATION_RESULT)));
+	});
+}
+
+#[test]
+fn find_best_buy_route_should_return_route_with_lowest_amount_in() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let best = Router::find_best_buy_route(AssetPair::new(HDX, AUSD), 100, 20);
+
+		//Assert
+		assert_eq!(best, Some((xyk_then_omnipool_route(), XYK_BUY_CALCULATION_RESULT)));
+	});
+}
+
+#[test]
+fn discover_route_should_return_current_route_when_no_pools_are_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		let route = Router::discover_route(AssetPair::new(HDX, AUSD));
+
+		//Assert
+		assert_eq!(route, Some(default_omnipool_route()));
+	});
+}
+
+#[test]
+fn discover_route_should_return_best_route_across_pools() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Act
+		let route = Router::discover_route(AssetPair::new(HDX, AUSD));
+
+		//Assert
+		assert_eq!(route, Some(omnipool_then_xyk_route()));
+	});
+}
+
+#[test]
+fn set_route_should_store_discovered_route_when_called_with_empty_route() {
+	ExtBuilder::default().with_pools(pools()).build().execute_with(|| {
+		//Arrange
+		let asset_pair = AssetPair::new(HDX, AUSD);
+
+		//Act
+		assert_ok!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), asset_pair, vec![]),
+			Pays::No.into()
+		);
+
+		//Assert
+		assert_eq!(Router::get_route(asset_pair), omnipool_then_xyk_route());
+
+		expect_events(vec![Event::RouteUpdated {
+			asset_ids: vec![HDX, AUSD],
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_route_should_fail_when_no_route_is_discovered() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::set_route(RuntimeOrigin::signed(ALICE), AssetPair::new(HDX, HDX), vec![]),
+			Error::<Test>::RouteNotFound
+		);
+	});
+}
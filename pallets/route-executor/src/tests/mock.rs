@@ -24,7 +24,7 @@ use frame_support::{
 use frame_system::EnsureRoot;
 use frame_system::{ensure_signed, pallet_prelude::OriginFor};
 use hydra_dx_math::ratio::Ratio;
-use hydradx_traits::router::{ExecutorError, PoolType, RefundEdCalculator, TradeExecution};
+use hydradx_traits::router::{take_pools, ExecutorError, PoolType, PoolsProvider, RefundEdCalculator, TradeExecution};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::{fungibles::FungibleCurrencies, BasicCurrencyAdapter, MockBoundErc20, MockErc20Currency};
 use pretty_assertions::assert_eq;
@@ -165,9 +165,25 @@ impl Config for Test {
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = MockedPoolsProvider;
+	type MaxRouteCandidates = MaxRouteCandidates;
+	type MaxRoutePoolAssets = MaxRoutePoolAssets;
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxRouteCandidates: u32 = 20;
+	pub const MaxRoutePoolAssets: u32 = 100;
+}
+
+pub struct MockedPoolsProvider;
+
+impl PoolsProvider<AssetId> for MockedPoolsProvider {
+	fn pools(max_assets: u32) -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		take_pools(POOLS.with(|v| v.borrow().clone()).into_iter(), max_assets)
+	}
+}
+
 pub struct MockedEdCalculator;
 
 impl RefundEdCalculator<Balance> for MockedEdCalculator {
@@ -275,6 +291,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<(PoolType<AssetId>, Vec<AssetId>)>,
 }
 
 // Returns default values for genesis config
@@ -282,6 +299,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			pools: vec![],
 		}
 	}
 }
@@ -292,6 +310,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_pools(mut self, pools: Vec<(PoolType<AssetId>, Vec<AssetId>)>) -> Self {
+		self.pools = pools;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
 
@@ -323,6 +346,8 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		POOLS.with(|v| *v.borrow_mut() = self.pools);
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -333,6 +358,7 @@ type ExecutedTradeInfo = (PoolType<AssetId>, Balance, AssetId, AssetId);
 thread_local! {
 	pub static EXECUTED_SELLS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
 	pub static EXECUTED_BUYS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
+	pub static POOLS: RefCell<Vec<(PoolType<AssetId>, Vec<AssetId>)>> = RefCell::new(Vec::default());
 }
 
 type OriginForRuntime = OriginFor<Test>;
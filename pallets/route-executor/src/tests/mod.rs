@@ -1,7 +1,9 @@
 pub mod buy;
+pub mod discover_route;
 pub mod force_insert_route;
 pub mod mock;
 pub mod sell;
 pub mod sell_all;
 pub mod set_route;
+pub mod split;
 pub mod spot_price;
@@ -316,7 +316,7 @@ fn set_route_should_fail_when_asset_pair_is_invalid_for_route() {
 }
 
 #[test]
-fn set_route_should_fail_when_called_with_empty_route() {
+fn set_route_should_fail_when_called_with_empty_route_and_no_better_route_is_discovered() {
 	ExtBuilder::default().build().execute_with(|| {
 		//Arrange
 		let asset_pair = AssetPair::new(HDX, AUSD);
@@ -325,7 +325,7 @@ fn set_route_should_fail_when_called_with_empty_route() {
 		//Act and assert
 		assert_noop!(
 			Router::set_route(RuntimeOrigin::signed(ALICE), asset_pair, empty_route),
-			Error::<Test>::InvalidRoute
+			Error::<Test>::RouteUpdateIsNotSuccessful
 		);
 	});
 }
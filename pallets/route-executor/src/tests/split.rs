@@ -0,0 +1,291 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2022  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, Trade, WeightedRoute};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+fn weighted_route(weight: Permill, pool: PoolType<AssetId>) -> WeightedRoute<Trade<AssetId>> {
+	WeightedRoute {
+		weight,
+		route: vec![Trade {
+			pool,
+			asset_in: HDX,
+			asset_out: AUSD,
+		}],
+	}
+}
+
+#[test]
+fn sell_split_should_work_when_amount_is_split_between_routes() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 100;
+		let limit = 20;
+
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(40), PoolType::Omnipool),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 60, HDX, AUSD),
+			(PoolType::Omnipool, 40, HDX, AUSD),
+		]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_split_should_assign_rounding_remainder_to_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+
+		let routes = vec![
+			weighted_route(Permill::from_parts(333_333), PoolType::XYK),
+			weighted_route(Permill::from_parts(333_333), PoolType::LBP),
+			weighted_route(Permill::from_parts(333_334), PoolType::Omnipool),
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			0,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![
+			(PoolType::XYK, 3, HDX, AUSD),
+			(PoolType::LBP, 3, HDX, AUSD),
+			(PoolType::Omnipool, 4, HDX, AUSD),
+		]);
+	});
+}
+
+#[test]
+fn sell_split_should_use_onchain_route_when_route_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(Permill::from_percent(50), PoolType::XYK),
+			WeightedRoute {
+				weight: Permill::from_percent(50),
+				route: vec![],
+			},
+		];
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			0,
+			routes
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::Omnipool, 5, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_aggregated_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let limit = XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT + 1;
+
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(40), PoolType::Omnipool),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, limit, routes),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_weights_do_not_sum_up_to_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(30), PoolType::Omnipool),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_has_zero_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(Permill::from_percent(100), PoolType::XYK),
+			weighted_route(Permill::zero(), PoolType::Omnipool),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_no_route_is_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, vec![]),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_max_number_of_splits_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted_route(Permill::from_percent(20), PoolType::XYK); 5];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 100, 0, routes),
+			Error::<Test>::MaxSplitsExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_assets_are_the_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![weighted_route(Permill::from_percent(100), PoolType::XYK)];
+
+		//Act and assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, HDX, 100, 0, routes),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_work_when_amount_is_split_between_routes() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 10;
+
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(40), PoolType::Omnipool),
+		];
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			routes
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 6, HDX, AUSD), (PoolType::Omnipool, 4, HDX, AUSD)]);
+		expect_events(vec![Event::Executed {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_aggregated_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let limit = XYK_BUY_CALCULATION_RESULT + OMNIPOOL_BUY_CALCULATION_RESULT - 1;
+
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(40), PoolType::Omnipool),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, limit, routes),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_weights_do_not_sum_up_to_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let routes = vec![
+			weighted_route(Permill::from_percent(60), PoolType::XYK),
+			weighted_route(Permill::from_percent(60), PoolType::Omnipool),
+		];
+
+		//Act and assert
+		assert_noop!(
+			Router::buy_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 100, routes),
+			Error::<Test>::InvalidSplit
+		);
+	});
+}
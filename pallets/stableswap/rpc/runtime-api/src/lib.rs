@@ -0,0 +1,52 @@
+// Copyright (C) 2020-2024  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the stableswap pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use pallet_stableswap::types::{AssetAmount, PoolSnapshot};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// The API to inspect stableswap pools and quote their trades.
+	pub trait StableswapApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Get current state of all pools.
+		fn pools() -> Vec<PoolSnapshot<AssetId>>;
+
+		/// Get current state of a pool - reserves, current amplification, share issuance and tradability of assets.
+		fn pool(pool_id: AssetId) -> Option<PoolSnapshot<AssetId>>;
+
+		/// Calculate amount of `asset_out` received when selling `amount_in` of `asset_in`.
+		/// Returns (amount_out, fee).
+		fn calculate_sell(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<(Balance, Balance), DispatchError>;
+
+		/// Calculate amount of `asset_in` required to buy `amount_out` of `asset_out`.
+		/// Returns (amount_in, fee).
+		fn calculate_buy(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<(Balance, Balance), DispatchError>;
+
+		/// Calculate amount of shares received for adding liquidity.
+		fn calculate_add_liquidity_shares(pool_id: AssetId, assets: Vec<AssetAmount<AssetId>>) -> Result<Balance, DispatchError>;
+
+		/// Calculate amount of asset received for removing `share_amount` of shares.
+		/// Returns (amount, fee).
+		fn calculate_remove_liquidity_one_asset(pool_id: AssetId, asset_id: AssetId, share_amount: Balance) -> Result<(Balance, Balance), DispatchError>;
+	}
+}
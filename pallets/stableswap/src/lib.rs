@@ -29,6 +29,12 @@
 //!
 //! This is currently used to update on-chain oracle.
 //!
+//! ### Dynamic fees
+//!
+//! Pools can opt in to dynamic trade fees by setting fee bounds via `set_pool_dynamic_fees`.
+//! Trade fee of such pool is calculated from the imbalance of pool reserves and from the trade volume of
+//! traded assets provided by `VolumeProvider`, bounded by the pool's min and max fee.
+//!
 //! ### Terminology
 //!
 //! * **LP** - liquidity provider
@@ -53,7 +59,7 @@
 
 extern crate core;
 
-use frame_support::pallet_prelude::{DispatchResult, Get};
+use frame_support::pallet_prelude::{DispatchResult, Get, Weight};
 use frame_support::{ensure, require_transactional, transactional, PalletId};
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydradx_traits::{registry::Inspect, AccountIdFor};
@@ -68,7 +74,10 @@ mod trade_execution;
 pub mod types;
 pub mod weights;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
+use crate::types::{
+	AssetAmount, Balance, DynamicFeeBounds, PoolAssetState, PoolInfo, PoolSnapshot, PoolState, PoolVolumeProvider,
+	StableswapHooks, Tradability,
+};
 use hydra_dx_math::stableswap::types::AssetReserve;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use orml_traits::MultiCurrency;
@@ -146,6 +155,9 @@ pub mod pallet {
 		/// Hooks are actions executed on add_liquidity, sell or buy.
 		type Hooks: StableswapHooks<Self::AssetId>;
 
+		/// Trade volume provider used to calculate dynamic fees of pools.
+		type VolumeProvider: PoolVolumeProvider<Self::AssetId>;
+
 		/// Minimum pool liquidity
 		#[pallet::constant]
 		type MinPoolLiquidity: Get<Balance>;
@@ -176,6 +188,11 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Dynamic fee bounds of pools which opted in to dynamic fees.
+	#[pallet::storage]
+	#[pallet::getter(fn pool_dynamic_fees)]
+	pub type PoolDynamicFees<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, DynamicFeeBounds>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -241,6 +258,11 @@ pub mod pallet {
 		},
 		/// A pool has been destroyed.
 		PoolDestroyed { pool_id: T::AssetId },
+		/// Dynamic fee bounds of a pool have been updated.
+		DynamicFeesUpdated {
+			pool_id: T::AssetId,
+			bounds: Option<DynamicFeeBounds>,
+		},
 	}
 
 	#[pallet::error]
@@ -317,6 +339,9 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Minimum dynamic fee is greater than maximum dynamic fee.
+		InvalidFeeBounds,
 	}
 
 	#[pallet::call]
@@ -715,6 +740,7 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
+							.saturating_add(Pallet::<T>::dynamic_fee_weight(1))
 							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn sell(
@@ -771,7 +797,11 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(
+				pool_id,
+				&initial_reserves,
+				Self::trade_fee(pool_id, &pool, &initial_reserves, &[asset_in, asset_out]),
+			);
 
 			Ok(())
 		}
@@ -790,6 +820,7 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
+							.saturating_add(Pallet::<T>::dynamic_fee_weight(1))
 							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
 		#[transactional]
 		pub fn buy(
@@ -849,7 +880,11 @@ pub mod pallet {
 			});
 
 			#[cfg(feature = "try-runtime")]
-			Self::ensure_trade_invariant(pool_id, &initial_reserves, pool.fee);
+			Self::ensure_trade_invariant(
+				pool_id,
+				&initial_reserves,
+				Self::trade_fee(pool_id, &pool, &initial_reserves, &[asset_in, asset_out]),
+			);
 
 			Ok(())
 		}
@@ -953,6 +988,7 @@ pub mod pallet {
 			} else {
 				// Remove the pool.
 				Pools::<T>::remove(pool_id);
+				PoolDynamicFees::<T>::remove(pool_id);
 				let _ = AssetTradability::<T>::clear_prefix(pool_id, MAX_ASSETS_IN_POOL, None);
 				T::DustAccountHandler::remove_account(&Self::pool_account(pool_id))?;
 				Self::deposit_event(Event::PoolDestroyed { pool_id });
@@ -971,6 +1007,156 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Remove liquidity from selected pool by specifying exact amounts of assets to receive.
+		///
+		/// Similar to `withdraw_asset_amount` but LP can receive any combination of pool assets.
+		/// Fee is applied to the difference between the amounts received and the amounts which would be received
+		/// if liquidity was removed proportionally to pool reserves.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `assets`: list of assets and amounts to receive
+		/// - 'max_share_amount': Slippage limit. Max amount of shares to burn.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_imbalanced()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+		#[transactional]
+		pub fn remove_liquidity_imbalanced(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			assets: Vec<AssetAmount<T::AssetId>>,
+			max_share_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!assets.is_empty(), Error::<T>::InvalidAssetAmount);
+
+			// Retrieve pool state.
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(assets.len() <= pool.assets.len(), Error::<T>::MaxAssetsExceeded);
+
+			let mut withdrawn_amounts = BTreeMap::<T::AssetId, Balance>::new();
+			for asset in assets.iter() {
+				ensure!(
+					Self::is_asset_allowed(pool_id, asset.asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+				ensure!(asset.amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+				ensure!(pool.find_asset(asset.asset_id).is_some(), Error::<T>::AssetNotInPool);
+				ensure!(
+					withdrawn_amounts.insert(asset.asset_id, asset.amount).is_none(),
+					Error::<T>::IncorrectAssets
+				);
+			}
+
+			let pool_account = Self::pool_account(pool_id);
+			let initial_reserves = pool
+				.reserves_with_decimals::<T>(&pool_account)
+				.ok_or(Error::<T>::UnknownDecimals)?;
+
+			let updated_reserves = pool
+				.assets
+				.iter()
+				.zip(initial_reserves.iter())
+				.map(|(asset_id, reserve)| -> Result<AssetReserve, DispatchError> {
+					let amount = withdrawn_amounts.get(asset_id).copied().unwrap_or_default();
+					let updated = reserve
+						.amount
+						.checked_sub(amount)
+						.ok_or(Error::<T>::InsufficientLiquidity)?;
+					ensure!(!updated.is_zero(), Error::<T>::InsufficientLiquidity);
+					Ok(AssetReserve::new(updated, reserve.decimals))
+				})
+				.collect::<Result<Vec<AssetReserve>, DispatchError>>()?;
+
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			let amplification = Self::get_amplification(&pool);
+
+			// Calculate how much shares user needs to provide to receive given amounts of assets.
+			let shares = hydra_dx_math::stableswap::calculate_shares_removed::<D_ITERATIONS>(
+				&initial_reserves,
+				&updated_reserves,
+				amplification,
+				share_issuance,
+				pool.fee,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(shares <= max_share_amount, Error::<T>::SlippageLimit);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(current_share_balance >= shares, Error::<T>::InsufficientShares);
+			ensure!(
+				current_share_balance == shares
+					|| current_share_balance.saturating_sub(shares) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+			ensure!(
+				share_issuance.saturating_sub(shares) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			// Burn shares and transfer assets to user.
+			T::Currency::withdraw(pool_id, &who, shares)?;
+			for asset in assets.iter() {
+				T::Currency::transfer(asset.asset_id, &pool_account, &who, asset.amount)?;
+			}
+
+			// All done and updated. let's call the on_liquidity_changed hook.
+			Self::call_on_liquidity_change_hook(pool_id, &initial_reserves, share_issuance)?;
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares,
+				amounts: assets,
+				fee: Balance::zero(),
+			});
+
+			#[cfg(feature = "try-runtime")]
+			Self::ensure_remove_liquidity_invariant(pool_id, &initial_reserves);
+
+			Ok(())
+		}
+
+		/// Set or remove dynamic fee bounds of a pool.
+		///
+		/// When set, trade fee of the pool is calculated dynamically within given bounds
+		/// and pool's static fee is used for liquidity operations only.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		/// - `bounds`: min and max dynamic fee. `None` disables dynamic fees of the pool.
+		///
+		/// Emits `DynamicFeesUpdated` event if successful.
+		#[pallet::call_index(12)]
+		// Bounded by `update_pool_fee` which reads the pool and writes one pool entry as well.
+		#[pallet::weight(<T as Config>::WeightInfo::update_pool_fee())]
+		#[transactional]
+		pub fn set_pool_dynamic_fees(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			bounds: Option<DynamicFeeBounds>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+
+			if let Some(bounds) = bounds {
+				ensure!(bounds.is_valid(), Error::<T>::InvalidFeeBounds);
+				PoolDynamicFees::<T>::insert(pool_id, bounds);
+			} else {
+				PoolDynamicFees::<T>::remove(pool_id);
+			}
+
+			Self::deposit_event(Event::DynamicFeesUpdated { pool_id, bounds });
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
@@ -985,7 +1171,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Calculates out amount given in amount.
 	/// Returns (out_amount, fee_amount) on success. Note that fee amount is already subtracted from the out amount.
-	fn calculate_out_amount(
+	pub fn calculate_out_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
@@ -1008,20 +1194,21 @@ impl<T: Config> Pallet<T> {
 		);
 
 		let amplification = Self::get_amplification(&pool);
+		let fee = Self::trade_fee(pool_id, &pool, &initial_reserves, &[asset_in, asset_out]);
 		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			index_in,
 			index_out,
 			amount_in,
 			amplification,
-			pool.fee,
+			fee,
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
 	/// Calculates in amount given out amount.
 	/// Returns (in_amount, fee_amount) on success. Note that fee amount is already added to the in amount.
-	fn calculate_in_amount(
+	pub fn calculate_in_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
@@ -1044,17 +1231,45 @@ impl<T: Config> Pallet<T> {
 		ensure!(!initial_reserves[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
+		let fee = Self::trade_fee(pool_id, &pool, &initial_reserves, &[asset_in, asset_out]);
 		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
 			&initial_reserves,
 			index_in,
 			index_out,
 			amount_out,
 			amplification,
-			pool.fee,
+			fee,
 		)
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
+	/// Weight of `n` trade fee calculations of a pool with dynamic fees, which is not part of the benchmarked trade weights.
+	/// Reads dynamic fee bounds of the pool and trade volume of the two traded assets.
+	pub fn dynamic_fee_weight(n: u32) -> Weight {
+		T::DbWeight::get().reads(3u64.saturating_mul(n.into()))
+	}
+
+	/// Returns trade fee of a pool, calculated from the trade volume of given `assets`.
+	/// Pool's static fee is returned if the pool has not opted in to dynamic fees.
+	fn trade_fee(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, BlockNumberFor<T>>,
+		reserves: &[AssetReserve],
+		assets: &[T::AssetId],
+	) -> Permill {
+		let Some(bounds) = PoolDynamicFees::<T>::get(pool_id) else {
+			return pool.fee;
+		};
+
+		let volumes: Vec<_> = assets
+			.iter()
+			.filter_map(|asset_id| T::VolumeProvider::asset_volume(pool_id, *asset_id))
+			.collect();
+
+		hydra_dx_math::stableswap::calculate_dynamic_fee(reserves, &volumes, bounds.min_fee, bounds.max_fee)
+			.unwrap_or(bounds.max_fee)
+	}
+
 	#[require_transactional]
 	fn do_create_pool(
 		share_asset: T::AssetId,
@@ -1252,7 +1467,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
@@ -1271,10 +1486,93 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	/// Add new asset to the list of pool assets.
+	/// No events emitted.
+	///
+	/// Liquidity of the asset and corresponding shares are not handled - it is up to the caller
+	/// to transfer the reserve to the pool account and to mint the shares.
+	pub fn add_pool_asset(pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+		ensure!(pool_id != asset_id, Error::<T>::ShareAssetInPoolAssets);
+		ensure!(T::AssetInspection::exists(asset_id), Error::<T>::AssetNotRegistered);
+
+		Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+			let pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::IncorrectAssets);
+
+			let mut pool_assets = pool.assets.to_vec();
+			pool_assets.push(asset_id);
+			pool_assets.sort();
+
+			pool.assets = pool_assets.try_into().map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+			Ok(())
+		})
+	}
+
+	/// Calculates amount of asset received for removing `share_amount` of shares.
+	/// Returns (amount, fee_amount) on success. Note that fee amount is already subtracted from the amount.
+	pub fn calculate_remove_liquidity_one_asset(
+		pool_id: T::AssetId,
+		asset_id: T::AssetId,
+		share_amount: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+		let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+		let reserves = pool
+			.reserves_with_decimals::<T>(&Self::pool_account(pool_id))
+			.ok_or(Error::<T>::UnknownDecimals)?;
+		let share_issuance = T::Currency::total_issuance(pool_id);
+		let amplification = Self::get_amplification(&pool);
+
+		hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			&reserves,
+			share_amount,
+			asset_idx,
+			share_issuance,
+			amplification,
+			pool.fee,
+		)
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Returns current state of a pool - reserves of pool assets, current amplification, current trade fee and share issuance.
+	/// Trade fee of a pool with dynamic fees is calculated from the trade volume of all pool assets.
+	pub fn pool_snapshot(pool_id: T::AssetId) -> Option<PoolSnapshot<T::AssetId>> {
+		let pool = Pools::<T>::get(pool_id)?;
+		let reserves = pool.reserves_with_decimals::<T>(&Self::pool_account(pool_id))?;
+		let normalized_reserves = hydra_dx_math::stableswap::normalize_reserves(&reserves);
+
+		let assets = pool
+			.assets
+			.iter()
+			.zip(reserves.iter().zip(normalized_reserves))
+			.map(|(asset_id, (reserve, normalized_reserve))| PoolAssetState {
+				asset_id: *asset_id,
+				reserve: reserve.amount,
+				decimals: reserve.decimals,
+				normalized_reserve,
+				tradability: AssetTradability::<T>::get(pool_id, asset_id),
+			})
+			.collect();
+
+		Some(PoolSnapshot {
+			pool_id,
+			assets,
+			amplification: Self::get_amplification(&pool),
+			fee: Self::trade_fee(pool_id, &pool, &reserves, &pool.assets),
+			share_issuance: T::Currency::total_issuance(pool_id),
+		})
+	}
+
+	/// Returns current state of all pools.
+	pub fn pool_snapshots() -> Vec<PoolSnapshot<T::AssetId>> {
+		Pools::<T>::iter_keys().filter_map(Self::pool_snapshot).collect()
+	}
 }
 
 impl<T: Config> Pallet<T> {
-	fn calculate_shares(pool_id: T::AssetId, assets: &[AssetAmount<T::AssetId>]) -> Result<Balance, DispatchError> {
+	/// Calculates amount of shares received for adding given assets to the pool.
+	pub fn calculate_shares(pool_id: T::AssetId, assets: &[AssetAmount<T::AssetId>]) -> Result<Balance, DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 		let pool_account = Self::pool_account(pool_id);
 
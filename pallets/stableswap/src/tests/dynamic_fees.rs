@@ -0,0 +1,326 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, DynamicFeeBounds, PoolInfo};
+use crate::{Error, Event, D_ITERATIONS, Y_ITERATIONS};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
+use hydra_dx_math::stableswap::types::AssetReserve;
+use sp_runtime::{DispatchError, Permill};
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+
+const BOUNDS: DynamicFeeBounds = DynamicFeeBounds {
+	min_fee: Permill::from_percent(1),
+	max_fee: Permill::from_percent(5),
+};
+
+fn pool_with_reserves(reserve_a: Balance, reserve_b: Balance) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 200 * ONE),
+			(ALICE, ASSET_A, reserve_a),
+			(ALICE, ASSET_B, reserve_b),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, reserve_a),
+					AssetAmount::new(ASSET_B, reserve_b),
+				],
+			},
+		)
+		.build()
+}
+
+// Returns out amount and fee of a sell using static pool fee instead of dynamic fees.
+fn sell_with_static_fee(pool_id: AssetId, fee: Permill, amount_in: Balance) -> (Balance, Balance) {
+	assert_ok!(Stableswap::set_pool_dynamic_fees(RuntimeOrigin::root(), pool_id, None));
+	assert_ok!(Stableswap::update_pool_fee(RuntimeOrigin::root(), pool_id, fee));
+	Stableswap::calculate_out_amount(pool_id, ASSET_A, ASSET_B, amount_in).unwrap()
+}
+
+#[test]
+fn set_pool_dynamic_fees_should_work() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+
+		assert_eq!(Stableswap::pool_dynamic_fees(pool_id), Some(BOUNDS));
+		expect_events(vec![Event::DynamicFeesUpdated {
+			pool_id,
+			bounds: Some(BOUNDS),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fees_should_remove_bounds_when_none_is_provided() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+
+		assert_ok!(Stableswap::set_pool_dynamic_fees(RuntimeOrigin::root(), pool_id, None));
+
+		assert_eq!(Stableswap::pool_dynamic_fees(pool_id), None);
+		expect_events(vec![Event::DynamicFeesUpdated { pool_id, bounds: None }.into()]);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fees_should_fail_when_called_by_non_authority_origin() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fees(RuntimeOrigin::signed(ALICE), pool_id, Some(BOUNDS)),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fees_should_fail_when_pool_does_not_exist() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fees(RuntimeOrigin::root(), 1000, Some(BOUNDS)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_pool_dynamic_fees_should_fail_when_min_fee_is_greater_than_max_fee() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_pool_dynamic_fees(
+				RuntimeOrigin::root(),
+				pool_id,
+				Some(DynamicFeeBounds {
+					min_fee: Permill::from_percent(5),
+					max_fee: Permill::from_percent(1),
+				})
+			),
+			Error::<Test>::InvalidFeeBounds
+		);
+	});
+}
+
+#[test]
+fn trade_fee_should_be_min_fee_when_pool_is_balanced_and_there_is_no_volume() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+
+		let result = Stableswap::calculate_out_amount(pool_id, ASSET_A, ASSET_B, 10 * ONE).unwrap();
+
+		assert!(result.1 > 0);
+		assert_eq!(
+			result,
+			sell_with_static_fee(pool_id, Permill::from_percent(1), 10 * ONE)
+		);
+	});
+}
+
+#[test]
+fn trade_fee_should_increase_when_pool_is_imbalanced() {
+	// imbalance = (300 - 100) / 400 = 0.5
+	pool_with_reserves(300 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+
+		let result = Stableswap::calculate_out_amount(pool_id, ASSET_A, ASSET_B, 10 * ONE).unwrap();
+
+		assert_eq!(
+			result,
+			sell_with_static_fee(pool_id, Permill::from_percent(3), 10 * ONE)
+		);
+	});
+}
+
+#[test]
+fn trade_fee_should_increase_with_net_volume_of_traded_assets() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+		set_asset_volume(
+			pool_id,
+			ASSET_B,
+			OracleEntry {
+				amount_in: 0,
+				amount_out: 25 * ONE,
+				liquidity: 100 * ONE,
+			},
+		);
+
+		let result = Stableswap::calculate_out_amount(pool_id, ASSET_A, ASSET_B, 10 * ONE).unwrap();
+
+		assert_eq!(
+			result,
+			sell_with_static_fee(pool_id, Permill::from_percent(2), 10 * ONE)
+		);
+	});
+}
+
+#[test]
+fn trade_fee_should_not_exceed_max_fee() {
+	pool_with_reserves(300 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+		set_asset_volume(
+			pool_id,
+			ASSET_A,
+			OracleEntry {
+				amount_in: 100 * ONE,
+				amount_out: 0,
+				liquidity: 100 * ONE,
+			},
+		);
+
+		let result = Stableswap::calculate_out_amount(pool_id, ASSET_A, ASSET_B, 10 * ONE).unwrap();
+
+		assert_eq!(result, sell_with_static_fee(pool_id, BOUNDS.max_fee, 10 * ONE));
+	});
+}
+
+#[test]
+fn buy_should_use_dynamic_fee_when_pool_has_dynamic_fees() {
+	// imbalance = (300 - 100) / 400 = 0.5
+	pool_with_reserves(300 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+		let (expected_in, expected_fee) =
+			hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&[AssetReserve::new(300 * ONE, 12), AssetReserve::new(100 * ONE, 12)],
+				0,
+				1,
+				10 * ONE,
+				100,
+				Permill::from_percent(3),
+			)
+			.unwrap();
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_B,
+			ASSET_A,
+			10 * ONE,
+			200 * ONE,
+		));
+
+		assert_balance!(BOB, ASSET_A, 200 * ONE - expected_in);
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			pool_id,
+			asset_in: ASSET_A,
+			asset_out: ASSET_B,
+			amount_in: expected_in,
+			amount_out: 10 * ONE,
+			fee: expected_fee,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn dynamic_fees_should_be_removed_when_pool_is_destroyed() {
+	pool_with_reserves(100 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![AssetAmount::new(ASSET_A, 0), AssetAmount::new(ASSET_B, 0)]
+				.try_into()
+				.unwrap(),
+		));
+
+		assert_eq!(Stableswap::pools(pool_id), None);
+		assert_eq!(Stableswap::pool_dynamic_fees(pool_id), None);
+	});
+}
+
+#[test]
+fn pool_snapshot_should_contain_dynamic_fee_when_pool_has_dynamic_fees() {
+	// imbalance = (300 - 100) / 400 = 0.5
+	pool_with_reserves(300 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::set_pool_dynamic_fees(
+			RuntimeOrigin::root(),
+			pool_id,
+			Some(BOUNDS)
+		));
+
+		let snapshot = Stableswap::pool_snapshot(pool_id).unwrap();
+
+		assert_eq!(snapshot.fee, Permill::from_percent(3));
+	});
+}
+
+#[test]
+fn pool_snapshot_should_contain_static_fee_when_pool_has_no_dynamic_fees() {
+	pool_with_reserves(300 * ONE, 100 * ONE).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		assert_ok!(Stableswap::update_pool_fee(
+			RuntimeOrigin::root(),
+			pool_id,
+			Permill::from_percent(2)
+		));
+
+		let snapshot = Stableswap::pool_snapshot(pool_id).unwrap();
+
+		assert_eq!(snapshot.fee, Permill::from_percent(2));
+	});
+}
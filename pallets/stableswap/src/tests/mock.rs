@@ -73,6 +73,7 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = const { RefCell::new(Vec::new()) };
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = const { RefCell::new(None) };
+	pub static ASSET_VOLUMES: RefCell<HashMap<(AssetId, AssetId), OracleEntry>> = RefCell::new(HashMap::default());
 }
 
 construct_runtime!(
@@ -186,6 +187,7 @@ impl Config for Test {
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type VolumeProvider = DummyVolumeProvider;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
@@ -215,6 +217,9 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ASSET_VOLUMES.with(|v| {
+			v.borrow_mut().clear();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -316,7 +321,8 @@ impl ExtBuilder {
 
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
-use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use crate::types::{AssetAmount, PoolInfo, PoolState, PoolVolumeProvider, StableswapHooks};
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use hydradx_traits::{AccountIdFor, Inspect};
 use sp_runtime::traits::Zero;
@@ -434,6 +440,20 @@ impl StableswapHooks<AssetId> for DummyHookAdapter {
 	}
 }
 
+pub struct DummyVolumeProvider;
+
+impl PoolVolumeProvider<AssetId> for DummyVolumeProvider {
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<OracleEntry> {
+		ASSET_VOLUMES.with(|v| v.borrow().get(&(pool_id, asset_id)).cloned())
+	}
+}
+
+pub(crate) fn set_asset_volume(pool_id: AssetId, asset_id: AssetId, volume: OracleEntry) {
+	ASSET_VOLUMES.with(|v| {
+		v.borrow_mut().insert((pool_id, asset_id), volume);
+	});
+}
+
 pub(crate) fn last_liquidity_changed_hook_state() -> Option<(AssetId, PoolState<AssetId>)> {
 	LAST_LIQUDITY_CHANGE_HOOK.with(|v| v.borrow().clone())
 }
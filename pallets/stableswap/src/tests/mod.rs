@@ -6,11 +6,14 @@ mod add_liquidity;
 mod amplification;
 mod calculate_spot_price;
 mod creation;
+mod dynamic_fees;
 mod hooks;
 mod invariants;
 pub(crate) mod mock;
 mod price;
+mod quote;
 mod remove_liquidity;
+mod remove_liquidity_imbalanced;
 mod trades;
 mod update_pool;
 
@@ -0,0 +1,163 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolAssetState, PoolInfo, PoolSnapshot, Tradability};
+use crate::{assert_balance, Error};
+use std::num::NonZeroU16;
+
+use frame_support::assert_ok;
+use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, 1, 200 * ONE),
+			(BOB, 2, 200_000_000),
+			(ALICE, 1, 200 * ONE),
+			(ALICE, 2, 200_000_000),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), 2, 6)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![1, 2].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![AssetAmount::new(1, 100 * ONE), AssetAmount::new(2, 100_000_000)],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn pool_snapshot_should_return_normalized_reserves_and_current_state() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			2,
+			Tradability::SELL
+		));
+
+		assert_eq!(
+			Stableswap::pool_snapshot(pool_id),
+			Some(PoolSnapshot {
+				pool_id,
+				assets: vec![
+					PoolAssetState {
+						asset_id: 1,
+						reserve: 100 * ONE,
+						decimals: 12,
+						normalized_reserve: 100 * ONE * 1_000_000,
+						tradability: Tradability::default(),
+					},
+					PoolAssetState {
+						asset_id: 2,
+						reserve: 100_000_000,
+						decimals: 6,
+						normalized_reserve: 100 * ONE * 1_000_000,
+						tradability: Tradability::SELL,
+					},
+				],
+				amplification: 100,
+				fee: Permill::from_percent(1),
+				share_issuance: Tokens::total_issuance(pool_id),
+			})
+		);
+		assert_eq!(Stableswap::pool_snapshots().len(), 1);
+	});
+}
+
+#[test]
+fn pool_snapshot_should_return_none_when_pool_does_not_exist() {
+	ext().execute_with(|| {
+		assert_eq!(Stableswap::pool_snapshot(1000), None);
+	});
+}
+
+#[test]
+fn calculate_out_amount_should_return_same_amount_as_sell() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let (amount_out, _) = Stableswap::calculate_out_amount(pool_id, 1, 2, 10 * ONE).unwrap();
+
+		assert_ok!(Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, 1, 2, 10 * ONE, 0));
+
+		assert_balance!(BOB, 2, 200_000_000 + amount_out);
+	});
+}
+
+#[test]
+fn calculate_in_amount_should_return_same_amount_as_buy() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let (amount_in, _) = Stableswap::calculate_in_amount(pool_id, 1, 2, 10_000_000).unwrap();
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			2,
+			1,
+			10_000_000,
+			u128::MAX
+		));
+
+		assert_balance!(BOB, 1, 200 * ONE - amount_in);
+	});
+}
+
+#[test]
+fn calculate_shares_should_return_same_amount_as_add_liquidity() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let assets = vec![AssetAmount::new(1, 10 * ONE)];
+
+		let shares = Stableswap::calculate_shares(pool_id, &assets).unwrap();
+
+		assert_ok!(Stableswap::add_liquidity(RuntimeOrigin::signed(BOB), pool_id, assets));
+
+		assert_balance!(BOB, pool_id, shares);
+	});
+}
+
+#[test]
+fn calculate_remove_liquidity_one_asset_should_return_same_amount_as_remove_liquidity() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let share_amount = Tokens::free_balance(pool_id, &ALICE) / 10;
+
+		let (amount, _) = Stableswap::calculate_remove_liquidity_one_asset(pool_id, 1, share_amount).unwrap();
+
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			1,
+			share_amount,
+			0
+		));
+
+		assert_balance!(ALICE, 1, 100 * ONE + amount);
+	});
+}
+
+#[test]
+fn calculate_remove_liquidity_one_asset_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_eq!(
+			Stableswap::calculate_remove_liquidity_one_asset(pool_id, 1000, ONE),
+			Err(Error::<Test>::AssetNotInPool.into())
+		);
+	});
+}
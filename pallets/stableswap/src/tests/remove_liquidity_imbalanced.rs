@@ -0,0 +1,259 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo};
+use crate::{assert_balance, Error};
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::Permill;
+use std::num::NonZeroU16;
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const ASSET_D: AssetId = 4;
+
+fn pool_with_liquidity(fee: Permill) -> ExtBuilder {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_B, 100 * ONE),
+			(BOB, ASSET_C, 100 * ONE),
+			(ALICE, ASSET_A, 1_000 * ONE),
+			(ALICE, ASSET_B, 1_000 * ONE),
+			(ALICE, ASSET_C, 1_000 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), ASSET_C, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B, ASSET_C].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee,
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 1_000 * ONE),
+					AssetAmount::new(ASSET_B, 1_000 * ONE),
+					AssetAmount::new(ASSET_C, 1_000 * ONE),
+				],
+			},
+		)
+}
+
+fn add_bob_liquidity(pool_id: AssetId) {
+	assert_ok!(Stableswap::add_liquidity(
+		RuntimeOrigin::signed(BOB),
+		pool_id,
+		vec![
+			AssetAmount::new(ASSET_A, 100 * ONE),
+			AssetAmount::new(ASSET_B, 100 * ONE),
+			AssetAmount::new(ASSET_C, 100 * ONE),
+		]
+	));
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_work() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_ok!(Stableswap::remove_liquidity_imbalanced(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(ASSET_A, 50 * ONE), AssetAmount::new(ASSET_C, 20 * ONE)],
+			shares,
+		));
+
+		assert_balance!(BOB, ASSET_A, 50 * ONE);
+		assert_balance!(BOB, ASSET_B, 0);
+		assert_balance!(BOB, ASSET_C, 20 * ONE);
+		let remaining_shares = Tokens::free_balance(pool_id, &BOB);
+		assert!(remaining_shares < shares);
+		assert!(remaining_shares > 0);
+		assert_balance!(pool_account(pool_id), ASSET_A, 1_050 * ONE);
+		assert_balance!(pool_account(pool_id), ASSET_B, 1_100 * ONE);
+		assert_balance!(pool_account(pool_id), ASSET_C, 1_080 * ONE);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_burn_same_shares_as_withdraw_asset_amount_when_single_asset() {
+	let amount = 30 * ONE;
+
+	let shares_used_by_withdraw = pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+		assert_ok!(Stableswap::withdraw_asset_amount(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_B,
+			amount,
+			shares,
+		));
+		shares - Tokens::free_balance(pool_id, &BOB)
+	});
+
+	let shares_used_by_imbalanced = pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+		assert_ok!(Stableswap::remove_liquidity_imbalanced(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			vec![AssetAmount::new(ASSET_B, amount)],
+			shares,
+		));
+		shares - Tokens::free_balance(pool_id, &BOB)
+	});
+
+	assert_eq!(shares_used_by_imbalanced, shares_used_by_withdraw);
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_burn_more_shares_when_fee_is_set() {
+	let assets = vec![AssetAmount::new(ASSET_A, 60 * ONE), AssetAmount::new(ASSET_B, 10 * ONE)];
+
+	let shares_used_without_fee = pool_with_liquidity(Permill::zero()).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+		assert_ok!(Stableswap::remove_liquidity_imbalanced(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			assets.clone(),
+			shares,
+		));
+		shares - Tokens::free_balance(pool_id, &BOB)
+	});
+
+	let shares_used_with_fee = pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+		assert_ok!(Stableswap::remove_liquidity_imbalanced(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			assets.clone(),
+			shares,
+		));
+		shares - Tokens::free_balance(pool_id, &BOB)
+	});
+
+	assert!(shares_used_with_fee > shares_used_without_fee);
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_slippage_limit_is_reached() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_A, 50 * ONE), AssetAmount::new(ASSET_C, 20 * ONE)],
+				ONE,
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_asset_is_not_in_pool() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_A, 10 * ONE), AssetAmount::new(ASSET_D, 10 * ONE)],
+				shares,
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_asset_is_duplicated() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_A, 10 * ONE), AssetAmount::new(ASSET_A, 10 * ONE)],
+				shares,
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_amount_is_zero() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_A, 10 * ONE), AssetAmount::new(ASSET_B, 0)],
+				shares,
+			),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_amount_exceeds_reserve() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		add_bob_liquidity(pool_id);
+		let shares = Tokens::free_balance(pool_id, &BOB);
+
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_A, 1_100 * ONE)],
+				shares,
+			),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_imbalanced_should_fail_when_pool_does_not_exist() {
+	pool_with_liquidity(Permill::from_percent(1)).build().execute_with(|| {
+		assert_noop!(
+			Stableswap::remove_liquidity_imbalanced(
+				RuntimeOrigin::signed(BOB),
+				100,
+				vec![AssetAmount::new(ASSET_A, 10 * ONE)],
+				ONE,
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
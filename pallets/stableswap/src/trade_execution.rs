@@ -1,11 +1,12 @@
 use crate::types::AssetAmount;
 use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
 use hydra_dx_math::stableswap::types::AssetReserve;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{take_pools, ExecutorError, PoolType, PoolsProvider, TradeExecution};
 use orml_traits::MultiCurrency;
 use sp_core::Get;
 use sp_runtime::{ArithmeticError, DispatchError, FixedU128};
 use sp_std::vec;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T>
 where
@@ -246,3 +247,17 @@ where
 		}
 	}
 }
+
+impl<T: Config> PoolsProvider<T::AssetId> for Pallet<T> {
+	fn pools(max_assets: u32) -> Vec<(PoolType<T::AssetId>, Vec<T::AssetId>)> {
+		take_pools(
+			Pools::<T>::iter().map(|(pool_id, pool)| {
+				// Share asset is tradeable too, as adding and removing liquidity is supported by the router
+				let mut assets = pool.assets.to_vec();
+				assets.push(pool_id);
+				(PoolType::Stableswap(pool_id), assets)
+			}),
+			max_assets,
+		)
+	}
+}
@@ -13,6 +13,7 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
+use hydra_dx_math::dynamic_fees::types::OracleEntry;
 use hydra_dx_math::stableswap::types::AssetReserve;
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
@@ -35,6 +36,22 @@ pub struct PoolInfo<AssetId, BlockNumber> {
 	pub fee: Permill,
 }
 
+/// Dynamic fee bounds of a pool.
+/// `min_fee`: fee applied when pool is balanced and there is no net trade volume
+/// `max_fee`: maximum fee applied when pool is imbalanced or net trade volume is high
+#[derive(Encode, Decode, Eq, PartialEq, Clone, Copy, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct DynamicFeeBounds {
+	pub min_fee: Permill,
+	pub max_fee: Permill,
+}
+
+impl DynamicFeeBounds {
+	pub(crate) fn is_valid(&self) -> bool {
+		self.min_fee <= self.max_fee
+	}
+}
+
 fn has_unique_elements<T>(iter: &mut T) -> bool
 where
 	T: Iterator,
@@ -120,6 +137,35 @@ impl Default for Tradability {
 	}
 }
 
+/// State of a pool asset.
+/// `reserve`: balance of the asset in the pool account
+/// `decimals`: decimals of the asset
+/// `normalized_reserve`: reserve converted to 18 decimals, as used in pool calculations
+/// `tradability`: tradable state of the asset in the pool
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct PoolAssetState<AssetId> {
+	pub asset_id: AssetId,
+	pub reserve: Balance,
+	pub decimals: u8,
+	pub normalized_reserve: Balance,
+	pub tradability: Tradability,
+}
+
+/// Current state of a pool.
+/// `pool_id`: pool id, which is also the share asset id
+/// `assets`: state of pool assets
+/// `amplification`: current amplification
+/// `fee`: trade fee
+/// `share_issuance`: total issuance of the share asset
+#[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo)]
+pub struct PoolSnapshot<AssetId> {
+	pub pool_id: AssetId,
+	pub assets: Vec<PoolAssetState<AssetId>>,
+	pub amplification: Balance,
+	pub fee: Permill,
+	pub share_issuance: Balance,
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 pub trait BenchmarkHelper<AssetId> {
 	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
@@ -167,3 +213,15 @@ impl<AssetId> StableswapHooks<AssetId> for () {
 		Weight::zero()
 	}
 }
+
+/// Interface for retrieving trade volume of pool assets, used to calculate dynamic fee of a pool.
+pub trait PoolVolumeProvider<AssetId> {
+	/// Returns amount in, amount out and liquidity of `asset_id` in pool `pool_id`.
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<OracleEntry>;
+}
+
+impl<AssetId> PoolVolumeProvider<AssetId> for () {
+	fn asset_volume(_pool_id: AssetId, _asset_id: AssetId) -> Option<OracleEntry> {
+		None
+	}
+}
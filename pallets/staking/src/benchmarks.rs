@@ -198,5 +198,92 @@ benchmarks! {
 		assert!(old_caller_1_balance < T::Currency::free_balance(hdx, &caller_1))
 	}
 
+	decrease_stake {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+		let amount = 20_000 * UNIT;
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		generate_votes::<T>(position_id, T::MaxVotes::get());
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(2);
+	}: _(RawOrigin::Signed(caller_1.clone()), position_id, amount)
+	verify {
+		let staked_amount = Pallet::<T>::positions(position_id).unwrap().stake;
+		assert_eq!(staked_amount, 30_000 * UNIT)
+	}
+
+	withdraw_unbonded {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 30_000 * UNIT)?;
+
+		let now = T::BlockNumberProvider::current_block_number();
+		let mut chunks = Vec::new();
+		for _ in 0..T::MaxUnbondingChunks::get() {
+			chunks.push(UnbondingChunk::new(1_000 * UNIT, now));
+		}
+		crate::Unbonding::<T>::insert(&caller_1, BoundedVec::truncate_from(chunks));
+		Pallet::<T>::update_staking_lock(&caller_1, 30_000 * UNIT)?;
+	}: _(RawOrigin::Signed(caller_1.clone()))
+	verify {
+		assert!(Pallet::<T>::unbonding(&caller_1).is_empty())
+	}
+
+	set_auto_compound {
+		let caller: T::AccountId = account("caller", 0, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller).unwrap().unwrap();
+	}: _(RawOrigin::Signed(caller.clone()), position_id, true)
+	verify {
+		assert!(Pallet::<T>::auto_compound(position_id).is_some())
+	}
+
+	compound_rewards {
+		let caller_0: T::AccountId = account("caller", 0, 1);
+		let caller_1: T::AccountId = account("caller", 1, 1);
+		let hdx = T::NativeAssetId::get();
+
+		T::Currency::update_balance(hdx, &caller_0, (100_000 * UNIT) as i128)?;
+		T::Currency::update_balance(hdx, &caller_1, (100_000 * UNIT) as i128)?;
+
+		init_staking::<T>(1_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_0).into(), 50_000 * UNIT)?;
+		Pallet::<T>::stake(RawOrigin::Signed(caller_1.clone()).into(), 50_000 * UNIT)?;
+
+		let position_id = Pallet::<T>::get_user_position_id(&caller_1).unwrap().unwrap();
+		Pallet::<T>::set_auto_compound(RawOrigin::Signed(caller_1.clone()).into(), position_id, true)?;
+
+		add_staking_rewards::<T>(20_000 * UNIT)?;
+		run_periods::<T>(<u128 as TryInto<u32>>::try_into(T::UnclaimablePeriods::get()).unwrap() + 1_u32);
+	}: {
+		Pallet::<T>::compound_position(position_id)?;
+	}
+	verify {
+		assert!(Pallet::<T>::positions(position_id).unwrap().stake > 50_000 * UNIT)
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
@@ -54,13 +54,14 @@ where
 			};
 
 			// We are capping vote by min(position stake, user's balance - vested amount - locked
-			// rewards).
+			// rewards - unbonding stake).
 			// Sub of vested and lockek rewards is necessary because locks overlay so users may end
 			// up in the situation where portion of the staking lock is also vested or locked
 			// rewads and we don't want to assign points for it.
 			let max_vote = T::Currency::free_balance(T::NativeAssetId::get(), who)
 				.saturating_sub(T::Vesting::locked(who.clone()))
 				.saturating_sub(position.accumulated_locked_rewards)
+				.saturating_sub(Pallet::<T>::get_unbonding_amount(who))
 				.min(position.stake);
 			let staking_vote = Vote {
 				amount: amount.min(position.stake).min(max_vote),
@@ -18,13 +18,14 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use crate::traits::{ActionData, DemocracyReferendum, PayablePercentage, VestingDetails};
-use crate::types::{Action, Balance, Period, Point, Position, StakingData, Voting};
+use crate::types::{Action, Balance, Period, Point, Position, StakingData, UnbondingChunk, Voting};
 use frame_support::ensure;
 use frame_support::{
 	pallet_prelude::DispatchResult,
 	pallet_prelude::*,
 	traits::nonfungibles::{Create, Inspect, InspectEnumerable, Mutate},
 	traits::{DefensiveOption, LockIdentifier},
+	transactional,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use hydra_dx_math::staking as math;
@@ -37,6 +38,7 @@ use sp_runtime::{
 };
 use sp_runtime::{DispatchError, FixedPointNumber, FixedU128};
 use sp_std::num::NonZeroU128;
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod tests;
@@ -175,6 +177,15 @@ pub mod pallet {
 		/// Provides information about amount of vested tokens.
 		type Vesting: VestingDetails<Self::AccountId, Balance>;
 
+		/// Number of blocks tokens removed by `decrease_stake` stay locked before they can be
+		/// withdrawn. Tokens are unlocked immediately if set to zero.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<BlockNumberFor<Self>>;
+
+		/// Max number of unbonding chunks the user can have at any time.
+		#[pallet::constant]
+		type MaxUnbondingChunks: Get<u32>;
+
 		#[cfg(feature = "runtime-benchmarks")]
 		/// Max mumber of locks per account.  It's used in on_vote_worst_case benchmarks.
 		type MaxLocks: Get<u32>;
@@ -218,6 +229,28 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Stake removed from the positions which is still locked until the end of the unbonding period.
+	#[pallet::getter(fn unbonding)]
+	pub(super) type Unbonding<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<UnbondingChunk<BlockNumberFor<T>>, T::MaxUnbondingChunks>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Positions with enabled auto-compounding of rewards and period they were last compounded in
+	/// by `on_idle`.
+	#[pallet::getter(fn auto_compound)]
+	pub(super) type AutoCompound<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Period, OptionQuery>;
+
+	#[pallet::storage]
+	/// Last position processed by `on_idle` auto-compounding.
+	#[pallet::getter(fn auto_compound_cursor)]
+	pub(super) type AutoCompoundCursor<T: Config> = StorageValue<_, T::PositionItemId, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -257,6 +290,36 @@ pub mod pallet {
 			unlocked_stake: Balance,
 		},
 
+		/// Staked amount for existing position was decreased.
+		StakeDecreased {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			total_stake: Balance,
+			locked_rewards: Balance,
+			slashed_points: Point,
+			payable_percentage: FixedU128,
+			unlock_at: BlockNumberFor<T>,
+		},
+
+		/// Unbonded stake was unlocked.
+		UnbondedWithdrawn { who: T::AccountId, amount: Balance },
+
+		/// Auto-compounding of rewards was enabled or disabled for the position.
+		AutoCompoundSet {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			enabled: bool,
+		},
+
+		/// Rewards were added to position's stake.
+		RewardsCompounded {
+			who: T::AccountId,
+			position_id: T::PositionItemId,
+			compounded: Balance,
+			total_stake: Balance,
+		},
+
 		/// Staking was initialized.
 		StakingInitialized { non_dustable_balance: Balance },
 
@@ -305,6 +368,15 @@ pub mod pallet {
 		/// Position contains processed votes. Removed these votes first before increasing stake or claiming.
 		ExistingProcessedVotes,
 
+		/// Remaining stake would be lower than amount used by position's active votes.
+		StakeLockedByVotes,
+
+		/// Maximum amount of unbonding chunks was reached.
+		MaxUnbondingChunksReached,
+
+		/// There is no unbonded stake to withdraw.
+		NothingToWithdraw,
+
 		/// Action cannot be completed because unexpected error has occurred. This should be reported
 		/// to protocol maintainers.
 		InconsistentState(InconsistentStateError),
@@ -384,7 +456,8 @@ pub mod pallet {
 		/// Emits `PositionCreated` event when successful.
 		///
 		#[pallet::call_index(1)]
-		#[pallet::weight(<T as Config>::WeightInfo::stake())]
+		// Unbonding queue read is not part of the benchmarked weight.
+		#[pallet::weight(<T as Config>::WeightInfo::stake().saturating_add(T::DbWeight::get().reads(1)))]
 		pub fn stake(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -404,7 +477,7 @@ pub mod pallet {
 				let position_id =
 					Self::create_position_and_mint_nft(&who, amount, staking.accumulated_reward_per_stake)?;
 
-				T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who, amount)?;
+				Self::update_staking_lock(&who, amount)?;
 
 				staking.add_stake(amount)?;
 
@@ -435,7 +508,8 @@ pub mod pallet {
 		/// Emits `StakeAdded` event when successful.
 		///
 		#[pallet::call_index(2)]
-		#[pallet::weight(<T as Config>::WeightInfo::increase_stake())]
+		// Unbonding queue and auto-compound reads are not part of the benchmarked weight.
+		#[pallet::weight(<T as Config>::WeightInfo::increase_stake().saturating_add(T::DbWeight::get().reads(2)))]
 		pub fn increase_stake(origin: OriginFor<T>, position_id: T::PositionItemId, amount: Balance) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -476,15 +550,23 @@ pub mod pallet {
 					)
 					.ok_or(Error::<T>::Arithmetic)?;
 
-					if !rewards.is_zero() {
+					let locked_rewards = if !rewards.is_zero() {
 						let pot = Self::pot_account_id();
 						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
 
-						position.accumulated_locked_rewards = position
-							.accumulated_locked_rewards
-							.checked_add(rewards)
-							.ok_or(Error::<T>::Arithmetic)?;
-					}
+						if AutoCompound::<T>::contains_key(position_id) {
+							Self::compound_rewards(&who, position_id, position, staking, rewards)?;
+							Zero::zero()
+						} else {
+							position.accumulated_locked_rewards = position
+								.accumulated_locked_rewards
+								.checked_add(rewards)
+								.ok_or(Error::<T>::Arithmetic)?;
+							rewards
+						}
+					} else {
+						Zero::zero()
+					};
 
 					position.accumulated_unpaid_rewards = unpaid_rewards;
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
@@ -514,19 +596,14 @@ pub mod pallet {
 
 					staking.add_stake(amount)?;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
-						&who,
-						position.get_total_locked()?,
-					)?;
+					Self::update_staking_lock(&who, position.get_total_locked()?)?;
 
 					Self::deposit_event(Event::StakeAdded {
 						who,
 						position_id,
 						stake: amount,
 						total_stake: position.stake,
-						locked_rewards: rewards,
+						locked_rewards,
 						slashed_points: slash_points,
 						payable_percentage,
 					});
@@ -551,7 +628,8 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` event when successful.
 		///
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		// Unbonding queue read is not part of the benchmarked weight.
+		#[pallet::weight(<T as Config>::WeightInfo::claim().saturating_add(T::DbWeight::get().reads(1)))]
 		pub fn claim(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -614,12 +692,7 @@ pub mod pallet {
 						};
 					position.reward_per_stake = staking.accumulated_reward_per_stake;
 
-					T::Currency::set_lock(
-						STAKING_LOCK_ID,
-						T::NativeAssetId::get(),
-						&who,
-						position.get_total_locked()?,
-					)?;
+					Self::update_staking_lock(&who, position.get_total_locked()?)?;
 
 					staking.pot_reserved_balance = staking
 						.pot_reserved_balance
@@ -656,7 +729,8 @@ pub mod pallet {
 		/// Emits `RewardsClaimed` and `Unstaked` events when successful.
 		///
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::unstake())]
+		// Unbonding queue read and auto-compound removal are not part of the benchmarked weight.
+		#[pallet::weight(<T as Config>::WeightInfo::unstake().saturating_add(T::DbWeight::get().reads_writes(1, 1)))]
 		pub fn unstake(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
@@ -711,7 +785,8 @@ pub mod pallet {
 						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
 
 					T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
-					T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), &who)?;
+					// Stake which is still unbonding stays locked.
+					Self::update_staking_lock(&who, Zero::zero())?;
 
 					Self::deposit_event(Event::RewardsClaimed {
 						who: who.clone(),
@@ -731,16 +806,330 @@ pub mod pallet {
 					});
 
 					PositionVotes::<T>::remove(position_id);
+					AutoCompound::<T>::remove(position_id);
 					*maybe_position = None;
 
 					Ok(())
 				})
 			})
 		}
+
+		/// Extrinsic to decrease staked amount of existing staking position by specified `amount`.
+		///
+		/// `decrease_stake` removes `amount` from the position specified by `position_id` while
+		/// position itself, its action points and reward per stake are preserved.
+		/// Rewards accumulated until this point are paid and locked to the user and position's
+		/// points are slashed proportionally to the removed stake.
+		///
+		/// Removed `amount` is moved to the unbonding queue and stays locked for `UnbondingPeriod`
+		/// blocks. It's unlocked immediately if `UnbondingPeriod` is zero.
+		///
+		/// Remaining stake must be at least `MinStake` and can't be lower than the amount used by any
+		/// of the position's active votes. Position can't have processed votes.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position which stake will be decreased.
+		/// - `amount`: Amount of native asset to be removed from staked amount.
+		///
+		/// Emits `StakeDecreased` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::decrease_stake())]
+		pub fn decrease_stake(origin: OriginFor<T>, position_id: T::PositionItemId, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::InsufficientStake);
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			use frame_support::StorageDoubleMap;
+			ensure!(
+				!ProcessedVotes::<T>::contains_prefix(&who),
+				Error::<T>::ExistingProcessedVotes
+			);
+
+			Staking::<T>::try_mutate(|staking| {
+				Self::update_rewards(staking)?;
+
+				Positions::<T>::try_mutate(position_id, |maybe_position| {
+					let position = maybe_position
+						.as_mut()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+					let remaining_stake = position
+						.stake
+						.checked_sub(amount)
+						.ok_or(Error::<T>::InsufficientStake)?;
+					ensure!(remaining_stake >= T::MinStake::get(), Error::<T>::InsufficientStake);
+
+					Self::process_votes(&who, position_id, position)?;
+
+					// Points for active votes are calculated from the position's stake so vote can't
+					// use more than the remaining stake.
+					ensure!(
+						PositionVotes::<T>::get(position_id)
+							.votes
+							.iter()
+							.all(|(_, vote)| vote.amount <= remaining_stake),
+						Error::<T>::StakeLockedByVotes
+					);
+
+					let current_period = Self::get_current_period()
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+					let created_at = Self::get_period_number(position.created_at)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					let (rewards, unpaid_rewards, payable_percentage) = Self::calculate_rewards(
+						position,
+						staking.accumulated_reward_per_stake,
+						current_period,
+						created_at,
+					)
+					.ok_or(Error::<T>::Arithmetic)?;
+
+					let locked_rewards = if !rewards.is_zero() {
+						let pot = Self::pot_account_id();
+						T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
+
+						if AutoCompound::<T>::contains_key(position_id) {
+							Self::compound_rewards(&who, position_id, position, staking, rewards)?;
+							Zero::zero()
+						} else {
+							position.accumulated_locked_rewards = position
+								.accumulated_locked_rewards
+								.checked_add(rewards)
+								.ok_or(Error::<T>::Arithmetic)?;
+							rewards
+						}
+					} else {
+						Zero::zero()
+					};
+
+					position.accumulated_unpaid_rewards = unpaid_rewards;
+					position.reward_per_stake = staking.accumulated_reward_per_stake;
+
+					let points =
+						Self::get_points(position, current_period, created_at).ok_or(Error::<T>::Arithmetic)?;
+					let slash_points = math::calculate_slashed_points(
+						points,
+						position.stake,
+						amount,
+						T::CurrentStakeWeight::get(),
+						T::MinSlash::get(&payable_percentage),
+					)
+					.ok_or(Error::<T>::Arithmetic)?;
+
+					position.accumulated_slash_points = position
+						.accumulated_slash_points
+						.checked_add(slash_points)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					position.stake = position.stake.checked_sub(amount).ok_or(Error::<T>::Arithmetic)?;
+
+					staking.pot_reserved_balance = staking
+						.pot_reserved_balance
+						.checked_sub(rewards)
+						.ok_or(Error::<T>::Arithmetic)?;
+
+					staking.total_stake = staking
+						.total_stake
+						.checked_sub(amount)
+						.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+					let now = T::BlockNumberProvider::current_block_number();
+					let unlock_at = now.saturating_add(T::UnbondingPeriod::get());
+					if unlock_at > now {
+						Unbonding::<T>::try_mutate(&who, |chunks| -> DispatchResult {
+							match chunks.last_mut() {
+								Some(chunk) if chunk.unlock_at == unlock_at => {
+									chunk.amount = chunk.amount.checked_add(amount).ok_or(Error::<T>::Arithmetic)?;
+								}
+								_ => chunks
+									.try_push(UnbondingChunk::new(amount, unlock_at))
+									.map_err(|_| Error::<T>::MaxUnbondingChunksReached)?,
+							}
+							Ok(())
+						})?;
+					}
+
+					// NOTE: vested tokens are excluded from staking by `ensure_stakeable_balance` so
+					// unlocked stake is never vested.
+					Self::update_staking_lock(&who, position.get_total_locked()?)?;
+
+					Self::deposit_event(Event::StakeDecreased {
+						who,
+						position_id,
+						amount,
+						total_stake: position.stake,
+						locked_rewards,
+						slashed_points: slash_points,
+						payable_percentage,
+						unlock_at,
+					});
+
+					Ok(())
+				})
+			})
+		}
+
+		/// Unlock all the stake from caller's unbonding queue which reached the end of the
+		/// unbonding period.
+		///
+		/// Caller doesn't need to own a staking position, stake unbonding from already destroyed
+		/// position can be withdrawn too.
+		///
+		/// Emits `UnbondedWithdrawn` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_unbonded())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let amount = Unbonding::<T>::try_mutate_exists(&who, |maybe_chunks| -> Result<Balance, DispatchError> {
+				let chunks = maybe_chunks.as_mut().ok_or(Error::<T>::NothingToWithdraw)?;
+
+				let mut unbonded = Balance::zero();
+				chunks.retain(|chunk| {
+					if chunk.unlock_at <= now {
+						unbonded = unbonded.saturating_add(chunk.amount);
+						false
+					} else {
+						true
+					}
+				});
+
+				ensure!(!unbonded.is_zero(), Error::<T>::NothingToWithdraw);
+
+				if chunks.is_empty() {
+					*maybe_chunks = None;
+				}
+
+				Ok(unbonded)
+			})?;
+
+			let position_locked = match Self::get_user_position_id(&who)? {
+				Some(position_id) => Positions::<T>::get(position_id)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?
+					.get_total_locked()?,
+				None => Zero::zero(),
+			};
+
+			Self::update_staking_lock(&who, position_locked)?;
+
+			Self::deposit_event(Event::UnbondedWithdrawn { who, amount });
+
+			Ok(())
+		}
+
+		/// Enable or disable auto-compounding of rewards for the staking position.
+		///
+		/// Rewards of the position with auto-compounding enabled are added to its stake instead of
+		/// being locked when the position's stake is changed, and once per period in `on_idle`.
+		/// Compounded rewards don't slash position's points.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of the position.
+		/// - `enabled`: Whether rewards should be compounded.
+		///
+		/// Emits `AutoCompoundSet` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			enabled: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Self::is_initialized(), Error::<T>::NotInitialized);
+
+			ensure!(Self::is_owner(&who, position_id), Error::<T>::Forbidden);
+
+			if enabled {
+				if !AutoCompound::<T>::contains_key(position_id) {
+					AutoCompound::<T>::insert(position_id, Period::zero());
+				}
+			} else {
+				AutoCompound::<T>::remove(position_id);
+			}
+
+			Self::deposit_event(Event::AutoCompoundSet {
+				who,
+				position_id,
+				enabled,
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let compound_weight = Self::compound_position_weight();
+			if compound_weight.is_zero() {
+				return Weight::zero();
+			}
+			let cursor_weight = T::DbWeight::get().reads_writes(2u64, 1u64);
+			let Some(weight_left) = remaining_weight.checked_sub(&cursor_weight) else {
+				return Weight::zero();
+			};
+			let max_by_ref_time = if compound_weight.ref_time().is_zero() {
+				u64::MAX
+			} else {
+				weight_left.ref_time() / compound_weight.ref_time()
+			};
+			let max_by_proof_size = if compound_weight.proof_size().is_zero() {
+				u64::MAX
+			} else {
+				weight_left.proof_size() / compound_weight.proof_size()
+			};
+			let max_compounds = max_by_ref_time.min(max_by_proof_size);
+			if max_compounds.is_zero() {
+				return Weight::zero();
+			}
+			if !Self::is_initialized() {
+				return T::DbWeight::get().reads(1u64);
+			}
+
+			let Some(current_period) = Self::get_current_period() else {
+				return cursor_weight;
+			};
+
+			let positions: Vec<(T::PositionItemId, Period)> = match AutoCompoundCursor::<T>::get() {
+				Some(last) => AutoCompound::<T>::iter_from(AutoCompound::<T>::hashed_key_for(last))
+					.take(max_compounds as usize)
+					.collect(),
+				None => AutoCompound::<T>::iter().take(max_compounds as usize).collect(),
+			};
+
+			match positions.last() {
+				Some((last, _)) if positions.len() as u64 == max_compounds => AutoCompoundCursor::<T>::put(last),
+				_ => AutoCompoundCursor::<T>::kill(),
+			}
+
+			let mut compounded = 0u64;
+			for (position_id, last_compounded) in positions.iter() {
+				if *last_compounded < current_period {
+					// Position is marked as processed even if compounding fails so it's retried in the
+					// next period.
+					let _ = Self::compound_position(*position_id);
+					AutoCompound::<T>::insert(position_id, current_period);
+					compounded += 1;
+				}
+			}
+
+			// Positions already compounded in this period are only read.
+			compound_weight
+				.saturating_mul(compounded)
+				.saturating_add(T::DbWeight::get().reads((positions.len() as u64).saturating_sub(compounded)))
+				.saturating_add(cursor_weight)
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -757,7 +1146,8 @@ impl<T: Config> Pallet<T> {
 		let free_balance = T::Currency::free_balance(T::NativeAssetId::get(), who);
 		let staked = position
 			.map(|p| p.stake.saturating_add(p.accumulated_locked_rewards))
-			.unwrap_or_default();
+			.unwrap_or_default()
+			.saturating_add(Self::get_unbonding_amount(who));
 		let vested = T::Vesting::locked(who.clone());
 
 		//NOTE: locks overlay so vested + staked can be bigger than free_balance
@@ -995,6 +1385,108 @@ impl<T: Config> Pallet<T> {
 	fn is_initialized() -> bool {
 		Staking::<T>::exists()
 	}
+
+	/// Adds `rewards` already paid to `who` to the position's stake. Compounded rewards don't slash
+	/// position's points.
+	fn compound_rewards(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+		position: &mut Position<BlockNumberFor<T>>,
+		staking: &mut StakingData,
+		rewards: Balance,
+	) -> DispatchResult {
+		position.stake = position.stake.checked_add(rewards).ok_or(Error::<T>::Arithmetic)?;
+		staking.add_stake(rewards)?;
+
+		Self::deposit_event(Event::RewardsCompounded {
+			who: who.clone(),
+			position_id,
+			compounded: rewards,
+			total_stake: position.stake,
+		});
+
+		Ok(())
+	}
+
+	/// Weight of auto-compounding one position in `on_idle`.
+	pub(crate) fn compound_position_weight() -> Weight {
+		T::WeightInfo::compound_rewards()
+	}
+
+	/// Pays claimable rewards of the position and adds them to its stake.
+	///
+	/// Returns compounded amount.
+	#[transactional]
+	pub(crate) fn compound_position(position_id: T::PositionItemId) -> Result<Balance, DispatchError> {
+		let who =
+			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &position_id)
+				.ok_or(Error::<T>::PositionNotFound)?;
+
+		Staking::<T>::try_mutate(|staking| {
+			Self::update_rewards(staking)?;
+
+			Positions::<T>::try_mutate(position_id, |maybe_position| {
+				let position = maybe_position
+					.as_mut()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::PositionNotFound.into())?;
+
+				let current_period = Self::get_current_period()
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+				let created_at = Self::get_period_number(position.created_at)
+					.defensive_ok_or::<Error<T>>(InconsistentStateError::Arithmetic.into())?;
+
+				let (rewards, unpaid_rewards, _) = Self::calculate_rewards(
+					position,
+					staking.accumulated_reward_per_stake,
+					current_period,
+					created_at,
+				)
+				.ok_or(Error::<T>::Arithmetic)?;
+
+				position.accumulated_unpaid_rewards = unpaid_rewards;
+				position.reward_per_stake = staking.accumulated_reward_per_stake;
+
+				if rewards.is_zero() {
+					return Ok(Zero::zero());
+				}
+
+				let pot = Self::pot_account_id();
+				T::Currency::transfer(T::NativeAssetId::get(), &pot, &who, rewards)?;
+
+				staking.pot_reserved_balance = staking
+					.pot_reserved_balance
+					.checked_sub(rewards)
+					.ok_or(Error::<T>::Arithmetic)?;
+
+				Self::compound_rewards(&who, position_id, position, staking, rewards)?;
+
+				Self::update_staking_lock(&who, position.get_total_locked()?)?;
+
+				Ok(rewards)
+			})
+		})
+	}
+
+	/// Returns total amount of tokens in `who`'s unbonding queue.
+	pub fn get_unbonding_amount(who: &T::AccountId) -> Balance {
+		Unbonding::<T>::get(who)
+			.iter()
+			.fold(Balance::zero(), |acc, chunk| acc.saturating_add(chunk.amount))
+	}
+
+	/// Sets staking lock to `locked` + tokens in the unbonding queue. Lock is removed if the total
+	/// is zero.
+	fn update_staking_lock(who: &T::AccountId, locked: Balance) -> DispatchResult {
+		let total_locked = locked
+			.checked_add(Self::get_unbonding_amount(who))
+			.ok_or(Error::<T>::Arithmetic)?;
+
+		if total_locked.is_zero() {
+			T::Currency::remove_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who)
+		} else {
+			T::Currency::set_lock(STAKING_LOCK_ID, T::NativeAssetId::get(), who, total_locked)
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -0,0 +1,324 @@
+use super::*;
+
+use frame_support::traits::Hooks;
+use frame_support::weights::Weight;
+use mock::Staking;
+use pretty_assertions::assert_eq;
+
+fn compounded_amount(position_id: PositionId) -> Balance {
+	System::events()
+		.iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Staking(Event::<Test>::RewardsCompounded {
+				position_id: id,
+				compounded,
+				..
+			}) if id == position_id => Some(compounded),
+			_ => None,
+		})
+		.sum()
+}
+
+#[test]
+fn set_auto_compound_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(BOB, 120_000 * ONE, 1_452_987, 0)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::set_auto_compound(RuntimeOrigin::signed(ALICE), bob_position_id, true),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn set_auto_compound_should_work_when_origin_is_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(BOB, 120_000 * ONE, 1_452_987, 0)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+
+			//Assert
+			assert_eq!(Staking::auto_compound(bob_position_id), Some(0));
+			assert_last_event!(Event::<Test>::AutoCompoundSet {
+				who: BOB,
+				position_id: bob_position_id,
+				enabled: true,
+			}
+			.into());
+
+			//Act
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				false
+			));
+
+			//Assert
+			assert_eq!(Staking::auto_compound(bob_position_id), None);
+		});
+}
+
+#[test]
+fn on_idle_should_compound_rewards_without_slashing_points() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+			Positions::<Test>::mutate(bob_position_id, |p| p.as_mut().unwrap().action_points = 50);
+			let position_before = Staking::positions(bob_position_id).unwrap();
+			let total_stake_before = Staking::staking().total_stake;
+
+			//Act
+			Staking::on_idle(1_700_000, Weight::MAX);
+
+			//Assert
+			let compounded = compounded_amount(bob_position_id);
+			assert!(!compounded.is_zero());
+
+			let position = Staking::positions(bob_position_id).unwrap();
+			assert_eq!(position.stake, 120_000 * ONE + compounded);
+			assert_eq!(position.action_points, 50);
+			assert_eq!(
+				position.accumulated_slash_points,
+				position_before.accumulated_slash_points
+			);
+			assert_eq!(position.accumulated_locked_rewards, 0);
+			assert_eq!(
+				position.reward_per_stake,
+				Staking::staking().accumulated_reward_per_stake
+			);
+
+			assert_eq!(Staking::staking().total_stake, total_stake_before + compounded);
+			assert_hdx_lock!(BOB, 120_000 * ONE + compounded, STAKING_LOCK);
+			assert_eq!(Staking::auto_compound(bob_position_id), Some(170));
+		});
+}
+
+#[test]
+fn on_idle_should_compound_position_only_once_per_period() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+			Staking::on_idle(1_700_000, Weight::MAX);
+			let stake = Staking::positions(bob_position_id).unwrap().stake;
+
+			//Act
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_001);
+			Staking::on_idle(1_700_001, Weight::MAX);
+
+			//Assert
+			assert_eq!(Staking::positions(bob_position_id).unwrap().stake, stake);
+
+			//Act
+			set_block_number(1_710_000);
+			Staking::on_idle(1_710_000, Weight::MAX);
+
+			//Assert
+			assert!(Staking::positions(bob_position_id).unwrap().stake > stake);
+			assert_eq!(Staking::auto_compound(bob_position_id), Some(171));
+		});
+}
+
+#[test]
+fn on_idle_should_charge_only_for_compounded_positions() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+			let db_weight = <Test as frame_system::Config>::DbWeight::get();
+
+			//Act & assert
+			assert_eq!(
+				Staking::on_idle(1_700_000, Weight::MAX),
+				Staking::compound_position_weight().saturating_add(db_weight.reads_writes(2, 1))
+			);
+
+			//Act & assert
+			set_block_number(1_700_001);
+			assert_eq!(Staking::on_idle(1_700_001, Weight::MAX), db_weight.reads_writes(3, 1));
+		});
+}
+
+#[test]
+fn on_idle_should_not_compound_when_proof_size_is_not_sufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+			let stake = Staking::positions(bob_position_id).unwrap().stake;
+			let remaining_weight = Weight::from_parts(
+				u64::MAX,
+				Staking::compound_position_weight().proof_size().saturating_sub(1),
+			);
+
+			//Act
+			let used_weight = Staking::on_idle(1_700_000, remaining_weight);
+
+			//Assert
+			assert_eq!(used_weight, Weight::zero());
+			assert_eq!(Staking::positions(bob_position_id).unwrap().stake, stake);
+			assert_eq!(Staking::auto_compound(bob_position_id), Some(0));
+		});
+}
+
+#[test]
+fn increase_stake_should_compound_rewards_when_auto_compound_is_enabled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+
+			//Act
+			assert_ok!(Staking::increase_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				10_000 * ONE
+			));
+
+			//Assert
+			let compounded = compounded_amount(bob_position_id);
+			assert!(!compounded.is_zero());
+
+			let position = Staking::positions(bob_position_id).unwrap();
+			assert_eq!(position.stake, 130_000 * ONE + compounded);
+			assert_eq!(position.accumulated_locked_rewards, 0);
+			assert_hdx_lock!(BOB, 130_000 * ONE + compounded, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn unstake_should_remove_auto_compound_when_position_is_destroyed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::set_auto_compound(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				true
+			));
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert_eq!(Staking::auto_compound(bob_position_id), None);
+		});
+}
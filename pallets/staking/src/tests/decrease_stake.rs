@@ -0,0 +1,386 @@
+use crate::types::{Conviction, UnbondingChunk, Vote};
+
+use super::*;
+
+use mock::Staking;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn decrease_stake_should_not_work_when_staking_is_not_initialized() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.start_at_block(1_452_987)
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let position_id = 0;
+
+			//Act & assert
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(ALICE), position_id, 10_000 * ONE),
+				Error::<Test>::NotInitialized
+			);
+		});
+}
+
+#[test]
+fn decrease_stake_should_not_work_when_origin_is_not_position_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(BOB), alice_position_id, 10_000 * ONE),
+				Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn decrease_stake_should_not_work_when_remaining_stake_is_lower_than_min_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::decrease_stake(
+					RuntimeOrigin::signed(ALICE),
+					alice_position_id,
+					100_000 * ONE - MinStake::get() + 1
+				),
+				Error::<Test>::InsufficientStake
+			);
+
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(ALICE), alice_position_id, 100_001 * ONE),
+				Error::<Test>::InsufficientStake
+			);
+
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(ALICE), alice_position_id, 0),
+				Error::<Test>::InsufficientStake
+			);
+		});
+}
+
+#[test]
+fn decrease_stake_should_not_work_when_position_has_processed_votes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+			ProcessedVotes::<Test>::insert(ALICE, 2, Vote::new(10_000 * ONE, Conviction::Locked1x));
+
+			//Act & assert
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(ALICE), alice_position_id, 10_000 * ONE),
+				Error::<Test>::ExistingProcessedVotes
+			);
+		});
+}
+
+#[test]
+fn decrease_stake_should_not_work_when_active_vote_is_bigger_than_remaining_stake() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE)])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE)])
+		.with_votings(vec![(0, vec![(1_u32, Vote::new(80_000 * ONE, Conviction::Locked2x))])])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let alice_position_id = Staking::get_user_position_id(&ALICE).unwrap().unwrap();
+
+			//Act & assert
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(ALICE), alice_position_id, 30_000 * ONE),
+				Error::<Test>::StakeLockedByVotes
+			);
+
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(ALICE),
+				alice_position_id,
+				20_000 * ONE
+			));
+		});
+}
+
+#[test]
+fn decrease_stake_should_unlock_stake_immediately_when_unbonding_period_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, HDX, 150_000 * ONE),
+			(BOB, HDX, 250_000 * ONE),
+			(CHARLIE, HDX, 10_000 * ONE),
+		])
+		.with_initialized_staking()
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+			(CHARLIE, 10_000 * ONE, 1_455_000, 10_000 * ONE),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_pending_rewards(10_000 * ONE);
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			Positions::<Test>::mutate(bob_position_id, |p| p.as_mut().unwrap().action_points = 50);
+			let total_stake_before = Staking::staking().total_stake;
+			let slashed_points_before = Staking::positions(bob_position_id).unwrap().accumulated_slash_points;
+
+			//Act
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				20_000 * ONE
+			));
+
+			//Assert
+			let position = Staking::positions(bob_position_id).unwrap();
+			assert_eq!(position.stake, 100_000 * ONE);
+			assert_eq!(position.action_points, 50);
+			assert_eq!(
+				position.reward_per_stake,
+				Staking::staking().accumulated_reward_per_stake
+			);
+			assert!(position.accumulated_slash_points > slashed_points_before);
+			assert!(!position.accumulated_locked_rewards.is_zero());
+
+			assert_eq!(Staking::staking().total_stake, total_stake_before - 20_000 * ONE);
+			assert_eq!(Staking::get_user_position_id(&BOB).unwrap(), Some(bob_position_id));
+			assert!(Staking::unbonding(BOB).is_empty());
+			assert_hdx_lock!(BOB, position.get_total_locked().unwrap(), STAKING_LOCK);
+
+			assert_last_event!(Event::<Test>::StakeDecreased {
+				who: BOB,
+				position_id: bob_position_id,
+				amount: 20_000 * ONE,
+				total_stake: 100_000 * ONE,
+				locked_rewards: position.accumulated_locked_rewards,
+				slashed_points: position.accumulated_slash_points - slashed_points_before,
+				payable_percentage: FixedU128::from_inner(31_383_184_812_088_337_u128),
+				unlock_at: 1_700_000,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn decrease_stake_should_keep_stake_locked_until_unbonding_period_ends() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.with_unbonding_period(1_000)
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+
+			//Act
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				20_000 * ONE
+			));
+
+			//Assert
+			let position = Staking::positions(bob_position_id).unwrap();
+			assert_eq!(position.stake, 100_000 * ONE);
+			assert_eq!(
+				Staking::unbonding(BOB).into_inner(),
+				vec![UnbondingChunk::new(20_000 * ONE, 1_701_000)]
+			);
+			assert_hdx_lock!(BOB, position.get_total_locked().unwrap() + 20_000 * ONE, STAKING_LOCK);
+
+			set_block_number(1_700_999);
+			assert_noop!(
+				Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)),
+				Error::<Test>::NothingToWithdraw
+			);
+
+			set_block_number(1_701_000);
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+
+			assert!(Staking::unbonding(BOB).is_empty());
+			assert_hdx_lock!(BOB, position.get_total_locked().unwrap(), STAKING_LOCK);
+			assert_last_event!(Event::<Test>::UnbondedWithdrawn {
+				who: BOB,
+				amount: 20_000 * ONE,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn withdraw_unbonded_should_only_unlock_matured_chunks() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.with_unbonding_period(1_000)
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			set_block_number(1_700_000);
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				10_000 * ONE
+			));
+			set_block_number(1_700_500);
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				15_000 * ONE
+			));
+
+			//Act
+			set_block_number(1_701_200);
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+
+			//Assert
+			let position = Staking::positions(bob_position_id).unwrap();
+			assert_eq!(position.stake, 95_000 * ONE);
+			assert_eq!(
+				Staking::unbonding(BOB).into_inner(),
+				vec![UnbondingChunk::new(15_000 * ONE, 1_701_500)]
+			);
+			assert_hdx_lock!(BOB, position.get_total_locked().unwrap() + 15_000 * ONE, STAKING_LOCK);
+		});
+}
+
+#[test]
+fn decrease_stake_should_not_work_when_max_unbonding_chunks_is_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.with_unbonding_period(1_000)
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			for i in 0..MaxUnbondingChunks::get() as u64 {
+				set_block_number(1_700_000 + i);
+				assert_ok!(Staking::decrease_stake(
+					RuntimeOrigin::signed(BOB),
+					bob_position_id,
+					1_000 * ONE
+				));
+			}
+
+			//Act & assert
+			set_block_number(1_700_100);
+			assert_noop!(
+				Staking::decrease_stake(RuntimeOrigin::signed(BOB), bob_position_id, 1_000 * ONE),
+				Error::<Test>::MaxUnbondingChunksReached
+			);
+		});
+}
+
+#[test]
+fn increase_stake_should_not_work_when_balance_is_unbonding() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 120_000 * ONE)])
+		.with_initialized_staking()
+		.with_unbonding_period(1_000)
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 0),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_460_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				20_000 * ONE
+			));
+
+			//Act & assert
+			assert_noop!(
+				Staking::increase_stake(RuntimeOrigin::signed(BOB), bob_position_id, 20_000 * ONE),
+				Error::<Test>::InsufficientBalance
+			);
+		});
+}
+
+#[test]
+fn unstake_should_keep_unbonding_stake_locked() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, HDX, 150_000 * ONE), (BOB, HDX, 250_000 * ONE)])
+		.with_initialized_staking()
+		.with_unbonding_period(1_000)
+		.start_at_block(1_452_987)
+		.with_stakes(vec![
+			(ALICE, 100_000 * ONE, 1_452_987, 200_000 * ONE),
+			(BOB, 120_000 * ONE, 1_452_987, 0),
+		])
+		.build()
+		.execute_with(|| {
+			//Arrange
+			set_block_number(1_700_000);
+			let bob_position_id = Staking::get_user_position_id(&BOB).unwrap().unwrap();
+			assert_ok!(Staking::decrease_stake(
+				RuntimeOrigin::signed(BOB),
+				bob_position_id,
+				20_000 * ONE
+			));
+
+			//Act
+			assert_ok!(Staking::unstake(RuntimeOrigin::signed(BOB), bob_position_id));
+
+			//Assert
+			assert_eq!(Staking::positions(bob_position_id), None);
+			assert_hdx_lock!(BOB, 20_000 * ONE, STAKING_LOCK);
+
+			set_block_number(1_701_000);
+			assert_ok!(Staking::withdraw_unbonded(RuntimeOrigin::signed(BOB)));
+			assert_hdx_lock!(BOB, 0, STAKING_LOCK);
+		});
+}
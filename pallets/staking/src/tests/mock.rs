@@ -201,6 +201,8 @@ parameter_types! {
 	pub const UnclaimablePeriods: BlockNumber = 10;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(15,100);
 	pub const MaxVotes: u32 = 10;
+	pub static UnbondingPeriod: BlockNumber = 0;
+	pub const MaxUnbondingChunks: u32 = 3;
 }
 
 impl pallet_staking::Config for Test {
@@ -228,6 +230,8 @@ impl pallet_staking::Config for Test {
 	type MaxPointsPerAction = DummyMaxPointsPerAction;
 	type ReferendumInfo = DummyReferendumStatus;
 	type Vesting = DummyVesting;
+	type UnbondingPeriod = UnbondingPeriod;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
 	type Collections = FreezableUniques;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type MinSlash = DummyMinSlash;
@@ -296,6 +300,7 @@ pub struct ExtBuilder {
 	stakes: Vec<(AccountId, Balance, BlockNumber, Balance)>,
 	init_staking: bool,
 	with_votings: Vec<(PositionId, Vec<(ReferendumIndex, Vote)>)>,
+	unbonding_period: BlockNumber,
 }
 
 impl ExtBuilder {
@@ -324,6 +329,11 @@ impl ExtBuilder {
 		self.with_votings = votings;
 		self
 	}
+
+	pub fn with_unbonding_period(mut self, period: BlockNumber) -> Self {
+		self.unbonding_period = period;
+		self
+	}
 }
 
 impl ExtBuilder {
@@ -340,6 +350,8 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		UnbondingPeriod::set(self.unbonding_period);
+
 		let mut r: sp_io::TestExternalities = t.into();
 		r.execute_with(|| {
 			if self.initial_block_number.is_zero() {
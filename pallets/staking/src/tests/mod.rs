@@ -5,7 +5,9 @@ use crate::*;
 use frame_support::{assert_noop, assert_ok};
 use orml_tokens::BalanceLock;
 
+mod auto_compound;
 mod claim;
+mod decrease_stake;
 mod increase_stake;
 pub(crate) mod mock;
 mod stake;
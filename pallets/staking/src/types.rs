@@ -60,6 +60,21 @@ impl<BlockNumber> Position<BlockNumber> {
 	}
 }
 
+/// Part of the stake removed from the position which stays locked until `unlock_at`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct UnbondingChunk<BlockNumber> {
+	/// Unbonding amount.
+	pub(crate) amount: Balance,
+	/// Block number from which `amount` can be withdrawn.
+	pub(crate) unlock_at: BlockNumber,
+}
+
+impl<BlockNumber> UnbondingChunk<BlockNumber> {
+	pub fn new(amount: Balance, unlock_at: BlockNumber) -> Self {
+		Self { amount, unlock_at }
+	}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo, Default)]
 pub struct StakingData {
 	/// Total amount of tokens staked in staking.
@@ -55,6 +55,10 @@ pub trait WeightInfo {
 	fn increase_stake() -> Weight;
 	fn claim() -> Weight;
 	fn unstake() -> Weight;
+	fn decrease_stake() -> Weight;
+	fn withdraw_unbonded() -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn compound_rewards() -> Weight;
 }
 
 /// Weights for pallet_staking using the hydraDX node and recommended hardware.
@@ -190,4 +194,29 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(10_u64))
 			.saturating_add(RocksDbWeight::get().writes(10_u64))
 	}
+	/// Votes are processed, rewards are paid and the lock is updated as in `increase_stake`.
+	/// Storage: `Staking::Unbonding` (r:1 w:1)
+	/// Storage: `Staking::AutoCompound` (r:1 w:0)
+	fn decrease_stake() -> Weight {
+		Self::increase_stake()
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 1_u64))
+	}
+	/// Position is read and the lock is updated as in `unstake`.
+	/// Storage: `Staking::Unbonding` (r:1 w:1)
+	fn withdraw_unbonded() -> Weight {
+		Self::unstake()
+			.saturating_add(RocksDbWeight::get().reads_writes(1_u64, 1_u64))
+	}
+	/// Staking and NFT state is read as in `initialize_staking`.
+	/// Storage: `Staking::AutoCompound` (r:1 w:1)
+	fn set_auto_compound() -> Weight {
+		Self::initialize_staking()
+	}
+	/// Rewards are paid and the lock is updated as in `claim`.
+	/// Storage: `Staking::Unbonding` (r:1 w:0)
+	/// Storage: `Staking::AutoCompound` (r:1 w:1)
+	fn compound_rewards() -> Weight {
+		Self::claim()
+			.saturating_add(RocksDbWeight::get().reads_writes(2_u64, 1_u64))
+	}
 }
@@ -29,6 +29,7 @@ use frame_support::{
 	weights::{Weight, WeightToFee},
 };
 use hydra_dx_math::{
+	dynamic_fees::types::OracleEntry,
 	ema::EmaPrice,
 	ensure,
 	omnipool::types::BalanceUpdate,
@@ -422,6 +423,15 @@ where
 			amount_out.into(),
 		)?;
 
+		for asset in [asset_in, asset_out] {
+			pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+				asset.asset_id.into(),
+				Lrna::get().into(),
+				OMNIPOOL_SOURCE,
+				Price::new(asset.after.reserve, asset.after.hub_reserve),
+			)?;
+		}
+
 		Ok(Self::on_trade_weight())
 	}
 
@@ -449,22 +459,58 @@ where
 			amount_out.into(),
 		)?;
 
+		pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+			asset.asset_id.into(),
+			Lrna::get().into(),
+			OMNIPOOL_SOURCE,
+			Price::new(asset.after.reserve, asset.after.hub_reserve),
+		)?;
+
 		Ok(Self::on_trade_weight())
 	}
 
+	fn on_asset_removed(asset_id: AssetId) -> Result<Weight, Self::Error> {
+		pallet_ema_oracle::Pallet::<Runtime>::remove_oracle_entries(OMNIPOOL_SOURCE, (asset_id, Lrna::get()));
+		pallet_circuit_breaker::Pallet::<Runtime>::remove_asset_limits(asset_id.into());
+
+		Ok(Self::on_asset_removed_weight())
+	}
+
 	fn on_liquidity_changed_weight() -> Weight {
 		let w1 = OnActivityHandler::<Runtime>::on_liquidity_changed_weight();
-		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_add_liquidity_limit()
-			.max(<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_remove_liquidity_limit());
+		let w2 = pallet_circuit_breaker::Pallet::<Runtime>::ensure_add_liquidity_limit_weight()
+			.max(pallet_circuit_breaker::Pallet::<Runtime>::ensure_remove_liquidity_limit_weight());
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_liquidity_limit_entry();
 		w1.saturating_add(w2).saturating_add(w3)
 	}
 
 	fn on_trade_weight() -> Weight {
 		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(2);
-		let w2 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_pool_state_change_limit();
+		let w2 = pallet_circuit_breaker::Pallet::<Runtime>::ensure_pool_state_change_limit_weight();
 		let w3 = <Runtime as pallet_circuit_breaker::Config>::WeightInfo::on_finalize_single_trade_limit_entry();
-		w1.saturating_add(w2).saturating_add(w3)
+		let w4 =
+			<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit().saturating_mul(2);
+		// `on_trade_fee` resolves the current owner of a transferred code and distributes the referrer shares
+		// across the chain of parent codes, which is not covered by the trade benchmarks.
+		let depth = <Runtime as pallet_referrals::Config>::MaxSubCodeDepth::get() as u64;
+		let w5 = <Runtime as frame_system::Config>::DbWeight::get()
+			.reads_writes(3u64.saturating_add(depth.saturating_mul(3)), depth);
+		// The dynamic fees of both assets are updated in a trade. Each update reads the short and long
+		// term oracle prices for the volatility fee and stores it, which is not covered by the trade benchmarks.
+		let w6 = pallet_ema_oracle::Pallet::<Runtime>::get_price_weight()
+			.saturating_mul(2)
+			.saturating_add(<Runtime as frame_system::Config>::DbWeight::get().writes(1))
+			.saturating_mul(2);
+		w1.saturating_add(w2)
+			.saturating_add(w3)
+			.saturating_add(w4)
+			.saturating_add(w5)
+			.saturating_add(w6)
+	}
+
+	fn on_asset_removed_weight() -> Weight {
+		pallet_ema_oracle::Pallet::<Runtime>::remove_oracle_entries_weight()
+			.saturating_add(pallet_circuit_breaker::Pallet::<Runtime>::remove_asset_limits_weight())
 	}
 
 	fn on_trade_fee(
@@ -859,6 +905,50 @@ where
 	}
 }
 
+pub struct OracleVolatilityProvider<Runtime, Lrna, ShortPeriod, LongPeriod>(
+	PhantomData<(Runtime, Lrna, ShortPeriod, LongPeriod)>,
+);
+
+impl<Runtime, Lrna, ShortPeriod, LongPeriod> pallet_dynamic_fees::traits::VolatilityProvider<AssetId>
+	for OracleVolatilityProvider<Runtime, Lrna, ShortPeriod, LongPeriod>
+where
+	Runtime: pallet_ema_oracle::Config,
+	Lrna: Get<AssetId>,
+	ShortPeriod: Get<OraclePeriod>,
+	LongPeriod: Get<OraclePeriod>,
+{
+	fn asset_prices(asset_id: AssetId) -> Option<(Price, Price)> {
+		let (short_price, _) =
+			pallet_ema_oracle::Pallet::<Runtime>::get_price(asset_id, Lrna::get(), ShortPeriod::get(), OMNIPOOL_SOURCE)
+				.ok()?;
+		let (long_price, _) =
+			pallet_ema_oracle::Pallet::<Runtime>::get_price(asset_id, Lrna::get(), LongPeriod::get(), OMNIPOOL_SOURCE)
+				.ok()?;
+		Some((short_price, long_price))
+	}
+}
+
+// Stableswap dynamic fees volume adapter
+pub struct StableswapOracleVolumeProvider<Runtime, Period>(PhantomData<(Runtime, Period)>);
+
+impl<Runtime, Period> pallet_stableswap::types::PoolVolumeProvider<AssetId>
+	for StableswapOracleVolumeProvider<Runtime, Period>
+where
+	Runtime: pallet_ema_oracle::Config,
+	Period: Get<OraclePeriod>,
+{
+	fn asset_volume(pool_id: AssetId, asset_id: AssetId) -> Option<OracleEntry> {
+		let entry =
+			pallet_ema_oracle::Pallet::<Runtime>::get_entry(asset_id, pool_id, Period::get(), STABLESWAP_SOURCE)
+				.ok()?;
+		Some(OracleEntry {
+			amount_in: entry.volume.a_in,
+			amount_out: entry.volume.a_out,
+			liquidity: entry.liquidity.a,
+		})
+	}
+}
+
 pub struct VestingInfo<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> pallet_staking::traits::VestingDetails<AccountId, Balance> for VestingInfo<Runtime>
@@ -924,7 +1014,8 @@ pub struct StableswapHooksAdapter<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> StableswapHooks<AssetId> for StableswapHooksAdapter<Runtime>
 where
-	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config,
+	Runtime: pallet_ema_oracle::Config + pallet_stableswap::Config + pallet_circuit_breaker::Config,
+	<Runtime as pallet_circuit_breaker::Config>::AssetId: From<AssetId>,
 {
 	fn on_liquidity_changed(pool_id: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
@@ -964,12 +1055,7 @@ where
 		Ok(())
 	}
 
-	fn on_trade(
-		pool_id: AssetId,
-		_asset_in: AssetId,
-		_asset_out: AssetId,
-		state: PoolState<AssetId>,
-	) -> DispatchResult {
+	fn on_trade(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, state: PoolState<AssetId>) -> DispatchResult {
 		let pool_size = state.assets.len();
 
 		// As we access by index, let's ensure correct vec lengths.
@@ -1004,6 +1090,21 @@ where
 			.map_err(|(_, e)| e)?;
 		}
 
+		for asset_id in [asset_in, asset_out] {
+			let idx = state
+				.assets
+				.iter()
+				.position(|asset| *asset == asset_id)
+				.ok_or(pallet_stableswap::Error::<Runtime>::IncorrectAssets)?;
+
+			pallet_circuit_breaker::Pallet::<Runtime>::ensure_price_deviation_limit(
+				asset_id.into(),
+				pool_id.into(),
+				STABLESWAP_SOURCE,
+				Price::new(state.share_prices[idx].0, state.share_prices[idx].1),
+			)?;
+		}
+
 		Ok(())
 	}
 
@@ -1012,7 +1113,10 @@ where
 	}
 
 	fn on_trade_weight(n: usize) -> Weight {
-		OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(n as u64)
+		let w1 = OnActivityHandler::<Runtime>::on_trade_weight().saturating_mul(n as u64);
+		let w2 =
+			<Runtime as pallet_circuit_breaker::Config>::WeightInfo::ensure_price_deviation_limit().saturating_mul(2);
+		w1.saturating_add(w2)
 	}
 }
 
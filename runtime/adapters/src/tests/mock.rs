@@ -341,6 +341,9 @@ impl pallet_route_executor::Config for Test {
 	type OraclePriceProvider = PriceProviderMock;
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type PoolsProvider = ();
+	type MaxRouteCandidates = frame_support::traits::ConstU32<10>;
+	type MaxRoutePoolAssets = frame_support::traits::ConstU32<100>;
 	type OraclePeriod = RouteValidationOraclePeriod;
 	type WeightInfo = ();
 }
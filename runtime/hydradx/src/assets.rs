@@ -21,8 +21,8 @@ use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
 	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider, RelayChainBlockNumberProvider,
-	StableswapHooksAdapter, VestingInfo,
+	OracleAssetVolumeProvider, OracleVolatilityProvider, PriceAdjustmentAdapter, RelayChainBlockHashProvider,
+	RelayChainBlockNumberProvider, StableswapHooksAdapter, StableswapOracleVolumeProvider, VestingInfo,
 };
 
 pub use hydradx_traits::{
@@ -42,7 +42,7 @@ use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKille
 use primitives::constants::{
 	chain::{OMNIPOOL_SOURCE, XYK_SOURCE},
 	currency::{NATIVE_EXISTENTIAL_DEPOSIT, UNITS},
-	time::DAYS,
+	time::{unix_time::DAY, DAYS},
 };
 use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, Percent};
 
@@ -65,7 +65,8 @@ use orml_traits::{
 	currency::{MultiCurrency, MultiLockableCurrency, MutationHooks, OnDeposit, OnTransfer},
 	GetByKey, Happened,
 };
-use pallet_dynamic_fees::types::FeeParams;
+use pallet_bonds::weights::WeightInfo as BondsWeights;
+use pallet_dynamic_fees::types::{FeeParams, VolatilityFeeParams};
 use pallet_lbp::weights::WeightInfo as LbpWeights;
 use pallet_route_executor::{weights::WeightInfo as RouterWeights, AmmTradeWeights, MAX_NUMBER_OF_TRADES};
 use pallet_staking::{
@@ -544,6 +545,7 @@ impl Contains<AccountId> for CircuitBreakerWhitelist {
 parameter_types! {
 	pub const DefaultMaxNetTradeVolumeLimitPerBlock: (u32, u32) = (5_000, 10_000);	// 50%
 	pub const DefaultMaxLiquidityLimitPerBlock: Option<(u32, u32)> = Some((500, 10_000));	// 5%
+	pub const PriceDeviationOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 impl pallet_circuit_breaker::Config for Runtime {
@@ -556,12 +558,48 @@ impl pallet_circuit_breaker::Config for Runtime {
 	type DefaultMaxAddLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type DefaultMaxRemoveLiquidityLimitPerBlock = DefaultMaxLiquidityLimitPerBlock;
 	type OmnipoolHubAsset = LRNA;
+	type PriceOracle = EmaOracle;
+	type PriceDeviationOraclePeriod = PriceDeviationOraclePeriod;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CircuitBreakerBenchmarkHelper;
 	type WeightInfo = weights::pallet_circuit_breaker::HydraWeight<Runtime>;
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CircuitBreakerBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_circuit_breaker::BenchmarkHelper<AssetId> for CircuitBreakerBenchmarkHelper {
+	fn set_oracle_price(
+		asset_id: AssetId,
+		quote_asset: AssetId,
+		source: Source,
+		price: hydra_dx_math::ema::EmaPrice,
+	) -> DispatchResult {
+		EmaOracle::add_oracle(RawOrigin::Root.into(), source, (asset_id, quote_asset))?;
+		pallet_ema_oracle::OnActivityHandler::<Runtime>::on_trade(
+			source,
+			asset_id,
+			quote_asset,
+			price.n,
+			price.d,
+			price.n,
+			price.d,
+			price,
+		)
+		.map_err(|(_, e)| e)?;
+		<EmaOracle as frame_support::traits::Hooks<BlockNumber>>::on_finalize(System::block_number());
+
+		Ok(())
+	}
+}
+
 parameter_types! {
 	pub SupportedPeriods: BoundedVec<OraclePeriod, ConstU32<{ pallet_ema_oracle::MAX_PERIODS }>> = BoundedVec::truncate_from(vec![
-		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes]);
+		OraclePeriod::LastBlock, OraclePeriod::Short, OraclePeriod::TenMinutes, OraclePeriod::Hour]);
+	/// Checkpoint every 10 minutes (assuming 6 second blocks) and keep them for a day.
+	pub const OracleCheckpointInterval: BlockNumber = 100;
+	pub const OracleMaxCheckpoints: u32 = 144;
 }
 
 pub struct OracleWhitelist<Runtime>(PhantomData<Runtime>);
@@ -588,6 +626,8 @@ impl pallet_ema_oracle::Config for Runtime {
 	/// With every asset trading against LRNA we will only have as many pairs as there will be assets, so
 	/// 40 seems a decent upper bound for the foreseeable future.
 	type MaxUniqueEntries = ConstU32<40>;
+	type CheckpointInterval = OracleCheckpointInterval;
+	type MaxCheckpoints = OracleMaxCheckpoints;
 	type WeightInfo = weights::pallet_ema_oracle::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	/// Should take care of the overhead introduced by `OracleWhitelist`.
@@ -804,6 +844,7 @@ parameter_types! {
 	pub MaxPriceDifference: Permill = Permill::from_rational(15u32, 1000u32);
 	pub MaxConfigurablePriceDifference: Permill = Permill::from_percent(5);
 	pub MinimalPeriod: u32 = 5;
+	pub MaxLimitOrderDuration: u32 = 30 * DAYS;
 	pub BumpChance: Percent = Percent::from_percent(17);
 	pub NamedReserveId: NamedReserveIdentifier = *b"dcaorder";
 	pub MaxNumberOfRetriesOnError: u8 = 3;
@@ -842,6 +883,7 @@ impl pallet_dca::Config for Runtime {
 	type MaxPriceDifferenceBetweenBlocks = MaxPriceDifference;
 	type MaxConfigurablePriceDifferenceBetweenBlocks = MaxConfigurablePriceDifference;
 	type MinimalPeriod = MinimalPeriod;
+	type MaxLimitOrderDuration = MaxLimitOrderDuration;
 	type BumpChance = BumpChance;
 	type MaxSchedulePerBlock = MaxSchedulesPerBlock;
 	type MaxNumberOfRetriesOnError = MaxNumberOfRetriesOnError;
@@ -975,9 +1017,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(Stableswap::dynamic_fee_weight(c + e))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_sell(c, e),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1023,9 +1067,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(Stableswap::dynamic_fee_weight(c + e))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_buy(c, e),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1055,9 +1101,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(Stableswap::dynamic_fee_weight(c + e))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_buy(c, e),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1079,9 +1127,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(Stableswap::dynamic_fee_weight(c + e))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_sell(c, e),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1103,9 +1153,11 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(Stableswap::dynamic_fee_weight(c + e))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_buy(c, e),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1136,8 +1188,10 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(Stableswap::dynamic_fee_weight(1))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1149,8 +1203,10 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::pallet_lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(Stableswap::dynamic_fee_weight(1))
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1183,6 +1239,7 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 					weights::pallet_stableswap::HydraWeight::<Runtime>::calculate_spot_price_with_fee()
 				}
 				PoolType::XYK => weights::pallet_xyk::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
+				PoolType::Bonds => weights::pallet_bonds::HydraWeight::<Runtime>::calculate_spot_price_with_fee(),
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -1193,11 +1250,56 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 	fn get_route_weight() -> Weight {
 		weights::pallet_route_executor::HydraWeight::<Runtime>::get_route()
 	}
+
+	fn discover_route_weight() -> Weight {
+		let mut weight = Self::get_route_weight();
+
+		//Each candidate route is evaluated with a sell calculation,
+		//and we expect a worst case with max number of trades in the most expensive pool which is stableswap
+		let weight_of_candidate_route = weights::pallet_stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+			.saturating_add(Stableswap::dynamic_fee_weight(1))
+			.saturating_mul(MAX_NUMBER_OF_TRADES.into());
+		weight.saturating_accrue(weight_of_candidate_route.saturating_mul(MaxRouteCandidates::get().into()));
+
+		//Reading the pools of the AMMs to enumerate the candidate routes, one read per pool asset in the worst case
+		weight.saturating_accrue(
+			<Runtime as frame_system::Config>::DbWeight::get().reads(MaxRoutePoolAssets::get().into()),
+		);
+
+		weight
+	}
+
+	fn set_discovered_route_weight() -> Weight {
+		//The discovered route is validated as any new route, so we expect a worst case with max number of trades,
+		//each of them in the pool which is the most expensive to validate
+		let weight_of_most_expensive_trade = [
+			PoolType::Omnipool,
+			PoolType::Stableswap(0),
+			PoolType::XYK,
+			PoolType::LBP,
+			PoolType::Bonds,
+		]
+		.into_iter()
+		.map(|pool| {
+			Self::set_route_weight(&[Trade {
+				pool,
+				asset_in: 0,
+				asset_out: 1,
+			}])
+		})
+		.fold(Weight::zero(), |acc, weight| acc.max(weight));
+
+		weight_of_most_expensive_trade
+			.saturating_mul(MAX_NUMBER_OF_TRADES.into())
+			.saturating_add(Self::skip_ed_handling_overweight())
+	}
 }
 
 parameter_types! {
 	pub const DefaultRoutePoolType: PoolType<AssetId> = PoolType::Omnipool;
 	pub const RouteValidationOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
+	pub const MaxRouteCandidates: u32 = 16;
+	pub const MaxRoutePoolAssets: u32 = 256;
 
 }
 
@@ -1207,7 +1309,7 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, Bonds);
 	type DefaultRoutePoolType = DefaultRoutePoolType;
 	type NativeAssetId = NativeAssetId;
 	type InspectRegistry = AssetRegistry;
@@ -1215,6 +1317,9 @@ impl pallet_route_executor::Config for Runtime {
 	type EdToRefundCalculator = RefundAndLockedEdCalculator;
 	type OraclePriceProvider = hydradx_adapters::OraclePriceProvider<AssetId, EmaOracle, LRNA>;
 	type OraclePeriod = RouteValidationOraclePeriod;
+	type PoolsProvider = (Omnipool, Stableswap, XYK, LBP, Bonds);
+	type MaxRouteCandidates = MaxRouteCandidates;
+	type MaxRoutePoolAssets = MaxRoutePoolAssets;
 }
 
 parameter_types! {
@@ -1268,7 +1373,13 @@ parameter_types! {
 		amplification: FixedU128::one(),                 // 1
 	};
 
+	pub VolatilityFeeParameters: VolatilityFeeParams<Permill> = VolatilityFeeParams{
+		max_fee: Permill::from_rational(1u32,100u32),    // 1%
+		amplification: FixedU128::one(),                 // 1
+	};
+
 	pub const DynamicFeesOraclePeriod: OraclePeriod = OraclePeriod::Short;
+	pub const DynamicFeesLongOraclePeriod: OraclePeriod = OraclePeriod::Hour;
 }
 
 impl pallet_dynamic_fees::Config for Runtime {
@@ -1279,11 +1390,15 @@ impl pallet_dynamic_fees::Config for Runtime {
 	type Oracle = OracleAssetVolumeProvider<Runtime, LRNA, DynamicFeesOraclePeriod>;
 	type AssetFeeParameters = AssetFeeParams;
 	type ProtocolFeeParameters = ProtocolFeeParams;
+	type VolatilityOracle =
+		OracleVolatilityProvider<Runtime, LRNA, DynamicFeesOraclePeriod, DynamicFeesLongOraclePeriod>;
+	type VolatilityFeeParameters = VolatilityFeeParameters;
 }
 
 // Stableswap
 parameter_types! {
 	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub const StableswapDynamicFeesOraclePeriod: OraclePeriod = OraclePeriod::Short;
 }
 
 pub struct StableswapAccountIdConstructor<T: frame_system::Config>(PhantomData<T>);
@@ -1381,6 +1496,32 @@ impl<T: pallet_ema_oracle::Config> pallet_ema_oracle::BenchmarkHelper<AssetId> f
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_bonds::Config> pallet_bonds::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId) -> DispatchResult {
+		let result = with_transaction(|| {
+			TransactionOutcome::Commit(AssetRegistry::register_sufficient_asset(
+				Some(asset_id),
+				None,
+				AssetKind::Token,
+				1,
+				None,
+				Some(12),
+				None,
+				None,
+			))
+		});
+
+		// don't throw error if the asset is already registered
+		if result.is_err_and(|e| e == pallet_asset_registry::Error::<Runtime>::AssetAlreadyRegistered.into()) {
+			return Ok(());
+		};
+
+		let _ = result?;
+		Ok(())
+	}
+}
+
 impl pallet_stableswap::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type BlockNumberProvider = System;
@@ -1391,6 +1532,7 @@ impl pallet_stableswap::Config for Runtime {
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
+	type VolumeProvider = StableswapOracleVolumeProvider<Runtime, StableswapDynamicFeesOraclePeriod>;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
@@ -1399,10 +1541,19 @@ impl pallet_stableswap::Config for Runtime {
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RouteExecutor = Router;
+	type RouteProvider = Router;
+	type RouterWeightInfo = RouterWeightInfo;
+	type WeightInfo = weights::pallet_omnipool_subpools::HydraWeight<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
 	pub const BondsPalletId: PalletId = PalletId(*b"pltbonds");
+	pub const BondsPoolTimeScale: primitives::Moment = 10 * 365 * DAY;
 }
 
 pub struct AssetTypeWhitelist;
@@ -1422,9 +1573,15 @@ impl pallet_bonds::Config for Runtime {
 	type PalletId = BondsPalletId;
 	type IssueOrigin = EnsureSigned<AccountId>;
 	type AssetTypeWhitelist = AssetTypeWhitelist;
+	type CouponExemptAccounts = DustRemovalWhitelist;
 	type ProtocolFee = ProtocolFee;
 	type FeeReceiver = TreasuryAccount;
+	type PoolTimeScale = BondsPoolTimeScale;
+	type MinPoolLiquidity = MinPoolLiquidity;
+	type MinTradingLimit = MinTradingLimit;
 	type WeightInfo = weights::pallet_bonds::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
 // Staking
@@ -1438,6 +1595,8 @@ parameter_types! {
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 1;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(2,100);
+	pub const StakingUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxUnbondingChunks: u32 = 10;
 }
 
 pub struct PointsPerAction;
@@ -1483,6 +1642,8 @@ impl pallet_staking::Config for Runtime {
 	type ReferendumInfo = pallet_staking::integrations::democracy::ReferendumStatus<Runtime>;
 	type MaxPointsPerAction = PointsPerAction;
 	type Vesting = VestingInfo<Runtime>;
+	type UnbondingPeriod = StakingUnbondingPeriod;
+	type MaxUnbondingChunks = MaxUnbondingChunks;
 	type WeightInfo = weights::pallet_staking::HydraWeight<Runtime>;
 	type MinSlash = StakingMinSlash;
 
@@ -1510,6 +1671,11 @@ where
 	}
 }
 
+parameter_types! {
+	pub const LBPMaxAllowlistSize: u32 = 1_000;
+	pub const LBPMaxParticipantsRemoved: u32 = 500;
+}
+
 impl pallet_lbp::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MultiCurrency = Currencies;
@@ -1523,6 +1689,8 @@ impl pallet_lbp::Config for Runtime {
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type MaxAllowlistSize = LBPMaxAllowlistSize;
+	type MaxParticipantsRemoved = LBPMaxParticipantsRemoved;
 }
 
 parameter_types! {
@@ -1557,6 +1725,8 @@ parameter_types! {
 	pub const MinCodeLength: u32 = 4;
 	pub const ReferralsOraclePeriod: OraclePeriod = OraclePeriod::TenMinutes;
 	pub const ReferralsSeedAmount: Balance = 10_000_000_000_000;
+	pub const ReferralsMaxSubCodeDepth: u32 = 3;
+	pub const ReferralsMaxParentShare: Permill = Permill::from_percent(50);
 	pub ReferralsExternalRewardAccount: Option<AccountId> = Some(StakingPalletId::get().into_account_truncating());
 }
 
@@ -1579,6 +1749,8 @@ impl pallet_referrals::Config for Runtime {
 	type LevelVolumeAndRewardPercentages = ReferralsLevelVolumeAndRewards;
 	type ExternalAccount = ReferralsExternalRewardAccount;
 	type SeedNativeAmount = ReferralsSeedAmount;
+	type MaxSubCodeDepth = ReferralsMaxSubCodeDepth;
+	type MaxParentShare = ReferralsMaxParentShare;
 	type WeightInfo = weights::pallet_referrals::HydraWeight<Runtime>;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = ReferralsBenchmarkHelper;
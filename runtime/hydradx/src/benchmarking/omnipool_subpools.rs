@@ -0,0 +1,148 @@
+use crate::{AccountId, AssetId, Balance, Omnipool, OmnipoolSubpools, Router, Runtime};
+
+use super::*;
+
+use super::omnipool::{init, update_balance};
+use frame_benchmarking::account;
+use frame_benchmarking::BenchmarkError;
+use frame_support::sp_runtime::{traits::One, FixedU128, Permill};
+use frame_system::RawOrigin;
+use hydradx_traits::router::{AssetPair, RouteProvider};
+use orml_benchmarking::runtime_benchmarks;
+
+const DAI: AssetId = 2;
+
+fn add_omnipool_token(name: &[u8], owner: &AccountId) -> Result<(AssetId, u128), BenchmarkError> {
+	let token_id =
+		register_asset(name.to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	let position_id = Omnipool::next_position_id();
+
+	update_balance(token_id, &Omnipool::protocol_account(), 1_000_000_000_000_000u128);
+	Omnipool::add_token(
+		RawOrigin::Root.into(),
+		token_id,
+		FixedU128::from((1, 2)),
+		Permill::from_percent(100),
+		owner.clone(),
+	)?;
+
+	Ok((token_id, position_id))
+}
+
+fn create_subpool(asset_a: AssetId, asset_b: AssetId) -> Result<AssetId, BenchmarkError> {
+	let share_asset = register_asset(b"SHARE".to_vec(), Balance::one())
+		.map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+
+	OmnipoolSubpools::create_subpool(
+		RawOrigin::Root.into(),
+		share_asset,
+		asset_a,
+		asset_b,
+		Permill::from_percent(100),
+		100u16,
+		Permill::from_percent(0),
+	)?;
+
+	Ok(share_asset)
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_omnipool_subpools}
+
+	create_subpool {
+		init()?;
+		let owner: AccountId = account("owner", 0, 1);
+		let (asset_a, _) = add_omnipool_token(b"STB1", &owner)?;
+		let (asset_b, _) = add_omnipool_token(b"STB2", &owner)?;
+		let share_asset =
+			register_asset(b"SHARE".to_vec(), Balance::one()).map_err(|_| BenchmarkError::Stop("Failed to register asset"))?;
+	}: _(RawOrigin::Root, share_asset, asset_a, asset_b, Permill::from_percent(100), 100u16, Permill::from_percent(0))
+	verify {
+		assert!(OmnipoolSubpools::subpools(share_asset).is_some());
+		assert!(Omnipool::assets(asset_a).is_none());
+	}
+
+	migrate_asset_to_subpool {
+		init()?;
+		let owner: AccountId = account("owner", 0, 1);
+		let (asset_a, _) = add_omnipool_token(b"STB1", &owner)?;
+		let (asset_b, _) = add_omnipool_token(b"STB2", &owner)?;
+		let (asset_c, _) = add_omnipool_token(b"STB3", &owner)?;
+		let share_asset = create_subpool(asset_a, asset_b)?;
+	}: _(RawOrigin::Root, share_asset, asset_c)
+	verify {
+		assert!(OmnipoolSubpools::migrated_assets(asset_c).is_some());
+		assert!(Omnipool::assets(asset_c).is_none());
+	}
+
+	migrate_position {
+		init()?;
+		let owner: AccountId = account("owner", 0, 1);
+		let (asset_a, position_id) = add_omnipool_token(b"STB1", &owner)?;
+		let (asset_b, _) = add_omnipool_token(b"STB2", &owner)?;
+		let share_asset = create_subpool(asset_a, asset_b)?;
+	}: _(RawOrigin::Signed(owner), position_id)
+	verify {
+		assert_eq!(Omnipool::positions(position_id).unwrap().asset_id, share_asset);
+	}
+
+	migrate_route {
+		init()?;
+		let owner: AccountId = account("owner", 0, 1);
+		let caller: AccountId = account("caller", 0, 1);
+		let (asset_a, _) = add_omnipool_token(b"STB1", &owner)?;
+		let (asset_b, _) = add_omnipool_token(b"STB2", &owner)?;
+		create_subpool(asset_a, asset_b)?;
+		let asset_pair = AssetPair::new(DAI, asset_a);
+	}: _(RawOrigin::Signed(caller), asset_pair)
+	verify {
+		assert_eq!(Router::get_route(asset_pair), OmnipoolSubpools::trade_route(DAI, asset_a));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::NativeExistentialDeposit;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use sp_runtime::BuildStorage;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::<crate::Runtime>::default()
+			.build_storage()
+			.unwrap();
+
+		pallet_asset_registry::GenesisConfig::<crate::Runtime> {
+			registered_assets: vec![
+				(
+					Some(1),
+					Some(b"LRNA".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+				(
+					Some(2),
+					Some(b"DAI".to_vec().try_into().unwrap()),
+					1_000u128,
+					None,
+					None,
+					None,
+					true,
+				),
+			],
+			native_asset_name: b"HDX".to_vec().try_into().unwrap(),
+			native_existential_deposit: NativeExistentialDeposit::get(),
+			native_decimals: 12,
+			native_symbol: b"HDX".to_vec().try_into().unwrap(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		sp_io::TestExternalities::new(t)
+	}
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -57,7 +57,7 @@ fn funded_account(name: &'static str, index: u32, assets: &[AssetId]) -> Account
 	account
 }
 
-fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> DispatchResult {
+fn setup_lbp(caller: AccountId, trader: AccountId, asset_in: AssetId, asset_out: AssetId) -> DispatchResult {
 	let asset_in_amount = 1_000_000_000;
 	let asset_out_amount = 2_000_000_000;
 	let initial_weight = 20_000_000;
@@ -87,6 +87,18 @@ fn setup_lbp(caller: AccountId, asset_in: AssetId, asset_out: AssetId) -> Dispat
 		"Pool does not exist."
 	);
 
+	// worst case - account limits and allowlist are checked and the trade is recorded
+	LBP::set_sale_limits(
+		RawOrigin::Signed(caller.clone()).into(),
+		pool_id.clone(),
+		pallet_lbp::SaleLimits {
+			max_bought_per_account: Some(asset_out_amount),
+			min_trade_interval: Some(1u32),
+			allowlist_only: true,
+		},
+	)?;
+	LBP::add_to_allowlist(RawOrigin::Signed(caller.clone()).into(), pool_id.clone(), trader)?;
+
 	let start = 1u32;
 	let end = 11u32;
 
@@ -185,7 +197,7 @@ runtime_benchmarks! {
 		let caller: AccountId = funded_account("caller", 7, &[asset_in, asset_out]);
 		let seller: AccountId = funded_account("seller", 8, &[asset_in, asset_out]);
 
-		setup_lbp(caller, asset_in, asset_out)?;
+		setup_lbp(caller, seller.clone(), asset_in, asset_out)?;
 
 		let trades = vec![Trade {
 			pool: PoolType::LBP,
@@ -218,7 +230,7 @@ runtime_benchmarks! {
 		let caller: AccountId = funded_account("caller", 0, &[asset_in, asset_out]);
 		let buyer: AccountId = funded_account("buyer", 1, &[asset_in, asset_out]);
 
-		setup_lbp(caller, asset_in, asset_out)?;
+		setup_lbp(caller, buyer.clone(), asset_in, asset_out)?;
 
 		let trades = vec![Trade {
 			pool: PoolType::LBP,
@@ -439,7 +451,7 @@ runtime_benchmarks! {
 		let caller: AccountId = funded_account("caller", 7, &[asset_in, asset_out]);
 		let seller: AccountId = funded_account("seller", 8, &[asset_in, asset_out]);
 
-		setup_lbp(caller, asset_in, asset_out)?;
+		setup_lbp(caller, seller.clone(), asset_in, asset_out)?;
 
 		let trades = vec![Trade {
 			pool: PoolType::LBP,
@@ -200,6 +200,7 @@ impl pallet_evm_accounts::Config for crate::Runtime {
 	type EvmNonceProvider = EvmNonceProvider;
 	type FeeMultiplier = sp_core::ConstU32<50>;
 	type ControllerOrigin = crate::SuperMajorityTechCommittee;
+	type AssetId = AssetId;
 	type WeightInfo = crate::weights::pallet_evm_accounts::HydraWeight<crate::Runtime>;
 }
 
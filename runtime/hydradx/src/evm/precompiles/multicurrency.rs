@@ -35,15 +35,19 @@ use crate::{
 };
 use codec::EncodeLike;
 use frame_support::traits::{IsType, OriginTrait};
-use hydradx_traits::evm::{Erc20Mapping, InspectEvmAccounts};
+use hydradx_traits::evm::Erc20Mapping;
 use hydradx_traits::registry::Inspect as InspectRegistry;
 use orml_traits::{MultiCurrency as MultiCurrencyT, MultiCurrency};
 use pallet_evm::{AddressMapping, ExitRevert, Precompile, PrecompileFailure, PrecompileHandle, PrecompileResult};
-use primitive_types::H160;
+use primitive_types::{H160, H256, U256};
 use primitives::{AssetId, Balance};
 use sp_runtime::traits::Dispatchable;
 use sp_std::marker::PhantomData;
 
+/// Topic of the ERC20 `Approval(address,address,uint256)` event.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] =
+	hex_literal::hex!("8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925");
+
 pub struct MultiCurrencyPrecompile<Runtime>(PhantomData<Runtime>);
 
 impl<Runtime> Precompile for MultiCurrencyPrecompile<Runtime>
@@ -56,6 +60,7 @@ where
 	AssetId: EncodeLike<<Runtime as pallet_asset_registry::Config>::AssetId>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
 	<Runtime as pallet_asset_registry::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_evm_accounts::Config>::AssetId: From<AssetId>,
 	Currencies: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	pallet_currencies::Pallet<Runtime>: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	<Runtime as frame_system::Config>::AccountId:
@@ -74,6 +79,7 @@ where
 
 			handle.check_function_modifier(match selector {
 				Function::Transfer => FunctionModifier::NonPayable,
+				Function::Approve => FunctionModifier::NonPayable,
 				Function::TransferFrom => FunctionModifier::NonPayable,
 				_ => FunctionModifier::View,
 			})?;
@@ -85,8 +91,8 @@ where
 				Function::TotalSupply => Self::total_supply(asset_id, handle),
 				Function::BalanceOf => Self::balance_of(asset_id, handle),
 				Function::Transfer => Self::transfer(asset_id, handle),
-				Function::Allowance => Self::allowance(handle),
-				Function::Approve => Self::not_supported(),
+				Function::Allowance => Self::allowance(asset_id, handle),
+				Function::Approve => Self::approve(asset_id, handle),
 				Function::TransferFrom => Self::transfer_from(asset_id, handle),
 			};
 		}
@@ -106,6 +112,7 @@ where
 		+ pallet_evm_accounts::Config,
 	AssetId: EncodeLike<<Runtime as pallet_asset_registry::Config>::AssetId>,
 	<Runtime as pallet_asset_registry::Config>::AssetId: From<AssetId>,
+	<Runtime as pallet_evm_accounts::Config>::AssetId: From<AssetId>,
 	Currencies: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	pallet_currencies::Pallet<Runtime>: MultiCurrency<Runtime::AccountId, CurrencyId = AssetId, Balance = Balance>,
 	<Runtime as frame_system::Config>::AccountId:
@@ -239,69 +246,90 @@ where
 		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 
-	fn allowance(handle: &mut impl PrecompileHandle) -> PrecompileResult {
-		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+	fn allowance(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost().saturating_mul(2))?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
 		input.expect_arguments(2)?;
 
-		let _owner: H160 = input.read::<Address>()?.into();
+		let owner: H160 = input.read::<Address>()?.into();
 		let spender: H160 = input.read::<Address>()?.into();
 
-		let allowance =
-			if <pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
-				spender,
-			) {
-				u128::MAX
-			} else {
-				0
-			};
+		// Approved contracts have unlimited allowance.
+		let allowance = pallet_evm_accounts::Pallet::<Runtime>::allowance(asset_id.into(), owner, spender);
+
+		log::debug!(target: "evm", "multicurrency: allowance owner: {:?}, spender: {:?}, allowance: {:?}", owner, spender, allowance);
 
 		let encoded = Output::encode_uint::<u128>(allowance);
 		Ok(succeed(encoded))
 	}
 
+	fn approve(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		handle.record_log_costs_manual(3, 32)?;
+
+		// Parse input
+		let mut input = handle.read_input()?;
+		input.expect_arguments(2)?;
+
+		let spender: H160 = input.read::<Address>()?.into();
+		let value: U256 = input.read::<U256>()?;
+
+		// Values which don't fit into `Balance` (e.g. `type(uint256).max`) are unlimited allowances.
+		let amount: Balance = value.try_into().unwrap_or(Balance::MAX);
+		let owner: H160 = handle.context().caller;
+
+		log::debug!(target: "evm", "multicurrency: approve owner: {:?}, spender: {:?}, amount: {:?}", owner, spender, amount);
+
+		pallet_evm_accounts::Pallet::<Runtime>::approve(asset_id.into(), owner, spender, amount);
+
+		handle.log(
+			handle.code_address(),
+			sp_std::vec![
+				H256::from(SELECTOR_LOG_APPROVAL),
+				H256::from(owner),
+				H256::from(spender)
+			],
+			EvmDataWriter::new().write(value).build(),
+		)?;
+
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
+	}
+
 	fn transfer_from(asset_id: AssetId, handle: &mut impl PrecompileHandle) -> PrecompileResult {
 		handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
 
 		// Parse input
 		let mut input = handle.read_input()?;
 		input.expect_arguments(3)?;
 
 		let origin: H160 = handle.context().caller;
-		let from: H160 = input.read::<Address>()?.into();
+		let from_address: H160 = input.read::<Address>()?.into();
 		let to: H160 = input.read::<Address>()?.into();
 		let amount = input.read::<Balance>()?;
 
-		let from = ExtendedAddressMapping::into_account_id(from);
+		let from = ExtendedAddressMapping::into_account_id(from_address);
 		let to = ExtendedAddressMapping::into_account_id(to);
 
 		log::debug!(target: "evm", "multicurrency: transferFrom from: {:?}, to: {:?}, amount: {:?}", from, to, amount);
 
-		if <pallet_evm_accounts::Pallet<Runtime> as InspectEvmAccounts<Runtime::AccountId>>::is_approved_contract(
-			origin,
-		) {
-			<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
-				asset_id,
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
-				&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
-				amount,
-			)
-			.map_err(|e| PrecompileFailure::Revert {
-				exit_status: ExitRevert::Reverted,
-				output: Into::<&str>::into(e).as_bytes().to_vec(),
-			})?;
+		// Approved contracts are allowed to transfer without allowance.
+		pallet_evm_accounts::Pallet::<Runtime>::spend_allowance(asset_id.into(), from_address, origin, amount)
+			.map_err(|_| revert("Insufficient allowance"))?;
 
-			Ok(succeed(EvmDataWriter::new().write(true).build()))
-		} else {
-			Err(revert("Not approved contract"))
-		}
-	}
+		<pallet_currencies::Pallet<Runtime> as MultiCurrency<Runtime::AccountId>>::transfer(
+			asset_id,
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(from)),
+			&(<sp_runtime::AccountId32 as Into<Runtime::AccountId>>::into(to)),
+			amount,
+		)
+		.map_err(|e| PrecompileFailure::Revert {
+			exit_status: ExitRevert::Reverted,
+			output: Into::<&str>::into(e).as_bytes().to_vec(),
+		})?;
 
-	fn not_supported() -> PrecompileResult {
-		Err(PrecompileFailure::Error {
-			exit_status: pallet_evm::ExitError::Other("not supported".into()),
-		})
+		Ok(succeed(EvmDataWriter::new().write(true).build()))
 	}
 }
@@ -48,6 +48,13 @@ pub use xcm::*;
 
 use codec::{Decode, Encode};
 use hydradx_traits::evm::InspectEvmAccounts;
+use hydradx_traits::pools::SpotPriceProvider;
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouteProvider, RouteSpotPriceProvider, Trade};
+use hydradx_traits::{AggregatedEntry, AggregatedOracle, HistoricalAggregatedOracle, PriceOracle};
+use orml_traits::GetByKey;
+use pallet_ema_oracle::{OracleEntry, OraclePeriod};
+use pallet_omnipool::types::{AssetReserveState, PositionValue, TradeQuote};
+use pallet_stableswap::types::{AssetAmount, PoolSnapshot};
 use sp_core::{ConstU128, Get, H160, H256, U256};
 use sp_genesis_builder::PresetId;
 use sp_runtime::{
@@ -57,7 +64,7 @@ use sp_runtime::{
 		UniqueSaturatedInto,
 	},
 	transaction_validity::{TransactionValidity, TransactionValidityError},
-	Permill,
+	DispatchError, FixedU128, Permill,
 };
 
 use sp_std::{convert::From, prelude::*};
@@ -112,10 +119,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 273,
+	spec_version: 297,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	state_version: 1,
 };
 
@@ -167,6 +174,7 @@ construct_runtime!(
 		Claims: pallet_claims = 53,
 		GenesisHistory: pallet_genesis_history = 55,
 		CollatorRewards: pallet_collator_rewards = 57,
+		OmnipoolSubpools: pallet_omnipool_subpools = 58,
 		Omnipool: pallet_omnipool = 59,
 		TransactionPause: pallet_transaction_pause = 60,
 		Duster: pallet_duster = 61,
@@ -271,7 +279,10 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	migration::OnRuntimeUpgradeMigration,
+	(
+		migration::OnRuntimeUpgradeMigration,
+		pallet_lbp::migration::v1::Migration<Runtime>,
+	),
 >;
 
 impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
@@ -933,6 +944,131 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_route_executor_rpc_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn calculate_sell_trade_amounts(route: Vec<Trade<AssetId>>, amount_in: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+			Router::calculate_sell_trade_amounts(&route, amount_in)
+		}
+
+		fn calculate_buy_trade_amounts(route: Vec<Trade<AssetId>>, amount_out: Balance) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+			Router::calculate_buy_trade_amounts(&route, amount_out)
+		}
+
+		fn get_route(asset_pair: AssetPair<AssetId>) -> Vec<Trade<AssetId>> {
+			<Router as RouteProvider<AssetId>>::get_route(asset_pair)
+		}
+
+		fn spot_price_with_fee(route: Vec<Trade<AssetId>>) -> Option<FixedU128> {
+			Router::spot_price_with_fee(&route)
+		}
+	}
+
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, Balance, ItemId> for Runtime {
+		fn assets() -> Vec<(AssetId, AssetReserveState<Balance>)> {
+			Omnipool::load_all_asset_states()
+		}
+
+		fn asset_state(asset_id: AssetId) -> Option<AssetReserveState<Balance>> {
+			Omnipool::load_asset_state(asset_id).ok()
+		}
+
+		fn spot_price(asset_a: AssetId, asset_b: AssetId) -> Option<FixedU128> {
+			<Omnipool as SpotPriceProvider<AssetId>>::spot_price(asset_a, asset_b)
+		}
+
+		fn fees(asset_id: AssetId) -> (Permill, Permill) {
+			<Runtime as pallet_omnipool::Config>::Fee::get(&asset_id)
+		}
+
+		fn calculate_sell(asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<TradeQuote<Balance>, DispatchError> {
+			Omnipool::quote_sell(asset_in, asset_out, amount_in)
+		}
+
+		fn calculate_buy(asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<TradeQuote<Balance>, DispatchError> {
+			Omnipool::quote_buy(asset_in, asset_out, amount_out)
+		}
+
+		fn position_value(position_id: ItemId) -> Result<PositionValue<AssetId, Balance>, DispatchError> {
+			Omnipool::calculate_position_value(position_id)
+		}
+	}
+
+	impl pallet_stableswap_rpc_runtime_api::StableswapApi<Block, AssetId, Balance> for Runtime {
+		fn pools() -> Vec<PoolSnapshot<AssetId>> {
+			Stableswap::pool_snapshots()
+		}
+
+		fn pool(pool_id: AssetId) -> Option<PoolSnapshot<AssetId>> {
+			Stableswap::pool_snapshot(pool_id)
+		}
+
+		fn calculate_sell(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_in: Balance) -> Result<(Balance, Balance), DispatchError> {
+			Stableswap::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
+		}
+
+		fn calculate_buy(pool_id: AssetId, asset_in: AssetId, asset_out: AssetId, amount_out: Balance) -> Result<(Balance, Balance), DispatchError> {
+			Stableswap::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
+		}
+
+		fn calculate_add_liquidity_shares(pool_id: AssetId, assets: Vec<AssetAmount<AssetId>>) -> Result<Balance, DispatchError> {
+			Stableswap::calculate_shares(pool_id, &assets)
+		}
+
+		fn calculate_remove_liquidity_one_asset(pool_id: AssetId, asset_id: AssetId, share_amount: Balance) -> Result<(Balance, Balance), DispatchError> {
+			Stableswap::calculate_remove_liquidity_one_asset(pool_id, asset_id, share_amount)
+		}
+	}
+
+	impl pallet_ema_oracle_rpc_runtime_api::EmaOracleApi<Block, BlockNumber> for Runtime {
+		fn get_entry(
+			source: pallet_ema_oracle::Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+		) -> Option<AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>> {
+			EmaOracle::get_entry(asset_a, asset_b, period, source).ok()
+		}
+
+		fn get_entry_at(
+			source: pallet_ema_oracle::Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+			block_number: BlockNumber,
+		) -> Option<AggregatedEntry<Balance, BlockNumber, pallet_ema_oracle::Price>> {
+			EmaOracle::get_entry_at(asset_a, asset_b, period, source, block_number).ok()
+		}
+
+		fn checkpoints(
+			source: pallet_ema_oracle::Source,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			period: OraclePeriod,
+		) -> Vec<OracleEntry<BlockNumber>> {
+			let assets = pallet_ema_oracle::ordered_pair(asset_a, asset_b);
+			let checkpoints = EmaOracle::checkpoints(source, assets, period);
+			if assets == (asset_a, asset_b) {
+				checkpoints
+			} else {
+				checkpoints.into_iter().map(|entry| entry.inverted()).collect()
+			}
+		}
+
+		fn oracles() -> Vec<(pallet_ema_oracle::Source, (AssetId, AssetId))> {
+			pallet_ema_oracle::Oracles::<Runtime>::iter_keys()
+				.filter(|(_, _, period)| *period == OraclePeriod::LastBlock)
+				.map(|(source, assets, _)| (source, assets))
+				.collect()
+		}
+
+		fn periods() -> Vec<OraclePeriod> {
+			EmaOracle::tracked_periods()
+		}
+
+		fn route_price(route: Vec<Trade<AssetId>>, period: OraclePeriod) -> Option<pallet_ema_oracle::Price> {
+			<Runtime as pallet_route_executor::Config>::OraclePriceProvider::price(&route, period)
+		}
+	}
+
 	impl xcm_fee_payment_runtime_api::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: polkadot_xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
 			if !matches!(xcm_version, 3 | 4) {
@@ -1015,6 +1151,7 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_list_benchmark!(list, extra, pallet_duster, benchmarking::duster);
 			orml_list_benchmark!(list, extra, pallet_omnipool, benchmarking::omnipool);
+			orml_list_benchmark!(list, extra, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_list_benchmark!(list, extra, pallet_route_executor, benchmarking::route_executor);
 			orml_list_benchmark!(list, extra, pallet_dca, benchmarking::dca);
 			orml_list_benchmark!(list, extra, pallet_xyk, benchmarking::xyk);
@@ -1152,6 +1289,7 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_transaction_multi_payment, benchmarking::multi_payment);
 			orml_add_benchmark!(params, batches, pallet_duster, benchmarking::duster);
 			orml_add_benchmark!(params, batches, pallet_omnipool, benchmarking::omnipool);
+			orml_add_benchmark!(params, batches, pallet_omnipool_subpools, benchmarking::omnipool_subpools);
 			orml_add_benchmark!(params, batches, pallet_route_executor, benchmarking::route_executor);
 			orml_add_benchmark!(params, batches, pallet_dca, benchmarking::dca);
 			orml_add_benchmark!(params, batches, pallet_xyk, benchmarking::xyk);
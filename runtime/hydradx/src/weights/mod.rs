@@ -26,6 +26,7 @@ pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_omnipool;
 pub mod pallet_omnipool_liquidity_mining;
+pub mod pallet_omnipool_subpools;
 pub mod pallet_otc;
 pub mod pallet_otc_settlements;
 pub mod pallet_preimage;
@@ -162,4 +162,43 @@ impl<T: frame_system::Config> pallet_circuit_breaker::WeightInfo for HydraWeight
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `CircuitBreaker::RollingVolumeLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::RollingVolumeLimitPerAsset` (`max_values`: None, `max_size`: Some(37), added: 2512, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::RollingVolumePerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::RollingVolumePerAsset` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	fn set_rolling_volume_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_873_000 picoseconds.
+		Weight::from_parts(11_204_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:0 w:1)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::TradingHaltedUntil` (r:0 w:1)
+	/// Proof: `CircuitBreaker::TradingHaltedUntil` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	fn set_price_deviation_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 10_312_000 picoseconds.
+		Weight::from_parts(10_598_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `CircuitBreaker::TradingHaltedUntil` (r:1 w:1)
+	/// Proof: `CircuitBreaker::TradingHaltedUntil` (`max_values`: None, `max_size`: Some(24), added: 2499, mode: `MaxEncodedLen`)
+	/// Storage: `CircuitBreaker::PriceDeviationLimitPerAsset` (r:1 w:0)
+	/// Proof: `CircuitBreaker::PriceDeviationLimitPerAsset` (`max_values`: None, `max_size`: Some(32), added: 2507, mode: `MaxEncodedLen`)
+	/// Storage: `EmaOracle::Oracles` (r:2 w:0)
+	/// Proof: `EmaOracle::Oracles` (`max_values`: None, `max_size`: Some(177), added: 2652, mode: `MaxEncodedLen`)
+	fn ensure_price_deviation_limit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `6294`
+		// Minimum execution time: 31_406_000 picoseconds.
+		Weight::from_parts(32_018_000, 6294)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
\ No newline at end of file
@@ -136,7 +136,11 @@ impl<T: frame_system::Config> pallet_lbp::WeightInfo for HydraWeight<T> {
 	/// Proof: `Tokens::Locks` (`max_values`: None, `max_size`: Some(1261), added: 3736, mode: `MaxEncodedLen`)
 	/// Storage: `LBP::FeeCollectorWithAsset` (r:0 w:1)
 	/// Proof: `LBP::FeeCollectorWithAsset` (`max_values`: None, `max_size`: Some(69), added: 2544, mode: `MaxEncodedLen`)
-	fn remove_liquidity() -> Weight {
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	/// Storage: `LBP::Allowlist` (r:0 w:1000)
+	/// The range of component `a` is `[0, 1000]`.
+	/// Removal of the pool as benchmarked before allowlists were introduced, plus removal of `a` allowlisted accounts.
+	fn remove_liquidity(a: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1895`
 		//  Estimated: `11322`
@@ -144,6 +148,8 @@ impl<T: frame_system::Config> pallet_lbp::WeightInfo for HydraWeight<T> {
 		Weight::from_parts(136_175_000, 11322)
 			.saturating_add(T::DbWeight::get().reads(14_u64))
 			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().reads_writes(1_u64, 1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
 	}
 	/// Storage: `Tokens::Accounts` (r:5 w:5)
 	/// Proof: `Tokens::Accounts` (`max_values`: None, `max_size`: Some(108), added: 2583, mode: `MaxEncodedLen`)
@@ -287,4 +293,32 @@ impl<T: frame_system::Config> pallet_lbp::WeightInfo for HydraWeight<T> {
 		Weight::from_parts(25_559_000, 6156)
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 	}
+	/// Storage: `LBP::PoolData` (r:1 w:1)
+	/// Same storage access as `update_pool_data`, which is an upper bound of the call.
+	fn set_sale_limits() -> Weight {
+		Self::update_pool_data()
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Allowlist` (r:1 w:1)
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	fn add_to_allowlist() -> Weight {
+		Self::update_pool_data()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Allowlist` (r:1 w:1)
+	/// Storage: `LBP::AllowlistSize` (r:1 w:1)
+	fn remove_from_allowlist() -> Weight {
+		Self::update_pool_data()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Storage: `LBP::PoolData` (r:1 w:0)
+	/// Storage: `LBP::Participants` (r:p w:p)
+	/// Proof: `LBP::Participants` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 500]`.
+	fn clear_participants(p: u32, ) -> Weight {
+		T::DbWeight::get().reads(1_u64)
+			.saturating_add(T::DbWeight::get().reads_writes((1_u64).saturating_mul(p.into()), (1_u64).saturating_mul(p.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(p.into()))
+	}
 }
\ No newline at end of file
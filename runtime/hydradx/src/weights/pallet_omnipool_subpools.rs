@@ -0,0 +1,75 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Weights for `pallet_omnipool_subpools`
+//!
+//! The calls of the pallet consist of Omnipool, Stableswap and Router operations, so their weights are composed
+//! of the benchmarked weights of those operations and the storage access of the pallet itself.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+use pallet_omnipool::WeightInfo as OmnipoolWeights;
+use pallet_route_executor::WeightInfo as RouterWeights;
+use pallet_route_executor::MAX_NUMBER_OF_TRADES;
+use pallet_stableswap::WeightInfo as StableswapWeights;
+
+type OmnipoolWeight<T> = super::pallet_omnipool::HydraWeight<T>;
+type StableswapWeight<T> = super::pallet_stableswap::HydraWeight<T>;
+type RouterWeight<T> = super::pallet_route_executor::HydraWeight<T>;
+
+/// Weights for `pallet_omnipool_subpools` using the HydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_omnipool_subpools::WeightInfo for HydraWeight<T> {
+	/// Stableswap pool is created, reserves of both assets are moved out of Omnipool as in `remove_token`
+	/// and the share asset is added to Omnipool as in `add_token`.
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:0 w:2)
+	/// Storage: `OmnipoolSubpools::Subpools` (r:0 w:1)
+	fn create_subpool() -> Weight {
+		StableswapWeight::<T>::create_pool()
+			.saturating_add(OmnipoolWeight::<T>::remove_token().saturating_mul(2))
+			.saturating_add(OmnipoolWeight::<T>::add_token())
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Asset is added to the Stableswap pool as liquidity in `add_liquidity`, its reserve is moved out of Omnipool
+	/// as in `remove_token` and the share asset state is updated as in Omnipool `add_liquidity`.
+	/// Storage: `OmnipoolSubpools::Subpools` (r:1 w:0)
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:0 w:1)
+	fn migrate_asset_to_subpool() -> Weight {
+		StableswapWeight::<T>::add_liquidity()
+			.saturating_add(OmnipoolWeight::<T>::remove_token())
+			.saturating_add(OmnipoolWeight::<T>::add_liquidity())
+			.saturating_add(T::DbWeight::get().reads_writes(1_u64, 1_u64))
+	}
+	/// Position is loaded and updated as in `sacrifice_position`.
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:1 w:0)
+	fn migrate_position() -> Weight {
+		OmnipoolWeight::<T>::sacrifice_position()
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+	/// Route is loaded as in `get_route` and both assets of each trade are checked for migration.
+	/// Storage: `OmnipoolSubpools::MigratedAssets` (r:10 w:0)
+	fn migrate_route() -> Weight {
+		RouterWeight::<T>::get_route()
+			.saturating_add(T::DbWeight::get().reads(2_u64.saturating_mul(MAX_NUMBER_OF_TRADES.into())))
+	}
+}
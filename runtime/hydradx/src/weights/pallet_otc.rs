@@ -145,4 +145,8 @@ impl<T: frame_system::Config> pallet_otc::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Expired order is cancelled with the same storage access as `cancel_order`, without the owner check.
+	fn cancel_expired_order() -> Weight {
+		Self::cancel_order()
+	}
 }
\ No newline at end of file
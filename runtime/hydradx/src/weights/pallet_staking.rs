@@ -182,4 +182,29 @@ impl<T: frame_system::Config> pallet_staking::WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(10_u64))
 			.saturating_add(T::DbWeight::get().writes(10_u64))
 	}
+	/// Votes are processed, rewards are paid and the lock is updated as in `increase_stake`.
+	/// Storage: `Staking::Unbonding` (r:1 w:1)
+	/// Storage: `Staking::AutoCompound` (r:1 w:0)
+	fn decrease_stake() -> Weight {
+		Self::increase_stake()
+			.saturating_add(T::DbWeight::get().reads_writes(2_u64, 1_u64))
+	}
+	/// Position is read and the lock is updated as in `unstake`.
+	/// Storage: `Staking::Unbonding` (r:1 w:1)
+	fn withdraw_unbonded() -> Weight {
+		Self::unstake()
+			.saturating_add(T::DbWeight::get().reads_writes(1_u64, 1_u64))
+	}
+	/// Staking and NFT state is read as in `initialize_staking`.
+	/// Storage: `Staking::AutoCompound` (r:1 w:1)
+	fn set_auto_compound() -> Weight {
+		Self::initialize_staking()
+	}
+	/// Rewards are paid and the lock is updated as in `claim`.
+	/// Storage: `Staking::Unbonding` (r:1 w:0)
+	/// Storage: `Staking::AutoCompound` (r:1 w:1)
+	fn compound_rewards() -> Weight {
+		Self::claim()
+			.saturating_add(T::DbWeight::get().reads_writes(2_u64, 1_u64))
+	}
 }
\ No newline at end of file
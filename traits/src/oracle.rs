@@ -43,6 +43,7 @@ where
 ///
 /// Note: Some of the oracles are named after certain periods of time.
 /// This description relies on the mapping of the enum to the internal implementation and can thus not be guaranteed.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum OraclePeriod {
 	/// The oracle data is from the last block, thus unaggregated.
@@ -57,9 +58,13 @@ pub enum OraclePeriod {
 	Day,
 	/// The oracle data was aggregated over the blocks of the last week.
 	Week,
+	/// The oracle data was aggregated over the given number of blocks.
+	/// Custom periods need to be registered in the oracle pallet before they are tracked.
+	Custom(u32),
 }
 
 impl OraclePeriod {
+	/// Return the predefined periods. Custom periods are not included.
 	pub const fn all_periods() -> &'static [OraclePeriod] {
 		use OraclePeriod::*;
 		&[LastBlock, Short, TenMinutes, Hour, Day, Week]
@@ -269,6 +274,39 @@ impl<AssetId, Balance, BlockNumber, Price> AggregatedOracle<AssetId, Balance, Bl
 	}
 }
 
+/// An oracle returning an entry of oracle data aggregated over `period` as it was at a past block.
+pub trait HistoricalAggregatedOracle<AssetId, Balance, BlockNumber, Price> {
+	type Error;
+	fn get_entry_at(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		period: OraclePeriod,
+		source: Source,
+		block_number: BlockNumber,
+	) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, Self::Error>;
+
+	fn get_entry_at_weight() -> Weight;
+}
+
+/// Default implementation of the oracle trait that always returns `Err`.
+impl<AssetId, Balance, BlockNumber, Price> HistoricalAggregatedOracle<AssetId, Balance, BlockNumber, Price> for () {
+	type Error = ();
+
+	fn get_entry_at(
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+		_block_number: BlockNumber,
+	) -> Result<AggregatedEntry<Balance, BlockNumber, Price>, Self::Error> {
+		Err(())
+	}
+
+	fn get_entry_at_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 /// An oracle returning a price aggregated over `period` with the associated oracle age (to allow
 /// judging whether the oracle had a chance to settle yet).
 pub trait AggregatedPriceOracle<AssetId, BlockNumber, Price> {
@@ -3,7 +3,8 @@ use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::sp_runtime::{DispatchError, DispatchResult};
 use frame_support::weights::Weight;
 use scale_info::TypeInfo;
-use sp_arithmetic::FixedU128;
+use serde::{Deserialize, Serialize};
+use sp_arithmetic::{FixedU128, Permill};
 use sp_std::vec;
 use sp_std::vec::Vec;
 
@@ -11,6 +12,7 @@ pub trait RouteSpotPriceProvider<AssetId> {
 	fn spot_price_with_fee(route: &[Trade<AssetId>]) -> Option<FixedU128>;
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Debug, Encode, Decode, Copy, Clone, PartialOrd, PartialEq, Eq, Default, TypeInfo, MaxEncodedLen)]
 pub struct AssetPair<AssetId> {
 	pub asset_in: AssetId,
@@ -58,14 +60,67 @@ pub trait RouteProvider<AssetId> {
 			asset_out: asset_pair.asset_out,
 		}]
 	}
+
+	/// Discovers the best route for the asset pair by evaluating a bounded number of candidate routes.
+	/// Returns `None` if no route could be found.
+	fn discover_route(_asset_pair: AssetPair<AssetId>) -> Option<Vec<Trade<AssetId>>> {
+		None
+	}
+}
+
+/// Provides the pools of the AMMs, used to discover the routes between assets.
+pub trait PoolsProvider<AssetId> {
+	/// Returns the pools with all the assets which can be traded in them.
+	///
+	/// The total number of assets of the returned pools is at most `max_assets`.
+	/// Pools are read from storage only until the limit is reached.
+	fn pools(max_assets: u32) -> Vec<(PoolType<AssetId>, Vec<AssetId>)>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<AssetId> PoolsProvider<AssetId> for Tuple {
+	fn pools(max_assets: u32) -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+		let mut pools = Vec::new();
+		for_tuples!( #(
+			let remaining_assets = max_assets.saturating_sub(number_of_pool_assets(&pools));
+			pools.extend(Tuple::pools(remaining_assets));
+		)* );
+		pools
+	}
+}
+
+/// Total number of assets of the pools.
+pub fn number_of_pool_assets<AssetId>(pools: &[(PoolType<AssetId>, Vec<AssetId>)]) -> u32 {
+	pools
+		.iter()
+		.fold(0u32, |acc, (_, assets)| acc.saturating_add(assets.len() as u32))
 }
 
+/// Takes the pools from the iterator as long as the total number of their assets does not exceed `max_assets`.
+pub fn take_pools<AssetId>(
+	pools: impl Iterator<Item = (PoolType<AssetId>, Vec<AssetId>)>,
+	max_assets: u32,
+) -> Vec<(PoolType<AssetId>, Vec<AssetId>)> {
+	let mut remaining_assets = max_assets as usize;
+	pools
+		.take_while(|(_, assets)| {
+			if assets.len() > remaining_assets {
+				return false;
+			}
+			remaining_assets = remaining_assets.saturating_sub(assets.len());
+			true
+		})
+		.collect()
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Clone, Copy, Debug, Eq, PartialEq, TypeInfo, MaxEncodedLen)]
 pub enum PoolType<AssetId> {
 	XYK,
 	LBP,
 	Stableswap(AssetId),
 	Omnipool,
+	Bonds,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -75,6 +130,7 @@ pub enum ExecutorError<E> {
 }
 
 ///A single trade for buy/sell, describing the asset pair and the pool type in which the trade is executed
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Encode, Decode, Debug, Eq, PartialEq, Copy, Clone, TypeInfo, MaxEncodedLen)]
 pub struct Trade<AssetId> {
 	pub pool: PoolType<AssetId>,
@@ -82,12 +138,19 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
 }
 
+///A route with the portion of the trade amount to be executed through it, used for split trades
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
+pub struct WeightedRoute<Trade> {
+	pub weight: Permill,
+	pub route: Vec<Trade>,
+}
+
 pub fn inverse_route<AssetId>(trades: Vec<Trade<AssetId>>) -> Vec<Trade<AssetId>> {
 	trades
 		.into_iter()
@@ -129,6 +192,24 @@ pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 		route: Vec<Trade>,
 	) -> DispatchResult;
 
+	fn sell_split(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		routes: Vec<WeightedRoute<Trade>>,
+	) -> DispatchResult;
+
+	fn buy_split(
+		origin: Origin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		routes: Vec<WeightedRoute<Trade>>,
+	) -> DispatchResult;
+
 	fn calculate_sell_trade_amounts(route: &[Trade], amount_in: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
 
 	fn calculate_buy_trade_amounts(route: &[Trade], amount_out: Balance) -> Result<Vec<AmountInAndOut>, DispatchError>;
@@ -321,6 +402,8 @@ pub trait AmmTradeWeights<Trade> {
 	fn force_insert_route_weight() -> Weight;
 	fn get_route_weight() -> Weight;
 	fn calculate_spot_price_with_fee_weight(route: &[Trade]) -> Weight;
+	fn discover_route_weight() -> Weight;
+	fn set_discovered_route_weight() -> Weight;
 }
 
 impl<Trade> AmmTradeWeights<Trade> for () {
@@ -351,6 +434,12 @@ impl<Trade> AmmTradeWeights<Trade> for () {
 	fn calculate_spot_price_with_fee_weight(_route: &[Trade]) -> Weight {
 		Weight::zero()
 	}
+	fn discover_route_weight() -> Weight {
+		Weight::zero()
+	}
+	fn set_discovered_route_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub trait RefundEdCalculator<Balance> {